@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for opening an arbitration panel for a disputed claim
+#[derive(Accounts)]
+pub struct AssignArbitrationPanel<'info> {
+    /// Anyone can open the panel; pays for the panel account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Arbitrator registry PDA
+    #[account(
+        seeds = [ArbitratorRegistry::SEED_PREFIX],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// Claim account PDA
+    #[account(
+        mut,
+        seeds = [
+            Claim::SEED_PREFIX,
+            claim.policy.as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump,
+        constraint = (claim.status == ClaimStatus::Disputed ||
+                     claim.status == ClaimStatus::InArbitration)
+                     @ FreelanceShieldError::ClaimNotInArbitration
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Arbitration panel PDA for the claim
+    #[account(
+        init,
+        payer = payer,
+        space = ArbitrationPanel::SIZE,
+        seeds = [ArbitrationPanel::SEED_PREFIX, claim.key().as_ref()],
+        bump
+    )]
+    pub arbitration_panel: Account<'info, ArbitrationPanel>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Open an arbitration panel for a disputed claim and commit to the slot
+/// whose hash will seed its draw; `draw_panel` picks the members once it passes
+pub fn handler(ctx: Context<AssignArbitrationPanel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_state = &ctx.accounts.program_state;
+    let claim = &mut ctx.accounts.claim;
+    let panel = &mut ctx.accounts.arbitration_panel;
+    
+    // Parties to the claim can never sit on its panel
+    require!(
+        ctx.accounts.arbitrator_registry.eligible_for(claim).len() >=
+            program_state.arbitration_panel_size as usize,
+        FreelanceShieldError::NotEnoughArbitrators
+    );
+    
+    panel.claim = claim.key();
+    panel.members = Vec::new();
+    panel.decisions = Vec::new();
+    panel.selection_seed = [0; 32];
+    panel.selection_slot = 0;
+    panel.draw_slot = clock.slot + PANEL_DRAW_DELAY_SLOTS;
+    panel.assigned_at = clock.unix_timestamp;
+    panel.decision_deadline = 0;
+    panel.outcome = None;
+    panel.is_resolved = false;
+    panel.is_settled = false;
+    panel.bump = *ctx.bumps.get("arbitration_panel").unwrap();
+    
+    claim.status = ClaimStatus::InArbitration;
+    claim.last_update_slot = clock.slot;
+    
+    msg!("Arbitration panel opened: Draw slot: {}", panel.draw_slot);
+    Ok(())
+}
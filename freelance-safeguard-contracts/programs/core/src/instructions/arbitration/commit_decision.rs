@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+use super::{apply_panel_outcome, CommitArbitrationDecisionParams};
+
+/// Accounts for a panel arbitrator committing a decision
+#[derive(Accounts)]
+pub struct CommitArbitrationDecision<'info> {
+    /// Arbitrator wallet
+    pub authority: Signer<'info>,
+    
+    /// Arbitrator account PDA
+    #[account(
+        mut,
+        seeds = [Arbitrator::SEED_PREFIX, authority.key().as_ref()],
+        bump = arbitrator.bump
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Policy account PDA
+    #[account(
+        mut,
+        seeds = [
+            Policy::SEED_PREFIX,
+            policy.owner.as_ref(),
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
+        ],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Product account PDA
+    #[account(
+        mut,
+        seeds = [Product::SEED_PREFIX, &policy.product_id.to_bytes()],
+        bump
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Claim account PDA
+    #[account(
+        mut,
+        seeds = [
+            Claim::SEED_PREFIX,
+            policy.key().as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Arbitration panel PDA for the claim
+    #[account(
+        mut,
+        seeds = [ArbitrationPanel::SEED_PREFIX, claim.key().as_ref()],
+        bump = arbitration_panel.bump,
+        constraint = arbitration_panel.is_member(&authority.key()) @ FreelanceShieldError::NotPanelMember,
+        constraint = !arbitration_panel.is_settled @ FreelanceShieldError::ArbitrationPanelSettled
+    )]
+    pub arbitration_panel: Account<'info, ArbitrationPanel>,
+}
+
+/// Commit an arbitration decision on a disputed claim
+pub fn handler(ctx: Context<CommitArbitrationDecision>, params: CommitArbitrationDecisionParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let panel = &mut ctx.accounts.arbitration_panel;
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    
+    require!(
+        params.reason.len() <= MAX_REASON_LENGTH,
+        FreelanceShieldError::InvalidReason
+    );
+    
+    require!(
+        clock.unix_timestamp <= panel.decision_deadline,
+        FreelanceShieldError::ArbitrationDeadlinePassed
+    );
+    
+    require!(
+        !panel.has_decided(&arbitrator.authority),
+        FreelanceShieldError::ArbitratorAlreadyDecided
+    );
+    
+    // Decisions made after the majority is reached still count towards the
+    // arbitrator's track record, they just no longer change the outcome
+    panel.decisions.push(PanelDecision {
+        arbitrator: arbitrator.authority,
+        approve: params.approve,
+        reason: params.reason,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    arbitrator.cases_decided += 1;
+    arbitrator.last_decision_at = clock.unix_timestamp;
+    
+    if !panel.is_resolved {
+        if let Some(approved) = panel.majority_outcome() {
+            let (approvals, _) = panel.tally();
+            apply_panel_outcome(
+                &mut ctx.accounts.claim,
+                &mut ctx.accounts.policy,
+                &mut ctx.accounts.product,
+                &mut ctx.accounts.program_state,
+                approved,
+                approvals,
+                panel.members.len(),
+                &clock,
+            );
+            
+            panel.outcome = Some(approved);
+            panel.is_resolved = true;
+            
+            msg!("Arbitration panel reached majority: Approved: {}", approved);
+        }
+    }
+    
+    msg!("Arbitration decision committed: Approve: {}, Decisions: {}/{}",
+        params.approve, panel.decisions.len(), panel.members.len());
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use crate::state::*;
+use crate::utils::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for drawing the members of an opened arbitration panel
+#[derive(Accounts)]
+pub struct DrawArbitrationPanel<'info> {
+    /// Anyone can trigger the draw once the committed slot has passed
+    pub cranker: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Arbitrator registry PDA
+    #[account(
+        mut,
+        seeds = [ArbitratorRegistry::SEED_PREFIX],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// Claim account PDA
+    #[account(
+        seeds = [
+            Claim::SEED_PREFIX,
+            claim.policy.as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump,
+        constraint = claim.status == ClaimStatus::InArbitration @ FreelanceShieldError::ClaimNotInArbitration
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Arbitration panel PDA for the claim
+    #[account(
+        mut,
+        seeds = [ArbitrationPanel::SEED_PREFIX, claim.key().as_ref()],
+        bump = arbitration_panel.bump,
+        constraint = arbitration_panel.members.is_empty() && !arbitration_panel.is_settled
+                     @ FreelanceShieldError::ArbitrationPanelAlreadyDrawn
+    )]
+    pub arbitration_panel: Account<'info, ArbitrationPanel>,
+    
+    /// SlotHashes sysvar used as the randomness source
+    /// CHECK: Address is constrained to the SlotHashes sysvar
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: AccountInfo<'info>,
+}
+
+/// Draw a random panel from the committed slot hash
+/// Remaining accounts are the drawn members' arbitrator PDAs, in draw order,
+/// so each is marked as sitting on an unsettled panel
+pub fn handler(ctx: Context<DrawArbitrationPanel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_state = &ctx.accounts.program_state;
+    let registry = &mut ctx.accounts.arbitrator_registry;
+    let claim = &ctx.accounts.claim;
+    let panel = &mut ctx.accounts.arbitration_panel;
+    
+    require!(
+        clock.slot > panel.draw_slot,
+        FreelanceShieldError::PanelDrawSlotNotReached
+    );
+    
+    // A draw slot that has aged out of SlotHashes is replaced by a fresh commitment
+    let (seed, selection_slot) = match derive_selection_seed(&ctx.accounts.slot_hashes, panel.draw_slot, &claim.key())? {
+        Some(selection) => selection,
+        None => {
+            panel.draw_slot = clock.slot + PANEL_DRAW_DELAY_SLOTS;
+            msg!("Draw slot expired, recommitted to slot {}", panel.draw_slot);
+            return Ok(());
+        }
+    };
+    
+    // Arbitrators may have left since the panel was opened; the authority decides instead
+    let candidates = registry.eligible_for(claim);
+    if candidates.len() < program_state.arbitration_panel_size as usize {
+        panel.is_settled = true;
+        msg!("Not enough eligible arbitrators, escalating to program authority");
+        return Ok(());
+    }
+    
+    let members = select_panel(
+        &candidates,
+        program_state.arbitration_panel_size as usize,
+        &seed,
+    )?;
+    
+    require!(
+        ctx.remaining_accounts.len() == members.len(),
+        FreelanceShieldError::InvalidArbitratorAccount
+    );
+    
+    for (member, account_info) in members.iter().zip(ctx.remaining_accounts.iter()) {
+        let mut arbitrator = Account::<Arbitrator>::try_from(account_info)?;
+        
+        let expected_address = Pubkey::create_program_address(
+            &[Arbitrator::SEED_PREFIX, member.as_ref(), &[arbitrator.bump]],
+            &crate::ID,
+        ).map_err(|_| error!(FreelanceShieldError::InvalidArbitratorAccount))?;
+        require!(
+            account_info.key() == expected_address && arbitrator.authority == *member,
+            FreelanceShieldError::InvalidArbitratorAccount
+        );
+        
+        arbitrator.active_panels += 1;
+        arbitrator.exit(&crate::ID)?;
+    }
+    
+    panel.members = members;
+    panel.selection_seed = seed;
+    panel.selection_slot = selection_slot;
+    panel.decision_deadline = clock.unix_timestamp +
+        (program_state.arbitration_period_days as i64 * 86400);
+    
+    registry.total_panels += 1;
+    
+    msg!("Arbitration panel drawn: {} members, Deadline: {}",
+        panel.members.len(), panel.decision_deadline);
+    Ok(())
+}
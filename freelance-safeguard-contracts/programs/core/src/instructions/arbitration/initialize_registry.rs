@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for initializing the arbitrator registry
+#[derive(Accounts)]
+pub struct InitializeArbitratorRegistry<'info> {
+    /// Program authority
+    #[account(
+        mut,
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Arbitrator registry PDA
+    #[account(
+        init,
+        payer = authority,
+        space = ArbitratorRegistry::SIZE,
+        seeds = [ArbitratorRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize the arbitrator registry
+pub fn handler(ctx: Context<InitializeArbitratorRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.arbitrator_registry;
+    
+    registry.active_arbitrators = Vec::new();
+    registry.total_registered = 0;
+    registry.total_panels = 0;
+    registry.bump = *ctx.bumps.get("arbitrator_registry").unwrap();
+    
+    msg!("Arbitrator registry initialized");
+    Ok(())
+}
@@ -0,0 +1,66 @@
+pub mod initialize_registry;
+pub mod register;
+pub mod stake;
+pub mod update_status;
+pub mod withdraw_stake;
+pub mod assign_panel;
+pub mod draw_panel;
+pub mod commit_decision;
+pub mod settle_panel;
+
+pub use initialize_registry::*;
+pub use register::*;
+pub use stake::*;
+pub use update_status::*;
+pub use withdraw_stake::*;
+pub use assign_panel::*;
+pub use draw_panel::*;
+pub use commit_decision::*;
+pub use settle_panel::*;
+
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+// Parameter structs for arbitration instructions
+
+/// Parameters for committing an arbitration decision
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CommitArbitrationDecisionParams {
+    /// Whether to approve the claim
+    pub approve: bool,
+    /// Reason for the decision
+    pub reason: String,
+}
+
+/// Apply a panel's majority outcome to the claim, policy and statistics
+pub(crate) fn apply_panel_outcome(
+    claim: &mut Claim,
+    policy: &mut Policy,
+    product: &mut Product,
+    program_state: &mut ProgramState,
+    approved: bool,
+    approvals: usize,
+    panel_size: usize,
+    clock: &Clock,
+) {
+    if approved {
+        claim.status = ClaimStatus::Approved;
+        policy.status = PolicyStatus::ClaimPending;
+        product.claims_count += 1;
+        program_state.approved_claims += 1;
+    } else {
+        claim.status = ClaimStatus::Rejected;
+        policy.status = PolicyStatus::Active;
+        program_state.rejected_claims += 1;
+    }
+
+    claim.verdict = Some(Verdict {
+        approved,
+        reason: format!("Decided by arbitration panel ({}/{} approve)", approvals, panel_size),
+        processed_at: clock.unix_timestamp,
+        processor: ProcessorType::Arbitration,
+    });
+    claim.last_update_slot = clock.slot;
+
+    program_state.arbitrated_claims += 1;
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for registering a stake-backed arbitrator
+#[derive(Accounts)]
+pub struct RegisterArbitrator<'info> {
+    /// Arbitrator wallet (pays rent and stake)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Arbitrator registry PDA
+    #[account(
+        mut,
+        seeds = [ArbitratorRegistry::SEED_PREFIX],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// Arbitrator account PDA (holds the stake)
+    #[account(
+        init,
+        payer = authority,
+        space = Arbitrator::SIZE,
+        seeds = [Arbitrator::SEED_PREFIX, authority.key().as_ref()],
+        bump
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Register as an arbitrator by staking lamports
+pub fn handler(ctx: Context<RegisterArbitrator>, stake_amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    require!(
+        stake_amount >= ctx.accounts.program_state.min_arbitrator_stake,
+        FreelanceShieldError::InsufficientArbitratorStake
+    );
+    
+    // Move the stake into the arbitrator PDA
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.arbitrator.to_account_info(),
+            },
+        ),
+        stake_amount,
+    )?;
+    
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    arbitrator.authority = ctx.accounts.authority.key();
+    arbitrator.stake_amount = stake_amount;
+    arbitrator.is_active = true;
+    arbitrator.cases_assigned = 0;
+    arbitrator.cases_decided = 0;
+    arbitrator.majority_decisions = 0;
+    arbitrator.cases_missed = 0;
+    arbitrator.total_slashed = 0;
+    arbitrator.registered_at = clock.unix_timestamp;
+    arbitrator.deactivated_at = 0;
+    arbitrator.last_decision_at = 0;
+    arbitrator.active_panels = 0;
+    arbitrator.bump = *ctx.bumps.get("arbitrator").unwrap();
+    
+    let registry = &mut ctx.accounts.arbitrator_registry;
+    registry.activate(arbitrator.authority)?;
+    registry.total_registered += 1;
+    
+    msg!("Arbitrator registered: {}, Stake: {}", arbitrator.authority, stake_amount);
+    Ok(())
+}
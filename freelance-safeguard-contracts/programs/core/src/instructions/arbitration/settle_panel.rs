@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::utils::*;
+use crate::FreelanceShieldError;
+use super::apply_panel_outcome;
+
+/// Accounts for settling an arbitration panel after its deadline
+///
+/// Remaining accounts: the `Arbitrator` PDA of every panel member, in panel order.
+#[derive(Accounts)]
+pub struct SettleArbitrationPanel<'info> {
+    /// Anyone can settle a panel once the deadline has passed
+    pub payer: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Arbitrator registry PDA
+    #[account(
+        mut,
+        seeds = [ArbitratorRegistry::SEED_PREFIX],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// Policy account PDA
+    #[account(
+        mut,
+        seeds = [
+            Policy::SEED_PREFIX,
+            policy.owner.as_ref(),
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
+        ],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Product account PDA
+    #[account(
+        mut,
+        seeds = [Product::SEED_PREFIX, &policy.product_id.to_bytes()],
+        bump
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Claim account PDA
+    #[account(
+        mut,
+        seeds = [
+            Claim::SEED_PREFIX,
+            policy.key().as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Arbitration panel PDA for the claim
+    #[account(
+        mut,
+        seeds = [ArbitrationPanel::SEED_PREFIX, claim.key().as_ref()],
+        bump = arbitration_panel.bump,
+        constraint = !arbitration_panel.is_settled @ FreelanceShieldError::ArbitrationPanelSettled,
+        constraint = !arbitration_panel.members.is_empty() @ FreelanceShieldError::ArbitrationPanelNotDrawn
+    )]
+    pub arbitration_panel: Account<'info, ArbitrationPanel>,
+    
+    /// Risk pool PDA receiving slashed stake
+    #[account(
+        mut,
+        seeds = [RiskPool::SEED_PREFIX],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+}
+
+/// Settle an arbitration panel: resolve by majority of cast decisions if
+/// needed, update track records and slash members who missed the deadline
+pub fn handler(ctx: Context<SettleArbitrationPanel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let panel = &mut ctx.accounts.arbitration_panel;
+    let program_state = &mut ctx.accounts.program_state;
+    
+    require!(
+        clock.unix_timestamp > panel.decision_deadline,
+        FreelanceShieldError::ArbitrationDeadlineNotReached
+    );
+    
+    require!(
+        ctx.remaining_accounts.len() == panel.members.len(),
+        FreelanceShieldError::InvalidArbitratorAccount
+    );
+    
+    // Resolve on the decisions that were actually cast; a tie (including no
+    // decisions at all) leaves the claim in arbitration for the authority
+    if !panel.is_resolved {
+        let (approvals, rejections) = panel.tally();
+        if approvals != rejections {
+            let approved = approvals > rejections;
+            apply_panel_outcome(
+                &mut ctx.accounts.claim,
+                &mut ctx.accounts.policy,
+                &mut ctx.accounts.product,
+                program_state,
+                approved,
+                approvals,
+                panel.members.len(),
+                &clock,
+            );
+            panel.outcome = Some(approved);
+            panel.is_resolved = true;
+        } else {
+            msg!("Arbitration panel tied, escalating to program authority");
+        }
+    }
+    
+    let risk_pool_info = ctx.accounts.risk_pool.to_account_info();
+    let mut total_slashed: u64 = 0;
+    
+    for (member, account_info) in panel.members.iter().zip(ctx.remaining_accounts.iter()) {
+        let mut arbitrator = Account::<Arbitrator>::try_from(account_info)?;
+        
+        let expected_address = Pubkey::create_program_address(
+            &[Arbitrator::SEED_PREFIX, member.as_ref(), &[arbitrator.bump]],
+            &crate::ID,
+        ).map_err(|_| error!(FreelanceShieldError::InvalidArbitratorAccount))?;
+        require!(
+            account_info.key() == expected_address && arbitrator.authority == *member,
+            FreelanceShieldError::InvalidArbitratorAccount
+        );
+        
+        arbitrator.cases_assigned += 1;
+        arbitrator.active_panels = arbitrator.active_panels.saturating_sub(1);
+        
+        match panel.decisions.iter().find(|d| d.arbitrator == *member) {
+            Some(decision) => {
+                if panel.outcome == Some(decision.approve) {
+                    arbitrator.majority_decisions += 1;
+                }
+            }
+            None => {
+                let slash_amount = calculate_slash_amount(
+                    arbitrator.stake_amount,
+                    program_state.arbitrator_slash_percentage,
+                )?;
+                
+                if slash_amount > 0 {
+                    **account_info.try_borrow_mut_lamports()? -= slash_amount;
+                    **risk_pool_info.try_borrow_mut_lamports()? += slash_amount;
+                }
+                
+                arbitrator.stake_amount -= slash_amount;
+                arbitrator.total_slashed += slash_amount;
+                arbitrator.cases_missed += 1;
+                total_slashed += slash_amount;
+                
+                // Drop arbitrators whose remaining stake no longer qualifies
+                if arbitrator.is_active && arbitrator.stake_amount < program_state.min_arbitrator_stake {
+                    ctx.accounts.arbitrator_registry.deactivate(member);
+                    arbitrator.is_active = false;
+                    arbitrator.deactivated_at = clock.unix_timestamp;
+                }
+                
+                msg!("Arbitrator {} missed the deadline, slashed {}", member, slash_amount);
+            }
+        }
+        
+        arbitrator.exit(&crate::ID)?;
+    }
+    
+    // Stake is in lamports, so it is tracked apart from the token-denominated capital
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    risk_pool.total_slashed_stake = risk_pool.total_slashed_stake
+        .checked_add(total_slashed)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+    
+    panel.is_settled = true;
+    
+    msg!("Arbitration panel settled: Outcome: {:?}, Total slashed: {}", panel.outcome, total_slashed);
+    Ok(())
+}
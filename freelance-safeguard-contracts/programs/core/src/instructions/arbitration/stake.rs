@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for adding stake to an arbitrator account
+#[derive(Accounts)]
+pub struct AddArbitratorStake<'info> {
+    /// Arbitrator wallet
+    #[account(
+        mut,
+        constraint = arbitrator.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Arbitrator account PDA
+    #[account(
+        mut,
+        seeds = [Arbitrator::SEED_PREFIX, authority.key().as_ref()],
+        bump = arbitrator.bump
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Top up an arbitrator's stake (e.g. after slashing)
+pub fn handler(ctx: Context<AddArbitratorStake>, amount: u64) -> Result<()> {
+    require!(amount > 0, FreelanceShieldError::InvalidDepositAmount);
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.arbitrator.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    arbitrator.stake_amount = arbitrator.stake_amount
+        .checked_add(amount)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+    
+    msg!("Arbitrator stake increased: Total: {}", arbitrator.stake_amount);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for activating or deactivating an arbitrator
+#[derive(Accounts)]
+pub struct UpdateArbitratorStatus<'info> {
+    /// Arbitrator wallet or program authority
+    #[account(
+        constraint = (arbitrator.authority == authority.key() ||
+                     program_state.authority == authority.key())
+                     @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Arbitrator registry PDA
+    #[account(
+        mut,
+        seeds = [ArbitratorRegistry::SEED_PREFIX],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// Arbitrator account PDA
+    #[account(
+        mut,
+        seeds = [Arbitrator::SEED_PREFIX, arbitrator.authority.as_ref()],
+        bump = arbitrator.bump
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+}
+
+/// Activate or deactivate an arbitrator
+pub fn handler(ctx: Context<UpdateArbitratorStatus>, is_active: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    let registry = &mut ctx.accounts.arbitrator_registry;
+    
+    if is_active {
+        require!(!arbitrator.is_active, FreelanceShieldError::ArbitratorAlreadyActive);
+        require!(
+            arbitrator.stake_amount >= ctx.accounts.program_state.min_arbitrator_stake,
+            FreelanceShieldError::InsufficientArbitratorStake
+        );
+        
+        registry.activate(arbitrator.authority)?;
+        arbitrator.is_active = true;
+        arbitrator.deactivated_at = 0;
+    } else {
+        require!(arbitrator.is_active, FreelanceShieldError::ArbitratorNotActive);
+        
+        registry.deactivate(&arbitrator.authority);
+        arbitrator.is_active = false;
+        arbitrator.deactivated_at = clock.unix_timestamp;
+    }
+    
+    msg!("Arbitrator {} active: {}", arbitrator.authority, is_active);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for withdrawing an inactive arbitrator's stake
+#[derive(Accounts)]
+pub struct WithdrawArbitratorStake<'info> {
+    /// Arbitrator wallet
+    #[account(
+        mut,
+        constraint = arbitrator.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Arbitrator account PDA
+    #[account(
+        mut,
+        seeds = [Arbitrator::SEED_PREFIX, authority.key().as_ref()],
+        bump = arbitrator.bump,
+        constraint = !arbitrator.is_active @ FreelanceShieldError::ArbitratorStillActive
+    )]
+    pub arbitrator: Account<'info, Arbitrator>,
+}
+
+/// Withdraw the stake of a deactivated arbitrator
+pub fn handler(ctx: Context<WithdrawArbitratorStake>) -> Result<()> {
+    let arbitrator = &mut ctx.accounts.arbitrator;
+    
+    // Every panel the arbitrator was drawn onto must settle (and slash) first
+    require!(
+        arbitrator.active_panels == 0,
+        FreelanceShieldError::StakeWithdrawalLocked
+    );
+    
+    let amount = arbitrator.stake_amount;
+    require!(amount > 0, FreelanceShieldError::InvalidWithdrawalAmount);
+    
+    **arbitrator.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    arbitrator.stake_amount = 0;
+    
+    msg!("Arbitrator stake withdrawn: {}", amount);
+    Ok(())
+}
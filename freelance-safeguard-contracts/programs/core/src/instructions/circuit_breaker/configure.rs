@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for configuring a circuit breaker
+#[derive(Accounts)]
+pub struct ConfigureCircuitBreaker<'info> {
+    /// Program authority
+    #[account(
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Circuit breaker PDA
+    #[account(
+        mut,
+        seeds = [CircuitBreaker::SEED_PREFIX, circuit_breaker.scope.as_ref()],
+        bump = circuit_breaker.bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+}
+
+/// Update circuit breaker thresholds, governance key or manual pause
+pub fn handler(ctx: Context<ConfigureCircuitBreaker>, params: ConfigureCircuitBreakerParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let circuit_breaker = &mut ctx.accounts.circuit_breaker;
+    
+    if let Some(governance_authority) = params.governance_authority {
+        circuit_breaker.governance_authority = governance_authority;
+    }
+    
+    if let Some(max_claims_per_hour) = params.max_claims_per_hour {
+        circuit_breaker.max_claims_per_hour = max_claims_per_hour;
+    }
+    
+    if let Some(max_claims_per_day) = params.max_claims_per_day {
+        circuit_breaker.max_claims_per_day = max_claims_per_day;
+    }
+    
+    if let Some(max_amount_per_hour) = params.max_amount_per_hour {
+        circuit_breaker.max_amount_per_hour = max_amount_per_hour;
+    }
+    
+    if let Some(max_amount_per_day) = params.max_amount_per_day {
+        circuit_breaker.max_amount_per_day = max_amount_per_day;
+    }
+    
+    // Manual pauses are recorded like automatic trips and cleared through reset
+    if params.pause_auto_approval == Some(true) && !circuit_breaker.auto_approval_paused {
+        circuit_breaker.auto_approval_paused = true;
+        circuit_breaker.pause_reason = PauseReason::AdminPause;
+        circuit_breaker.tripped_at = clock.unix_timestamp;
+    }
+    
+    msg!("Circuit breaker configured: Scope: {}", circuit_breaker.scope);
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for initializing a circuit breaker
+#[derive(Accounts)]
+#[instruction(scope: Pubkey)]
+pub struct InitializeCircuitBreaker<'info> {
+    /// Program authority
+    #[account(
+        mut,
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Product covered by the breaker (required unless `scope` is the default key)
+    pub product: Option<Account<'info, Product>>,
+    
+    /// Circuit breaker PDA
+    #[account(
+        init,
+        payer = authority,
+        space = CircuitBreaker::SIZE,
+        seeds = [CircuitBreaker::SEED_PREFIX, scope.as_ref()],
+        bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize the global breaker (`scope` = default key) or a product breaker
+pub fn handler(ctx: Context<InitializeCircuitBreaker>, scope: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    if scope != Pubkey::default() {
+        let product = ctx.accounts.product
+            .as_ref()
+            .ok_or(FreelanceShieldError::InvalidParameter)?;
+        require!(product.key() == scope, FreelanceShieldError::InvalidParameter);
+    }
+    
+    let circuit_breaker = &mut ctx.accounts.circuit_breaker;
+    circuit_breaker.scope = scope;
+    circuit_breaker.governance_authority = None;
+    circuit_breaker.hourly = RateWindow { window_start: clock.unix_timestamp, ..Default::default() };
+    circuit_breaker.daily = RateWindow { window_start: clock.unix_timestamp, ..Default::default() };
+    circuit_breaker.max_claims_per_hour = DEFAULT_BREAKER_MAX_CLAIMS_PER_HOUR;
+    circuit_breaker.max_claims_per_day = DEFAULT_BREAKER_MAX_CLAIMS_PER_DAY;
+    circuit_breaker.max_amount_per_hour = 0;
+    circuit_breaker.max_amount_per_day = 0;
+    circuit_breaker.auto_approval_paused = false;
+    circuit_breaker.pause_reason = PauseReason::None;
+    circuit_breaker.tripped_at = 0;
+    circuit_breaker.bump = *ctx.bumps.get("circuit_breaker").unwrap();
+    
+    msg!("Circuit breaker initialized: Scope: {}", scope);
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod initialize;
+pub mod configure;
+pub mod reset;
+
+pub use initialize::*;
+pub use configure::*;
+pub use reset::*;
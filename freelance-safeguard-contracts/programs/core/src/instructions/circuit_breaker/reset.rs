@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for clearing a tripped circuit breaker
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    /// Program authority or the breaker's governance authority
+    #[account(
+        constraint = circuit_breaker.can_reset(&authority.key(), &program_state.authority) @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Circuit breaker PDA
+    #[account(
+        mut,
+        seeds = [CircuitBreaker::SEED_PREFIX, circuit_breaker.scope.as_ref()],
+        bump = circuit_breaker.bump,
+        constraint = circuit_breaker.auto_approval_paused @ FreelanceShieldError::CircuitBreakerNotTripped
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+}
+
+/// Clear the pause reason and resume auto-approval
+pub fn handler(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    let circuit_breaker = &mut ctx.accounts.circuit_breaker;
+    let previous_reason = circuit_breaker.pause_reason;
+    
+    circuit_breaker.auto_approval_paused = false;
+    circuit_breaker.pause_reason = PauseReason::None;
+    
+    msg!("Circuit breaker reset: Scope: {}, Previous reason: {:?}",
+        circuit_breaker.scope, previous_reason);
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for attaching evidence to a claim
+#[derive(Accounts)]
+#[instruction(params: AddClaimEvidenceParams)]
+pub struct AddClaimEvidence<'info> {
+    /// Claimant or counterparty submitting the evidence
+    #[account(
+        mut,
+        constraint = submitter.key() == claim.owner ||
+                     Some(submitter.key()) == claim.counterparty @ FreelanceShieldError::Unauthorized
+    )]
+    pub submitter: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Claim account PDA
+    #[account(
+        mut,
+        seeds = [
+            Claim::SEED_PREFIX,
+            claim.policy.as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump,
+        constraint = matches!(
+            claim.status,
+            ClaimStatus::Pending | ClaimStatus::PendingVote | ClaimStatus::UnderReview
+        ) @ FreelanceShieldError::EvidenceSubmissionClosed,
+        constraint = Clock::get()?.unix_timestamp <= claim.voting_end_date @ FreelanceShieldError::EvidenceSubmissionClosed
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// New evidence PDA, indexed by the claim's evidence count
+    #[account(
+        init,
+        payer = submitter,
+        space = Evidence::SIZE,
+        seeds = [
+            Evidence::SEED_PREFIX,
+            claim.key().as_ref(),
+            &claim.evidence_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub evidence: Account<'info, Evidence>,
+    
+    /// Earlier evidence record being amended (required when `params.amends` is set)
+    #[account(
+        seeds = [
+            Evidence::SEED_PREFIX,
+            claim.key().as_ref(),
+            &amended_evidence.index.to_le_bytes()
+        ],
+        bump = amended_evidence.bump
+    )]
+    pub amended_evidence: Option<Account<'info, Evidence>>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Attach a content-addressed evidence record to a claim
+pub fn handler(ctx: Context<AddClaimEvidence>, params: AddClaimEvidenceParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let claim = &mut ctx.accounts.claim;
+    let submitter = ctx.accounts.submitter.key();
+    
+    require!(
+        claim.evidence_count < MAX_CLAIM_EVIDENCE,
+        FreelanceShieldError::TooManyEvidenceAttachments
+    );
+    
+    require!(
+        !params.mime_type.is_empty() && params.mime_type.len() <= MAX_MIME_TYPE_LENGTH,
+        FreelanceShieldError::InvalidEvidenceType
+    );
+    
+    require!(
+        !params.uri.is_empty() && params.uri.len() <= MAX_EVIDENCE_URI_LENGTH,
+        FreelanceShieldError::InvalidEvidenceUri
+    );
+    
+    require!(
+        params.content_hash != [0u8; 32],
+        FreelanceShieldError::InvalidEvidenceHash
+    );
+    
+    // Amendments must reference an earlier record from the same submitter
+    if let Some(amended_index) = params.amends {
+        let amended = ctx.accounts.amended_evidence
+            .as_ref()
+            .ok_or(FreelanceShieldError::InvalidEvidenceAmendment)?;
+        
+        require!(
+            amended.index == amended_index && amended.submitter == submitter,
+            FreelanceShieldError::InvalidEvidenceAmendment
+        );
+    }
+    
+    let party = if submitter == claim.owner {
+        EvidenceParty::Claimant
+    } else {
+        EvidenceParty::Counterparty
+    };
+    
+    let evidence = &mut ctx.accounts.evidence;
+    evidence.claim = claim.key();
+    evidence.index = claim.evidence_count;
+    evidence.submitter = submitter;
+    evidence.party = party;
+    evidence.content_hash = params.content_hash;
+    evidence.mime_type = params.mime_type;
+    evidence.storage_scheme = params.storage_scheme;
+    evidence.uri = params.uri;
+    evidence.amends = params.amends;
+    evidence.submitted_at = clock.unix_timestamp;
+    evidence.bump = *ctx.bumps.get("evidence").unwrap();
+    
+    claim.evidence_count += 1;
+    claim.last_update_slot = clock.slot;
+    
+    msg!("Evidence added: Index: {}, Party: {:?}", evidence.index, party);
+    Ok(())
+}
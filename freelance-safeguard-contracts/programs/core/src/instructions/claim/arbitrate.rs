@@ -5,6 +5,9 @@ use crate::utils::arbitration_fees::*;
 use crate::FreelanceShieldError;
 
 /// Accounts for arbitrating a disputed or complex claim
+///
+/// The program authority only arbitrates as a fallback: when the registry is
+/// too small to draw a panel, or when a settled panel ended without a majority.
 #[derive(Accounts)]
 pub struct ArbitrateClaim<'info> {
     /// Arbitrator (must be authorized)
@@ -29,7 +32,8 @@ pub struct ArbitrateClaim<'info> {
         seeds = [
             Policy::SEED_PREFIX, 
             policy.owner.as_ref(),
-            policy.product_id.as_ref()
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
         ],
         bump = policy.bump
     )]
@@ -58,6 +62,20 @@ pub struct ArbitrateClaim<'info> {
     )]
     pub claim: Account<'info, Claim>,
     
+    /// Arbitrator registry PDA
+    #[account(
+        seeds = [ArbitratorRegistry::SEED_PREFIX],
+        bump = arbitrator_registry.bump
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    /// Arbitration panel PDA for the claim, if one was drawn
+    #[account(
+        seeds = [ArbitrationPanel::SEED_PREFIX, claim.key().as_ref()],
+        bump = arbitration_panel.bump
+    )]
+    pub arbitration_panel: Option<Account<'info, ArbitrationPanel>>,
+    
     /// Risk pool account to receive fees
     #[account(mut)]
     pub risk_pool: AccountInfo<'info>,
@@ -88,6 +106,19 @@ pub fn handler(ctx: Context<ArbitrateClaim>, params: ArbitrateClaimParams) -> Re
         FreelanceShieldError::InvalidReason
     );
     
+    // Disputes go to a randomly drawn panel whenever enough eligible arbitrators exist
+    match &ctx.accounts.arbitration_panel {
+        Some(panel) => require!(
+            panel.is_settled && panel.outcome.is_none(),
+            FreelanceShieldError::ArbitrationPanelActive
+        ),
+        None => require!(
+            ctx.accounts.arbitrator_registry.eligible_for(claim).len() <
+                program_state.arbitration_panel_size as usize,
+            FreelanceShieldError::ArbitrationPanelRequired
+        ),
+    }
+    
     // Calculate and collect arbitration fee
     let complexity_level = params.complexity_level.unwrap_or_else(|| {
         determine_claim_complexity(
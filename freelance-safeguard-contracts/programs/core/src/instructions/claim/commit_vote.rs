@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for committing a sealed vote on a claim
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    /// Voter (community member or stakeholder)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Claim account PDA
+    #[account(
+        mut,
+        seeds = [
+            Claim::SEED_PREFIX,
+            claim.policy.as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump,
+        constraint = claim.status == ClaimStatus::PendingVote @ FreelanceShieldError::ClaimNotPendingVote,
+        constraint = claim.commit_reveal @ FreelanceShieldError::CommitRevealVotingDisabled,
+        constraint = Clock::get()?.unix_timestamp <= claim.voting_end_date @ FreelanceShieldError::CommitPhaseEnded
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Vote commitment PDA (one per voter per claim)
+    #[account(
+        init,
+        payer = voter,
+        space = VoteCommitment::SIZE,
+        seeds = [
+            VoteCommitment::SEED_PREFIX,
+            claim.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub vote_commitment: Account<'info, VoteCommitment>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Commit a sealed vote on a claim
+pub fn handler(ctx: Context<CommitVote>, params: CommitVoteParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let bond_amount = ctx.accounts.program_state.vote_commit_bond;
+    
+    // Every commitment must fit in the claim's vote list once revealed
+    require!(
+        (ctx.accounts.claim.commitment_count as usize) < MAX_CLAIM_VOTES,
+        FreelanceShieldError::TooManyVotes
+    );
+    
+    // Post the bond; it is returned on reveal and forfeited otherwise
+    if bond_amount > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.voter.to_account_info(),
+                    to: ctx.accounts.vote_commitment.to_account_info(),
+                },
+            ),
+            bond_amount,
+        )?;
+    }
+    
+    let vote_commitment = &mut ctx.accounts.vote_commitment;
+    vote_commitment.claim = ctx.accounts.claim.key();
+    vote_commitment.voter = ctx.accounts.voter.key();
+    vote_commitment.commitment = params.commitment;
+    vote_commitment.bond_amount = bond_amount;
+    vote_commitment.committed_at = clock.unix_timestamp;
+    vote_commitment.bump = *ctx.bumps.get("vote_commitment").unwrap();
+    
+    let claim = &mut ctx.accounts.claim;
+    claim.commitment_count += 1;
+    claim.last_update_slot = clock.slot;
+    
+    msg!("Vote committed: Total commitments: {}", claim.commitment_count);
+    Ok(())
+}
@@ -24,7 +24,8 @@ pub struct DisputeClaim<'info> {
         seeds = [
             Policy::SEED_PREFIX, 
             policy.owner.as_ref(),
-            policy.product_id.as_ref()
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
         ],
         bump = policy.bump
     )]
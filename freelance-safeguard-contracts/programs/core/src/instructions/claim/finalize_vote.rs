@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+use super::vote::tally_votes;
+
+/// Accounts for closing voting on a claim once its voting window has ended
+#[derive(Accounts)]
+pub struct FinalizeClaimVote<'info> {
+    /// Anyone can finalize an expired vote
+    pub payer: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Claim account PDA
+    #[account(
+        mut,
+        seeds = [
+            Claim::SEED_PREFIX,
+            claim.policy.as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump,
+        constraint = claim.status == ClaimStatus::PendingVote @ FreelanceShieldError::ClaimNotPendingVote,
+        // reveal_end_date equals voting_end_date for plaintext votes
+        constraint = Clock::get()?.unix_timestamp > claim.reveal_end_date @ FreelanceShieldError::RevealPhaseNotEnded
+    )]
+    pub claim: Account<'info, Claim>,
+}
+
+/// Tally the votes cast (or revealed) and decide the claim, sending it to
+/// arbitration if no majority was reached
+pub fn handler(ctx: Context<FinalizeClaimVote>) -> Result<()> {
+    let clock = Clock::get()?;
+    let claim = &mut ctx.accounts.claim;
+    
+    tally_votes(claim, ctx.accounts.program_state.min_votes_required, &clock, true);
+    claim.last_update_slot = clock.slot;
+    
+    msg!("Claim vote finalized: Votes: {}, Unrevealed: {}",
+        claim.votes.len(), claim.commitment_count - claim.revealed_count);
+    Ok(())
+}
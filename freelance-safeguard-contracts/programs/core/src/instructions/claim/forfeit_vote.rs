@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for forfeiting the bond of a vote that was never revealed
+#[derive(Accounts)]
+pub struct ForfeitUnrevealedVote<'info> {
+    /// Anyone can forfeit an unrevealed commitment after the reveal phase
+    pub payer: Signer<'info>,
+    
+    /// Claim account PDA
+    #[account(
+        seeds = [
+            Claim::SEED_PREFIX,
+            claim.policy.as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump,
+        constraint = Clock::get()?.unix_timestamp > claim.reveal_end_date @ FreelanceShieldError::RevealPhaseNotEnded
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Unrevealed vote commitment, closed into the risk pool
+    #[account(
+        mut,
+        seeds = [
+            VoteCommitment::SEED_PREFIX,
+            claim.key().as_ref(),
+            vote_commitment.voter.as_ref()
+        ],
+        bump = vote_commitment.bump,
+        close = risk_pool
+    )]
+    pub vote_commitment: Account<'info, VoteCommitment>,
+    
+    /// Risk pool PDA receiving the forfeited bond
+    #[account(
+        mut,
+        seeds = [RiskPool::SEED_PREFIX],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+}
+
+/// Forfeit the bond of a committed vote that was not revealed in time
+pub fn handler(ctx: Context<ForfeitUnrevealedVote>) -> Result<()> {
+    // Closing the commitment moves its whole balance (bond plus rent) into the pool;
+    // lamports are tracked apart from the token-denominated capital
+    let forfeited = ctx.accounts.vote_commitment.to_account_info().lamports();
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    risk_pool.total_forfeited_bonds = risk_pool.total_forfeited_bonds
+        .checked_add(forfeited)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+    
+    msg!("Unrevealed vote forfeited: Voter: {}, Bond: {}",
+        ctx.accounts.vote_commitment.voter, ctx.accounts.vote_commitment.bond_amount);
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::utils::*;
+use crate::FreelanceShieldError;
+
+/// Cover a claim is filed against: a policy or a group member certificate
+pub(crate) struct ClaimSubject {
+    /// Policy or certificate the claim PDA is keyed by
+    pub key: Pubkey,
+    /// Risk score of the insured (0-100)
+    pub risk_score: u8,
+    /// Coverage available to the claim
+    pub coverage_amount: u64,
+    /// Start of cover (Unix timestamp)
+    pub start_date: i64,
+    /// End of cover (Unix timestamp)
+    pub end_date: i64,
+    /// Claims already filed against the cover
+    pub claims_count: u8,
+}
+
+/// Check a submission's amount against the cover and bound its evidence
+pub(crate) fn validate_submission(params: &SubmitClaimParams, subject: &ClaimSubject) -> Result<()> {
+    require!(
+        params.amount <= subject.coverage_amount,
+        FreelanceShieldError::InvalidClaimAmount
+    );
+    
+    // Claims should be at least 1% of coverage amount to prevent spam
+    require!(
+        params.amount >= subject.coverage_amount / 100,
+        FreelanceShieldError::ClaimAmountTooSmall
+    );
+    
+    require!(
+        params.evidence_type.len() <= 64,
+        FreelanceShieldError::InvalidEvidenceType
+    );
+    
+    require!(
+        params.evidence_description.len() <= MAX_EVIDENCE_DESCRIPTION_LENGTH,
+        FreelanceShieldError::InvalidEvidenceDescription
+    );
+    
+    require!(
+        params.evidence_hashes.len() <= MAX_EVIDENCE_ATTACHMENTS,
+        FreelanceShieldError::TooManyEvidenceAttachments
+    );
+    
+    for hash in &params.evidence_hashes {
+        require!(
+            hash.len() <= MAX_EVIDENCE_HASH_LENGTH,
+            FreelanceShieldError::InvalidEvidenceHash
+        );
+    }
+    
+    Ok(())
+}
+
+/// Fill in a newly created claim from its submission
+pub(crate) fn open_claim(
+    claim: &mut Claim,
+    subject: &ClaimSubject,
+    owner: Pubkey,
+    params: SubmitClaimParams,
+    counterparty_verified: bool,
+    program_state: &ProgramState,
+    product: &Product,
+    clock: &Clock,
+) -> Result<()> {
+    // The client named on the claim cannot be the claimant
+    require!(
+        params.counterparty != Some(owner),
+        FreelanceShieldError::InvalidCounterparty
+    );
+    
+    claim.policy = subject.key;
+    claim.owner = owner;
+    claim.index = subject.claims_count;
+    claim.amount = params.amount;
+    claim.status = ClaimStatus::Pending;
+    claim.evidence_type = params.evidence_type;
+    claim.evidence_description = params.evidence_description;
+    claim.evidence_hashes = params.evidence_hashes;
+    claim.submission_date = clock.unix_timestamp;
+    claim.category = params.claim_category;
+    claim.verdict = None;
+    claim.votes = Vec::new();
+    
+    // Commit-reveal applies if enabled globally or for this product; the
+    // voting period then becomes the commit phase, followed by the reveal phase
+    claim.voting_end_date = clock.unix_timestamp + (program_state.voting_period_days as i64 * 86400);
+    claim.commit_reveal = program_state.commit_reveal_voting || product.commit_reveal_voting;
+    claim.reveal_end_date = if claim.commit_reveal {
+        claim.voting_end_date + (program_state.reveal_period_days as i64 * 86400)
+    } else {
+        claim.voting_end_date
+    };
+    claim.commitment_count = 0;
+    claim.revealed_count = 0;
+    
+    claim.counterparty = params.counterparty;
+    claim.counterparty_verified = counterparty_verified;
+    claim.evidence_count = 0;
+    claim.response_deadline = clock.unix_timestamp + (program_state.counterparty_response_days as i64 * 86400);
+    claim.counterparty_response = None;
+    
+    claim.transaction_signature = None;
+    claim.creation_slot = clock.slot;
+    claim.last_update_slot = clock.slot;
+    
+    Ok(())
+}
+
+/// Score a claim for fraud (0-100) against the product's history, then fold it in
+pub(crate) fn score_claim(
+    claim: &mut Claim,
+    subject: &ClaimSubject,
+    fraud_model: &FraudModel,
+    product: &mut Product,
+    clock: &Clock,
+) -> Result<()> {
+    claim.risk_score = calculate_claim_risk_score(
+        fraud_model,
+        subject.risk_score,
+        claim.amount,
+        subject.coverage_amount,
+        subject.end_date - subject.start_date,
+        clock.unix_timestamp - subject.start_date,
+        subject.claims_count,
+        (product.claim_amount_samples, product.claim_amount_mean, product.claim_amount_std_dev()),
+    )?;
+    claim.fraud_model_version = fraud_model.version;
+    product.record_claim_amount(claim.amount);
+    
+    Ok(())
+}
+
+/// Record claim velocity and either auto-approve the claim or send it to a vote
+/// A tripped breaker pauses auto-approval only
+pub(crate) fn route_claim(
+    claim: &mut Claim,
+    program_state: &mut ProgramState,
+    global_breaker: &mut CircuitBreaker,
+    product_breaker: &mut CircuitBreaker,
+    clock: &Clock,
+) {
+    let global_paused = global_breaker.record_claim(clock.unix_timestamp, claim.amount);
+    let product_paused = product_breaker.record_claim(clock.unix_timestamp, claim.amount);
+    if global_paused || product_paused {
+        msg!("Circuit breaker active: Auto-approval paused");
+    }
+    
+    if !global_paused && !product_paused &&
+       claim.risk_score <= program_state.auto_process_threshold &&
+       claim.amount <= program_state.auto_claim_limit {
+        claim.status = ClaimStatus::Approved;
+        claim.verdict = Some(Verdict {
+            approved: true,
+            reason: "Auto-approved based on low risk score and amount within auto-approval limit".to_string(),
+            processed_at: clock.unix_timestamp,
+            processor: ProcessorType::Automated,
+        });
+    
+        program_state.approved_claims += 1;
+    } else {
+        claim.status = ClaimStatus::PendingVote;
+    }
+}
@@ -4,6 +4,13 @@ pub mod process;
 pub mod pay;
 pub mod dispute;
 pub mod arbitrate;
+pub mod commit_vote;
+pub mod reveal_vote;
+pub mod finalize_vote;
+pub mod forfeit_vote;
+pub mod add_evidence;
+pub mod respond;
+pub(crate) mod intake;
 
 pub use submit::*;
 pub use vote::*;
@@ -11,6 +18,12 @@ pub use process::*;
 pub use pay::*;
 pub use dispute::*;
 pub use arbitrate::*;
+pub use commit_vote::*;
+pub use reveal_vote::*;
+pub use finalize_vote::*;
+pub use forfeit_vote::*;
+pub use add_evidence::*;
+pub use respond::*;
 
 // Parameter structs for claim instructions
 
@@ -8,6 +8,7 @@ use crate::FreelanceShieldError;
 pub struct PayClaim<'info> {
     /// Program authority
     #[account(
+        mut,
         constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
     )]
     pub authority: Signer<'info>,
@@ -27,7 +28,8 @@ pub struct PayClaim<'info> {
         seeds = [
             Policy::SEED_PREFIX, 
             policy.owner.as_ref(),
-            policy.product_id.as_ref()
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
         ],
         bump = policy.bump,
         constraint = policy.status == PolicyStatus::ClaimPending @ FreelanceShieldError::PolicyNotInClaimPending
@@ -64,18 +66,51 @@ pub struct PayClaim<'info> {
     pub risk_pool: Account<'info, RiskPool>,
     
     /// Program's token account for payment
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = program_token_account.mint == policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
     pub program_token_account: Account<'info, TokenAccount>,
     
     /// Claimant's token account for receiving payment
     #[account(
         mut,
-        constraint = claimant_token_account.owner == policy.owner @ FreelanceShieldError::InvalidTokenAccount
+        constraint = claimant_token_account.owner == policy.owner @ FreelanceShieldError::InvalidTokenAccount,
+        constraint = claimant_token_account.mint == policy.premium_mint @ FreelanceShieldError::MintMismatch
     )]
     pub claimant_token_account: Account<'info, TokenAccount>,
     
+    /// Subrogation record (required for non-payment claims recoverable from the
+    /// counterparty, refused for any other claim)
+    #[account(
+        init,
+        payer = authority,
+        space = Subrogation::SIZE,
+        seeds = [Subrogation::SEED_PREFIX, claim.key().as_ref()],
+        bump
+    )]
+    pub subrogation: Option<Account<'info, Subrogation>>,
+    
+    /// Debt summary of the claim counterparty (required alongside `subrogation`);
+    /// the handler checks it against the counterparty's own PDA
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DebtorRecord::SIZE,
+        seeds = [DebtorRecord::SEED_PREFIX, claim.counterparty.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub debtor_record: Option<Account<'info, DebtorRecord>>,
+    
+    /// Referrer of the policy (required when the policy was referred)
+    #[account(mut)]
+    pub referrer: Option<Account<'info, Referrer>>,
+    
     /// Token program
     pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
 }
 
 /// Pay an approved insurance claim
@@ -141,6 +176,60 @@ pub fn handler(ctx: Context<PayClaim>, transaction_signature: Option<String>) ->
     }
     claim.last_update_slot = clock.slot;
     
+    // Non-payment claims against a verified, uncontested (or adjudicated) client
+    // open a recovery against them; recovery accounts are refused for any other claim
+    let recoverable_client = claim.counterparty
+        .filter(|_| claim.category == ClaimCategory::NonPayment && claim.recoverable_from_counterparty());
+    match recoverable_client {
+        Some(client) => {
+            let subrogation = ctx.accounts.subrogation
+                .as_mut()
+                .ok_or(FreelanceShieldError::SubrogationAccountsRequired)?;
+            subrogation.claim = claim.key();
+            subrogation.product = product.key();
+            subrogation.client = client;
+            subrogation.amount_owed = claim.amount;
+            subrogation.amount_recovered = 0;
+            subrogation.status = SubrogationStatus::Open;
+            subrogation.created_at = clock.unix_timestamp;
+            subrogation.repayment_deadline = clock.unix_timestamp + SUBROGATION_REPAYMENT_DAYS * 86400;
+            subrogation.last_repayment_at = 0;
+            subrogation.bump = *ctx.bumps.get("subrogation").unwrap();
+            
+            let debtor_record = ctx.accounts.debtor_record
+                .as_mut()
+                .ok_or(FreelanceShieldError::SubrogationAccountsRequired)?;
+            let (expected_debtor_record, _) = Pubkey::find_program_address(
+                &[DebtorRecord::SEED_PREFIX, client.as_ref()],
+                &crate::ID,
+            );
+            require!(
+                debtor_record.key() == expected_debtor_record,
+                FreelanceShieldError::InvalidDebtorRecord
+            );
+            debtor_record.client = client;
+            debtor_record.open_subrogations += 1;
+            debtor_record.total_owed += claim.amount;
+            debtor_record.last_update = clock.unix_timestamp;
+            debtor_record.bump = *ctx.bumps.get("debtor_record").unwrap();
+            
+            msg!("Subrogation opened: Client: {}, Amount owed: {}", client, claim.amount);
+        }
+        None => require!(
+            ctx.accounts.subrogation.is_none() && ctx.accounts.debtor_record.is_none(),
+            FreelanceShieldError::ClaimNotRecoverable
+        ),
+    }
+    
+    // Referred policies count against the referrer's loss ratio
+    if let Some(referrer_wallet) = policy.referrer {
+        let referrer = ctx.accounts.referrer
+            .as_mut()
+            .ok_or(FreelanceShieldError::ReferrerAccountRequired)?;
+        require!(referrer.wallet == referrer_wallet, FreelanceShieldError::InvalidReferrer);
+        referrer.record_claim_paid(claim.amount, clock.unix_timestamp);
+    }
+    
     // Update policy status
     policy.status = PolicyStatus::ClaimPaid;
     
@@ -154,6 +243,7 @@ pub fn handler(ctx: Context<PayClaim>, transaction_signature: Option<String>) ->
     
     // Update program state statistics
     program_state.total_claims_paid += claim.amount;
+    program_state.total_claims_paid_stable += program_state.stable_value(&policy.premium_mint, claim.amount);
     program_state.total_coverage_liability -= policy.coverage_amount;
     
     // Calculate program-wide premium to claims ratio
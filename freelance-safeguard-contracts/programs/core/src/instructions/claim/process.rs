@@ -26,7 +26,8 @@ pub struct ProcessClaim<'info> {
         seeds = [
             Policy::SEED_PREFIX, 
             policy.owner.as_ref(),
-            policy.product_id.as_ref()
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
         ],
         bump = policy.bump,
         constraint = policy.status == PolicyStatus::ClaimPending @ FreelanceShieldError::PolicyNotInClaimPending
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+use crate::adapters::SolanaReputationProvider;
+use crate::interfaces::reputation::ReputationProvider;
+
+/// Accounts for the claim counterparty (client) responding to a claim
+#[derive(Accounts)]
+pub struct RespondToClaim<'info> {
+    /// Counterparty named on the claim
+    #[account(
+        constraint = Some(counterparty.key()) == claim.counterparty @ FreelanceShieldError::Unauthorized
+    )]
+    pub counterparty: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Claim account PDA
+    #[account(
+        mut,
+        seeds = [
+            Claim::SEED_PREFIX,
+            claim.policy.as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump,
+        constraint = claim.counterparty_response.is_none() @ FreelanceShieldError::CounterpartyAlreadyResponded,
+        constraint = Clock::get()?.unix_timestamp <= claim.response_deadline @ FreelanceShieldError::ResponseWindowEnded
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Policy the claim was filed against (required to approve on acknowledgement)
+    #[account(
+        mut,
+        constraint = policy.key() == claim.policy @ FreelanceShieldError::InvalidParameter
+    )]
+    pub policy: Option<Account<'info, Policy>>,
+    
+    /// Product that the policy is based on (required alongside `policy`)
+    #[account(mut)]
+    pub product: Option<Account<'info, Product>>,
+    
+    /// Global claim circuit breaker PDA
+    #[account(
+        seeds = [CircuitBreaker::SEED_PREFIX, Pubkey::default().as_ref()],
+        bump = global_breaker.bump
+    )]
+    pub global_breaker: Account<'info, CircuitBreaker>,
+    
+    /// Product claim circuit breaker PDA
+    #[account(
+        seeds = [CircuitBreaker::SEED_PREFIX, product_breaker.scope.as_ref()],
+        bump = product_breaker.bump
+    )]
+    pub product_breaker: Account<'info, CircuitBreaker>,
+}
+
+/// Acknowledge or contest a claim as its counterparty
+/// An acknowledgement approves an undecided claim only when the counterparty was
+/// verified from the policy's escrow and the claim clears the fraud and circuit
+/// breaker checks; otherwise it is recorded as evidence for the vote
+pub fn handler(ctx: Context<RespondToClaim>, params: RespondToClaimParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let claim = &mut ctx.accounts.claim;
+    let program_state = &mut ctx.accounts.program_state;
+    let counterparty = ctx.accounts.counterparty.key();
+    
+    require!(
+        params.reason.len() <= MAX_REASON_LENGTH,
+        FreelanceShieldError::InvalidReason
+    );
+    
+    claim.counterparty_response = Some(CounterpartyResponse {
+        acknowledged: params.acknowledge,
+        responded_at: clock.unix_timestamp,
+    });
+    
+    let undecided = matches!(
+        claim.status,
+        ClaimStatus::Pending | ClaimStatus::PendingVote | ClaimStatus::UnderReview
+    );
+    
+    if params.acknowledge && undecided && claim.counterparty_verified {
+        let policy = ctx.accounts.policy
+            .as_mut()
+            .ok_or(FreelanceShieldError::InvalidParameter)?;
+        let product = ctx.accounts.product
+            .as_mut()
+            .ok_or(FreelanceShieldError::InvalidParameter)?;
+        let (product_key, _) = Pubkey::find_program_address(
+            &[Product::SEED_PREFIX, &policy.product_id.to_bytes()],
+            &crate::ID,
+        );
+        require!(
+            product.key() == product_key && ctx.accounts.product_breaker.scope == product_key,
+            FreelanceShieldError::InvalidParameter
+        );
+        
+        // A verified acknowledgement still can't bypass the fraud model or a tripped breaker
+        let breakers_clear = !ctx.accounts.global_breaker.auto_approval_paused &&
+            !ctx.accounts.product_breaker.auto_approval_paused;
+        if breakers_clear && claim.risk_score <= program_state.auto_process_threshold {
+            claim.status = ClaimStatus::Approved;
+            claim.verdict = Some(Verdict {
+                approved: true,
+                reason: format!("Acknowledged by counterparty: {}", params.reason),
+                processed_at: clock.unix_timestamp,
+                processor: ProcessorType::Automated,
+            });
+            
+            policy.status = PolicyStatus::ClaimPending;
+            product.claims_count += 1;
+            program_state.approved_claims += 1;
+        }
+    }
+    
+    // An acknowledgement is an admission of fault by the client
+    SolanaReputationProvider::update_dispute(&counterparty, params.acknowledge)?;
+    
+    claim.last_update_slot = clock.slot;
+    
+    msg!("Counterparty responded: Acknowledged: {}, Reason: {}", params.acknowledge, params.reason);
+    Ok(())
+}
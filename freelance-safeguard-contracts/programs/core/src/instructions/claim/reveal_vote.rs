@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::*;
+use crate::FreelanceShieldError;
+use super::vote::tally_votes;
+
+/// Accounts for revealing a committed vote
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    /// Voter who made the commitment (receives the bond back)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Claim account PDA
+    #[account(
+        mut,
+        seeds = [
+            Claim::SEED_PREFIX,
+            claim.policy.as_ref(),
+            &[claim.index]
+        ],
+        bump = claim.bump,
+        constraint = Clock::get()?.unix_timestamp > claim.voting_end_date @ FreelanceShieldError::RevealPhaseNotStarted,
+        constraint = Clock::get()?.unix_timestamp <= claim.reveal_end_date @ FreelanceShieldError::RevealPhaseEnded
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Vote commitment PDA, closed back to the voter on reveal
+    #[account(
+        mut,
+        seeds = [
+            VoteCommitment::SEED_PREFIX,
+            claim.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump = vote_commitment.bump,
+        close = voter
+    )]
+    pub vote_commitment: Account<'info, VoteCommitment>,
+}
+
+/// Reveal a committed vote and refund the bond
+pub fn handler(ctx: Context<RevealVote>, params: RevealVoteParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let claim = &mut ctx.accounts.claim;
+    let program_state = &ctx.accounts.program_state;
+    
+    require!(
+        params.reason.len() <= MAX_REASON_LENGTH,
+        FreelanceShieldError::InvalidVoteReason
+    );
+    
+    let reason_hash = hash(params.reason.as_bytes()).to_bytes();
+    require!(
+        VoteCommitment::compute(params.approve, &reason_hash, &params.salt) ==
+            ctx.accounts.vote_commitment.commitment,
+        FreelanceShieldError::CommitmentMismatch
+    );
+    
+    claim.revealed_count += 1;
+    
+    // The claim may have been decided by an admin in the meantime; the voter
+    // still gets the bond back but the vote no longer counts
+    if claim.status == ClaimStatus::PendingVote {
+        claim.votes.push(Vote {
+            voter: ctx.accounts.voter.key(),
+            approve: params.approve,
+            reason: params.reason,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        // Only revealed votes are tallied, and only once no sealed votes remain
+        if claim.revealed_count == claim.commitment_count {
+            tally_votes(claim, program_state.min_votes_required, &clock, true);
+        }
+    }
+    
+    claim.last_update_slot = clock.slot;
+    
+    msg!("Vote revealed: Approve: {}, Revealed: {}/{}",
+        params.approve, claim.revealed_count, claim.commitment_count);
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
-use crate::utils::*;
 use crate::FreelanceShieldError;
 use crate::adapters::get_reputation_provider;
+use crate::interfaces::escrow::EscrowView;
+use super::intake::*;
 
 /// Accounts for submitting an insurance claim
 #[derive(Accounts)]
@@ -29,7 +30,8 @@ pub struct SubmitClaim<'info> {
         seeds = [
             Policy::SEED_PREFIX, 
             policy.owner.as_ref(),
-            policy.product_id.as_ref()
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
         ],
         bump = policy.bump,
         constraint = policy.status == PolicyStatus::Active @ FreelanceShieldError::PolicyNotActive,
@@ -39,10 +41,35 @@ pub struct SubmitClaim<'info> {
     
     /// Product that the policy is based on
     #[account(
-        constraint = policy.product == product.key() @ FreelanceShieldError::InvalidParameters,
+        mut,
+        seeds = [Product::SEED_PREFIX, &policy.product_id.to_bytes()],
+        bump
     )]
     pub product: Account<'info, Product>,
     
+    /// Fraud model PDA used to score the claim
+    #[account(
+        seeds = [FraudModel::SEED_PREFIX],
+        bump = fraud_model.bump
+    )]
+    pub fraud_model: Account<'info, FraudModel>,
+    
+    /// Global claim circuit breaker PDA
+    #[account(
+        mut,
+        seeds = [CircuitBreaker::SEED_PREFIX, Pubkey::default().as_ref()],
+        bump = global_breaker.bump
+    )]
+    pub global_breaker: Account<'info, CircuitBreaker>,
+    
+    /// Product claim circuit breaker PDA
+    #[account(
+        mut,
+        seeds = [CircuitBreaker::SEED_PREFIX, product.key().as_ref()],
+        bump = product_breaker.bump
+    )]
+    pub product_breaker: Account<'info, CircuitBreaker>,
+    
     /// Claim account PDA
     #[account(
         init,
@@ -50,8 +77,8 @@ pub struct SubmitClaim<'info> {
         space = Claim::BASE_SIZE + 
                 // Estimate space for evidence_hashes (assume max 5 hashes of 64 chars each)
                 (4 + (MAX_EVIDENCE_ATTACHMENTS * (4 + MAX_EVIDENCE_HASH_LENGTH))) +
-                // Estimate space for votes (assume max 10 votes)
-                (4 + (10 * Vote::SIZE)),
+                // Space for votes (max MAX_CLAIM_VOTES)
+                (4 + (MAX_CLAIM_VOTES * Vote::SIZE)),
         seeds = [
             Claim::SEED_PREFIX,
             policy.key().as_ref(),
@@ -61,88 +88,62 @@ pub struct SubmitClaim<'info> {
     )]
     pub claim: Account<'info, Claim>,
     
+    /// Escrow the policy is bound to (required for escrow-bound policies)
+    /// CHECK: validated against `policy.escrow` and decoded in the handler
+    pub escrow: Option<UncheckedAccount<'info>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
 }
 
 /// Submit an insurance claim
-pub fn handler(ctx: Context<SubmitClaim>, params: SubmitClaimParams) -> Result<()> {
+pub fn handler(ctx: Context<SubmitClaim>, mut params: SubmitClaimParams) -> Result<()> {
     let clock = Clock::get()?;
     let claim = &mut ctx.accounts.claim;
     let policy = &mut ctx.accounts.policy;
     let owner = &ctx.accounts.owner;
     let program_state = &mut ctx.accounts.program_state;
     
-    // Validate claim parameters
-    // Validate claim amount is within reasonable limits
-    require!(
-        params.amount <= policy.coverage_amount,
-        FreelanceShieldError::InvalidClaimAmount
-    );
-    
-    // Additional validation to prevent unreasonably small claims
-    // Claims should be at least 1% of coverage amount to prevent spam
-    require!(
-        params.amount >= policy.coverage_amount / 100,
-        FreelanceShieldError::ClaimAmountTooSmall
-    );
-    
-    require!(
-        params.evidence_type.len() <= 64,
-        FreelanceShieldError::InvalidEvidenceType
-    );
-    
-    require!(
-        params.evidence_description.len() <= MAX_EVIDENCE_DESCRIPTION_LENGTH,
-        FreelanceShieldError::InvalidEvidenceDescription
-    );
-    
-    require!(
-        params.evidence_hashes.len() <= MAX_EVIDENCE_ATTACHMENTS,
-        FreelanceShieldError::TooManyEvidenceAttachments
-    );
-    
-    for hash in &params.evidence_hashes {
+    let subject = ClaimSubject {
+        key: policy.key(),
+        risk_score: policy.risk_score,
+        coverage_amount: policy.coverage_amount,
+        start_date: policy.start_date,
+        end_date: policy.end_date,
+        claims_count: policy.claims_count,
+    };
+    validate_submission(&params, &subject)?;
+    
+    // Escrow-bound policies take the counterparty from the escrow's client;
+    // anyone else the claimant names stays unverified
+    let mut counterparty_verified = false;
+    if let Some(bound_escrow) = policy.escrow {
+        let escrow_info = ctx.accounts.escrow
+            .as_ref()
+            .ok_or(FreelanceShieldError::InvalidEscrowAccount)?;
+        require!(escrow_info.key() == bound_escrow, FreelanceShieldError::InvalidEscrowAccount);
+        
+        let escrow = EscrowView::load(&escrow_info.to_account_info())?;
         require!(
-            hash.len() <= MAX_EVIDENCE_HASH_LENGTH,
-            FreelanceShieldError::InvalidEvidenceHash
+            params.counterparty.map_or(true, |client| client == escrow.client),
+            FreelanceShieldError::InvalidCounterparty
         );
+        params.counterparty = Some(escrow.client);
+        counterparty_verified = true;
     }
     
-    // Initialize claim
-    claim.policy = ctx.accounts.policy.key();
-    claim.owner = ctx.accounts.owner.key();
-    claim.amount = params.amount;
-    claim.status = ClaimStatus::Pending;
-    claim.evidence_type = params.evidence_type;
-    claim.evidence_description = params.evidence_description;
-    claim.evidence_hashes = params.evidence_hashes;
-    claim.submission_date = clock.unix_timestamp;
-    claim.category = params.claim_category;
-    claim.verdict = None;
-    claim.votes = Vec::new();
-    
-    // Set voting end date
-    claim.voting_end_date = clock.unix_timestamp + (program_state.voting_period_days as i64 * 86400);
-    
-    claim.transaction_signature = None;
-    
-    // Calculate risk score for fraud detection (0-100)
-    // Using the enhanced risk scoring model with multiple weighted factors
-    let risk_score = calculate_claim_risk_score(
-        policy.risk_score,
-        params.amount,
-        policy.coverage_amount,
-        policy.end_date - policy.start_date,
-        clock.unix_timestamp - policy.start_date,
-        policy.claims_count,
-        Some(program_state.avg_claim_amount)
+    let amount = params.amount;
+    open_claim(
+        claim,
+        &subject,
+        owner.key(),
+        params,
+        counterparty_verified,
+        program_state,
+        &ctx.accounts.product,
+        &clock,
     )?;
-    
-    claim.risk_score = risk_score;
-    claim.creation_slot = clock.slot;
-    claim.last_update_slot = clock.slot;
-    claim.index = policy.claims_count;
+    score_claim(claim, &subject, &ctx.accounts.fraud_model, &mut ctx.accounts.product, &clock)?;
     claim.bump = *ctx.bumps.get("claim").unwrap();
     
     // Update policy
@@ -154,25 +155,14 @@ pub fn handler(ctx: Context<SubmitClaim>, params: SubmitClaimParams) -> Result<(
     let reputation_provider = get_reputation_provider();
     reputation_provider.update_claim(&owner.key(), false)?;
     
-    // Determine if claim can be auto-processed based on risk score and amount
-    if risk_score <= program_state.auto_process_threshold && 
-       params.amount <= program_state.auto_claim_limit {
-        // Auto-approve the claim
-        claim.status = ClaimStatus::Approved;
-        claim.verdict = Some(Verdict {
-            approved: true,
-            reason: "Auto-approved based on low risk score and amount within auto-approval limit".to_string(),
-            processed_at: clock.unix_timestamp,
-            processor: ProcessorType::Automated,
-        });
-        
-        // Update program statistics
-        program_state.approved_claims += 1;
-    } else {
-        // Send to community voting
-        claim.status = ClaimStatus::PendingVote;
-    }
+    route_claim(
+        claim,
+        program_state,
+        &mut ctx.accounts.global_breaker,
+        &mut ctx.accounts.product_breaker,
+        &clock,
+    );
     
-    msg!("Claim submitted: Amount: {}, Risk Score: {}", params.amount, risk_score);
+    msg!("Claim submitted: Amount: {}, Risk Score: {}", amount, claim.risk_score);
     Ok(())
 }
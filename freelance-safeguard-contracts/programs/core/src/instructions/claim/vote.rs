@@ -27,6 +27,7 @@ pub struct VoteOnClaim<'info> {
         ],
         bump = claim.bump,
         constraint = claim.status == ClaimStatus::PendingVote @ FreelanceShieldError::ClaimNotPendingVote,
+        constraint = !claim.commit_reveal @ FreelanceShieldError::CommitRevealVotingRequired,
         constraint = Clock::get()?.unix_timestamp <= claim.voting_end_date @ FreelanceShieldError::VotingPeriodEnded
     )]
     pub claim: Account<'info, Claim>,
@@ -48,6 +49,11 @@ pub fn handler(ctx: Context<VoteOnClaim>, params: VoteOnClaimParams) -> Result<(
         FreelanceShieldError::InvalidVoteReason
     );
     
+    require!(
+        claim.votes.len() < MAX_CLAIM_VOTES,
+        FreelanceShieldError::TooManyVotes
+    );
+    
     // Check if voter has already voted
     for vote in &claim.votes {
         if vote.voter == ctx.accounts.voter.key() {
@@ -64,45 +70,7 @@ pub fn handler(ctx: Context<VoteOnClaim>, params: VoteOnClaimParams) -> Result<(
     });
     
     // Update claim status if minimum votes reached
-    if claim.votes.len() >= program_state.min_votes_required as usize {
-        // Count votes
-        let mut approve_count = 0;
-        let mut reject_count = 0;
-        
-        for vote in &claim.votes {
-            if vote.approve {
-                approve_count += 1;
-            } else {
-                reject_count += 1;
-            }
-        }
-        
-        // Determine outcome based on majority
-        let total_votes = approve_count + reject_count;
-        let approve_percentage = (approve_count * 100) / total_votes;
-        
-        // If 2/3 majority is reached, process the claim
-        if approve_percentage >= 67 {
-            claim.status = ClaimStatus::Approved;
-            claim.verdict = Some(Verdict {
-                approved: true,
-                reason: format!("Approved by community vote ({}/{})", approve_count, total_votes),
-                processed_at: clock.unix_timestamp,
-                processor: ProcessorType::Community,
-            });
-        } else if reject_count >= (total_votes * 2) / 3 {
-            claim.status = ClaimStatus::Rejected;
-            claim.verdict = Some(Verdict {
-                approved: false,
-                reason: format!("Rejected by community vote ({}/{})", reject_count, total_votes),
-                processed_at: clock.unix_timestamp,
-                processor: ProcessorType::Community,
-            });
-        } else if total_votes >= program_state.min_votes_required as usize * 2 {
-            // If we have double the minimum votes but no clear majority, send to arbitration
-            claim.status = ClaimStatus::InArbitration;
-        }
-    }
+    tally_votes(claim, program_state.min_votes_required, &clock, false);
     
     claim.last_update_slot = clock.slot;
     
@@ -111,6 +79,51 @@ pub fn handler(ctx: Context<VoteOnClaim>, params: VoteOnClaimParams) -> Result<(
     Ok(())
 }
 
+/// Tally the recorded votes and update the claim status
+///
+/// A 2/3 majority approves or rejects once the minimum vote count is reached.
+/// Without a majority the claim goes to arbitration once twice the minimum has
+/// voted, or immediately on a final tally (e.g. after a reveal phase closes).
+pub(crate) fn tally_votes(claim: &mut Claim, min_votes_required: u8, clock: &Clock, final_tally: bool) {
+    let total_votes = claim.votes.len();
+    
+    if total_votes < min_votes_required as usize || total_votes == 0 {
+        if final_tally {
+            claim.status = ClaimStatus::InArbitration;
+        }
+        return;
+    }
+    
+    // Count votes
+    let approve_count = claim.votes.iter().filter(|v| v.approve).count();
+    let reject_count = total_votes - approve_count;
+    
+    // Determine outcome based on majority
+    let approve_percentage = (approve_count * 100) / total_votes;
+    
+    // If 2/3 majority is reached, process the claim
+    if approve_percentage >= 67 {
+        claim.status = ClaimStatus::Approved;
+        claim.verdict = Some(Verdict {
+            approved: true,
+            reason: format!("Approved by community vote ({}/{})", approve_count, total_votes),
+            processed_at: clock.unix_timestamp,
+            processor: ProcessorType::Community,
+        });
+    } else if reject_count >= (total_votes * 2) / 3 {
+        claim.status = ClaimStatus::Rejected;
+        claim.verdict = Some(Verdict {
+            approved: false,
+            reason: format!("Rejected by community vote ({}/{})", reject_count, total_votes),
+            processed_at: clock.unix_timestamp,
+            processor: ProcessorType::Community,
+        });
+    } else if final_tally || total_votes >= min_votes_required as usize * 2 {
+        // If we have double the minimum votes but no clear majority, send to arbitration
+        claim.status = ClaimStatus::InArbitration;
+    }
+}
+
 /// Get the claim index from the policy account
 fn get_claim_index(policy_pubkey: &Pubkey, claim_pubkey: Pubkey) -> Result<u8> {
     // In a real implementation, we would query the policy account to get the claim index
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for initializing the fraud model
+#[derive(Accounts)]
+pub struct InitializeFraudModel<'info> {
+    /// Program authority
+    #[account(
+        mut,
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Fraud model PDA
+    #[account(
+        init,
+        payer = authority,
+        space = FraudModel::SIZE,
+        seeds = [FraudModel::SEED_PREFIX],
+        bump
+    )]
+    pub fraud_model: Account<'info, FraudModel>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize the fraud model with the default weights
+pub fn handler(ctx: Context<InitializeFraudModel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let fraud_model = &mut ctx.accounts.fraud_model;
+    
+    fraud_model.version = 1;
+    fraud_model.governance_authority = None;
+    fraud_model.policy_risk_weight_bps = DEFAULT_FRAUD_POLICY_RISK_WEIGHT_BPS;
+    fraud_model.amount_ratio_weight_bps = DEFAULT_FRAUD_AMOUNT_RATIO_WEIGHT_BPS;
+    fraud_model.policy_age_weight_bps = DEFAULT_FRAUD_POLICY_AGE_WEIGHT_BPS;
+    fraud_model.claims_history_weight_bps = DEFAULT_FRAUD_CLAIMS_HISTORY_WEIGHT_BPS;
+    fraud_model.anomaly_weight_bps = DEFAULT_FRAUD_ANOMALY_WEIGHT_BPS;
+    fraud_model.claims_history_step = DEFAULT_FRAUD_CLAIMS_HISTORY_STEP;
+    fraud_model.anomaly_saturation_sigma_bps = DEFAULT_FRAUD_ANOMALY_SATURATION_SIGMA_BPS;
+    fraud_model.min_anomaly_samples = DEFAULT_FRAUD_MIN_ANOMALY_SAMPLES;
+    fraud_model.updated_at = clock.unix_timestamp;
+    fraud_model.updated_by = ctx.accounts.authority.key();
+    fraud_model.bump = *ctx.bumps.get("fraud_model").unwrap();
+    
+    msg!("Fraud model initialized: Version: {}", fraud_model.version);
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod initialize_model;
+pub mod update_model;
+
+pub use initialize_model::*;
+pub use update_model::*;
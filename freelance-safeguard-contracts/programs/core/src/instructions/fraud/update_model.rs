@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for updating the fraud model
+#[derive(Accounts)]
+pub struct UpdateFraudModel<'info> {
+    /// Program authority or the model's governance authority
+    #[account(
+        constraint = fraud_model.can_update(&authority.key(), &program_state.authority) @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Fraud model PDA
+    #[account(
+        mut,
+        seeds = [FraudModel::SEED_PREFIX],
+        bump = fraud_model.bump
+    )]
+    pub fraud_model: Account<'info, FraudModel>,
+}
+
+/// Update the fraud model; every update produces a new model version
+pub fn handler(ctx: Context<UpdateFraudModel>, params: UpdateFraudModelParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.key();
+    let fraud_model = &mut ctx.accounts.fraud_model;
+    
+    // Only the admin can hand model updates over to governance
+    if let Some(governance_authority) = params.governance_authority {
+        require!(
+            authority == ctx.accounts.program_state.authority,
+            FreelanceShieldError::Unauthorized
+        );
+        fraud_model.governance_authority = governance_authority;
+    }
+    
+    if let Some(weight) = params.policy_risk_weight_bps {
+        fraud_model.policy_risk_weight_bps = weight;
+    }
+    
+    if let Some(weight) = params.amount_ratio_weight_bps {
+        fraud_model.amount_ratio_weight_bps = weight;
+    }
+    
+    if let Some(weight) = params.policy_age_weight_bps {
+        fraud_model.policy_age_weight_bps = weight;
+    }
+    
+    if let Some(weight) = params.claims_history_weight_bps {
+        fraud_model.claims_history_weight_bps = weight;
+    }
+    
+    if let Some(weight) = params.anomaly_weight_bps {
+        fraud_model.anomaly_weight_bps = weight;
+    }
+    
+    if let Some(claims_history_step) = params.claims_history_step {
+        require!(claims_history_step <= 100, FreelanceShieldError::InvalidParameter);
+        fraud_model.claims_history_step = claims_history_step;
+    }
+    
+    if let Some(anomaly_saturation_sigma_bps) = params.anomaly_saturation_sigma_bps {
+        require!(anomaly_saturation_sigma_bps > 0, FreelanceShieldError::InvalidParameter);
+        fraud_model.anomaly_saturation_sigma_bps = anomaly_saturation_sigma_bps;
+    }
+    
+    if let Some(min_anomaly_samples) = params.min_anomaly_samples {
+        fraud_model.min_anomaly_samples = min_anomaly_samples;
+    }
+    
+    require!(
+        fraud_model.weights_valid(),
+        FreelanceShieldError::InvalidFraudModelWeights
+    );
+    
+    fraud_model.version = fraud_model.version
+        .checked_add(1)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+    fraud_model.updated_at = clock.unix_timestamp;
+    fraud_model.updated_by = authority;
+    
+    msg!("Fraud model updated: Version: {}", fraud_model.version);
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::utils::*;
+use crate::interfaces::reputation::UserProfileView;
+use crate::FreelanceShieldError;
+
+/// Accounts for adding a member to a group policy
+#[derive(Accounts)]
+#[instruction(params: AddGroupMemberParams)]
+pub struct AddGroupMember<'info> {
+    /// Group admin (pays the member's premium)
+    #[account(
+        mut,
+        constraint = group_policy.admin == admin.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Product the group is insured under
+    #[account(
+        mut,
+        address = group_policy.product @ FreelanceShieldError::InvalidParameter,
+        constraint = product.active @ FreelanceShieldError::ProductInactive
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Group policy PDA
+    #[account(
+        mut,
+        seeds = [GroupPolicy::SEED_PREFIX, group_policy.admin.as_ref(), group_policy.product.as_ref()],
+        bump = group_policy.bump,
+        constraint = group_policy.status == PolicyStatus::Active @ FreelanceShieldError::PolicyNotActive
+    )]
+    pub group_policy: Account<'info, GroupPolicy>,
+    
+    /// Member certificate PDA
+    #[account(
+        init,
+        payer = admin,
+        space = GroupMember::SIZE,
+        seeds = [GroupMember::SEED_PREFIX, group_policy.key().as_ref(), params.member.as_ref()],
+        bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+    
+    /// Member's reputation profile PDA (may be uninitialized)
+    /// CHECK: address and contents are checked by `UserProfileView::load_score`
+    pub member_profile: UncheckedAccount<'info>,
+    
+    /// Risk pool account PDA
+    #[account(
+        mut,
+        seeds = [RiskPool::SEED_PREFIX],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+    
+    /// Admin's token account for paying the premium
+    #[account(
+        mut,
+        constraint = admin_token_account.owner == admin.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = admin_token_account.mint == group_policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub admin_token_account: Account<'info, TokenAccount>,
+    
+    /// Risk pool token account receiving the premium
+    #[account(
+        mut,
+        constraint = risk_pool_token_account.owner == risk_pool.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = risk_pool_token_account.mint == group_policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub risk_pool_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Add a member certificate, priced at the group's blended risk
+pub fn handler(ctx: Context<AddGroupMember>, params: AddGroupMemberParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_state = &mut ctx.accounts.program_state;
+    let product = &mut ctx.accounts.product;
+    let group_policy = &mut ctx.accounts.group_policy;
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    
+    require!(
+        clock.unix_timestamp < group_policy.end_date,
+        FreelanceShieldError::PolicyNotActive
+    );
+    
+    require!(
+        params.coverage_amount > 0 && params.coverage_amount <= group_policy.per_member_limit,
+        FreelanceShieldError::InvalidCoverageAmount
+    );
+    
+    require!(
+        group_policy.allocated_coverage + params.coverage_amount <= group_policy.aggregate_limit,
+        FreelanceShieldError::GroupAggregateLimitExceeded
+    );
+    
+    require!(
+        group_policy.member_count < MAX_GROUP_MEMBERS,
+        FreelanceShieldError::GroupMemberLimitReached
+    );
+    
+    // Members joining mid-term pay for the remaining period only
+    let remaining_days = (((group_policy.end_date - clock.unix_timestamp) + 86399) / 86400) as u16;
+    
+    // Individual risk comes from on-chain data only: the member's reputation
+    // profile, and the riskiest industry since a member's industry is unverified
+    let reputation_score = UserProfileView::load_score(
+        &ctx.accounts.member_profile.to_account_info(),
+        &params.member,
+    )?;
+    let industry = Industry::riskiest(&program_state.industry_risk_weights);
+    
+    // Industry weights are stored x10 around 10, so x5 maps them onto 0-100
+    let industry_risk = (industry.risk_weight(&program_state.industry_risk_weights) as u16 * 5).min(100) as u8;
+    let risk_score = calculate_risk_score(
+        params.coverage_amount,
+        remaining_days,
+        reputation_score,
+        product.risk_factor,
+        industry_risk,
+    )?;
+    
+    // Price the member at the blend including them, not their individual risk
+    group_policy.add_member_risk(params.coverage_amount, risk_score);
+    let premium_amount = calculate_premium(
+        params.coverage_amount,
+        remaining_days,
+        group_policy.blended_risk_score,
+        product.base_premium_rate,
+        product.premium_multiplier,
+    )?;
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.admin_token_account.to_account_info(),
+        to: ctx.accounts.risk_pool_token_account.to_account_info(),
+        authority: ctx.accounts.admin.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    
+    token::transfer(cpi_ctx, premium_amount)?;
+    
+    // Initialize certificate
+    let group_member = &mut ctx.accounts.group_member;
+    group_member.group = group_policy.key();
+    group_member.member = params.member;
+    group_member.coverage_amount = params.coverage_amount;
+    group_member.risk_score = risk_score;
+    group_member.job_type = params.job_type;
+    group_member.industry = industry;
+    group_member.premium_paid = premium_amount;
+    group_member.status = PolicyStatus::Active;
+    group_member.claims_count = 0;
+    group_member.claims_paid = 0;
+    group_member.joined_at = clock.unix_timestamp;
+    group_member.bump = *ctx.bumps.get("group_member").unwrap();
+    
+    group_policy.total_premiums += premium_amount;
+    
+    // Update product statistics
+    product.active_policies += 1;
+    product.total_coverage += params.coverage_amount;
+    product.total_premiums += premium_amount;
+    
+    // Update program state statistics
+    program_state.active_policies += 1;
+    program_state.total_coverage += params.coverage_amount;
+    program_state.total_coverage_liability += params.coverage_amount;
+    program_state.total_premiums += premium_amount;
+    program_state.total_premiums_stable += program_state.stable_value(&group_policy.premium_mint, premium_amount);
+    
+    // Update risk pool
+    risk_pool.total_premiums_collected += premium_amount;
+    risk_pool.total_coverage_liability += params.coverage_amount;
+    
+    msg!("Group member added: {}, Coverage: {}, Premium: {}, Blended risk: {}",
+        params.member, params.coverage_amount, premium_amount, group_policy.blended_risk_score);
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for deciding a member claim that ended in arbitration
+///
+/// Arbitration panels settle against a `Policy`, so member claims that reach
+/// arbitration are decided by the program authority instead.
+#[derive(Accounts)]
+pub struct ArbitrateMemberClaim<'info> {
+    /// Program authority
+    #[account(
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Group policy PDA
+    #[account(
+        seeds = [GroupPolicy::SEED_PREFIX, group_policy.admin.as_ref(), group_policy.product.as_ref()],
+        bump = group_policy.bump
+    )]
+    pub group_policy: Account<'info, GroupPolicy>,
+    
+    /// Member certificate PDA
+    #[account(
+        seeds = [GroupMember::SEED_PREFIX, group_policy.key().as_ref(), group_member.member.as_ref()],
+        bump = group_member.bump,
+        constraint = group_member.status == PolicyStatus::ClaimPending @ FreelanceShieldError::PolicyNotInClaimPending
+    )]
+    pub group_member: Account<'info, GroupMember>,
+    
+    /// Latest claim filed against the certificate
+    #[account(
+        mut,
+        seeds = [Claim::SEED_PREFIX, group_member.key().as_ref(), &[claim.index]],
+        bump = claim.bump,
+        constraint = claim.index + 1 == group_member.claims_count @ FreelanceShieldError::InvalidParameter,
+        constraint = (claim.status == ClaimStatus::Disputed ||
+                     claim.status == ClaimStatus::InArbitration)
+                     @ FreelanceShieldError::ClaimNotInArbitration
+    )]
+    pub claim: Account<'info, Claim>,
+}
+
+/// Decide a disputed member claim; `settle_member_claim` then pays or releases it
+pub fn handler(ctx: Context<ArbitrateMemberClaim>, approved: bool, reason: String) -> Result<()> {
+    let clock = Clock::get()?;
+    let claim = &mut ctx.accounts.claim;
+    let program_state = &mut ctx.accounts.program_state;
+    
+    require!(
+        reason.len() <= MAX_REASON_LENGTH,
+        FreelanceShieldError::InvalidReason
+    );
+    
+    if approved {
+        claim.status = ClaimStatus::Approved;
+        program_state.approved_claims += 1;
+    } else {
+        claim.status = ClaimStatus::Rejected;
+        program_state.rejected_claims += 1;
+    }
+    
+    claim.verdict = Some(Verdict {
+        approved,
+        reason,
+        processed_at: clock.unix_timestamp,
+        processor: ProcessorType::Arbitration,
+    });
+    claim.last_update_slot = clock.slot;
+    
+    program_state.arbitrated_claims += 1;
+    
+    msg!("Member claim arbitrated: Member: {}, Approved: {}", ctx.accounts.group_member.member, approved);
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for creating a group policy
+#[derive(Accounts)]
+pub struct CreateGroupPolicy<'info> {
+    /// Agency admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Product the group is insured under
+    #[account(
+        constraint = product.active @ FreelanceShieldError::ProductInactive
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Group policy PDA
+    #[account(
+        init,
+        payer = admin,
+        space = GroupPolicy::SIZE,
+        seeds = [GroupPolicy::SEED_PREFIX, admin.key().as_ref(), product.key().as_ref()],
+        bump
+    )]
+    pub group_policy: Account<'info, GroupPolicy>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a master policy that members are added to
+pub fn handler(ctx: Context<CreateGroupPolicy>, params: CreateGroupPolicyParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_state = &ctx.accounts.program_state;
+    let product = &ctx.accounts.product;
+    
+    require!(
+        params.per_member_limit >= product.min_coverage_amount &&
+        params.per_member_limit <= product.max_coverage_amount,
+        FreelanceShieldError::InvalidCoverageAmount
+    );
+    
+    require!(
+        params.aggregate_limit >= params.per_member_limit,
+        FreelanceShieldError::InvalidGroupLimits
+    );
+    
+    require!(
+        params.period_days >= product.min_period_days &&
+        params.period_days <= product.max_period_days,
+        FreelanceShieldError::InvalidPeriod
+    );
+    
+    let group_policy = &mut ctx.accounts.group_policy;
+    group_policy.admin = ctx.accounts.admin.key();
+    group_policy.product = product.key();
+    group_policy.premium_mint = product.premium_mint;
+    group_policy.per_member_limit = params.per_member_limit;
+    group_policy.aggregate_limit = params.aggregate_limit;
+    group_policy.allocated_coverage = 0;
+    group_policy.risk_weighted_coverage = 0;
+    group_policy.blended_risk_score = 0;
+    group_policy.member_count = 0;
+    group_policy.total_premiums = 0;
+    group_policy.total_claims_paid = 0;
+    group_policy.status = PolicyStatus::Active;
+    group_policy.start_date = clock.unix_timestamp;
+    group_policy.end_date = clock.unix_timestamp + (params.period_days as i64 * 86400);
+    group_policy.claim_period_end = group_policy.end_date + (program_state.claim_period_days as i64 * 86400);
+    group_policy.bump = *ctx.bumps.get("group_policy").unwrap();
+    
+    msg!("Group policy created: Admin: {}, Aggregate limit: {}", 
+        group_policy.admin, group_policy.aggregate_limit);
+    Ok(())
+}
@@ -0,0 +1,13 @@
+pub mod create;
+pub mod add_member;
+pub mod remove_member;
+pub mod submit_member_claim;
+pub mod settle_member_claim;
+pub mod arbitrate_member_claim;
+
+pub use create::*;
+pub use add_member::*;
+pub use remove_member::*;
+pub use submit_member_claim::*;
+pub use settle_member_claim::*;
+pub use arbitrate_member_claim::*;
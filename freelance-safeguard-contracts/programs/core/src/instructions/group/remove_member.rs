@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for removing a member from a group policy
+#[derive(Accounts)]
+pub struct RemoveGroupMember<'info> {
+    /// Group admin (receives the certificate rent)
+    #[account(
+        mut,
+        constraint = group_policy.admin == admin.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Product the group is insured under
+    #[account(
+        mut,
+        address = group_policy.product @ FreelanceShieldError::InvalidParameter
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Group policy PDA
+    #[account(
+        mut,
+        seeds = [GroupPolicy::SEED_PREFIX, group_policy.admin.as_ref(), group_policy.product.as_ref()],
+        bump = group_policy.bump
+    )]
+    pub group_policy: Account<'info, GroupPolicy>,
+    
+    /// Member certificate PDA
+    #[account(
+        mut,
+        close = admin,
+        seeds = [GroupMember::SEED_PREFIX, group_policy.key().as_ref(), group_member.member.as_ref()],
+        bump = group_member.bump,
+        constraint = group_member.status != PolicyStatus::ClaimPending @ FreelanceShieldError::GroupMemberClaimPending
+    )]
+    pub group_member: Account<'info, GroupMember>,
+    
+    /// Risk pool account PDA
+    #[account(
+        mut,
+        seeds = [RiskPool::SEED_PREFIX],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+}
+
+/// Remove a member certificate and release its coverage
+/// Premiums already paid are not refunded
+pub fn handler(ctx: Context<RemoveGroupMember>) -> Result<()> {
+    let group_member = &ctx.accounts.group_member;
+    let group_policy = &mut ctx.accounts.group_policy;
+    let product = &mut ctx.accounts.product;
+    let program_state = &mut ctx.accounts.program_state;
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    
+    group_policy.remove_member_risk(group_member.coverage_amount, group_member.risk_score);
+    
+    // Release the member's coverage liability
+    product.active_policies = product.active_policies.saturating_sub(1);
+    product.total_coverage = product.total_coverage.saturating_sub(group_member.coverage_amount);
+    program_state.active_policies = program_state.active_policies.saturating_sub(1);
+    program_state.total_coverage = program_state.total_coverage.saturating_sub(group_member.coverage_amount);
+    
+    // A paid certificate's liability was already released when its claim settled
+    if group_member.status != PolicyStatus::ClaimPaid {
+        program_state.total_coverage_liability = program_state.total_coverage_liability.saturating_sub(group_member.coverage_amount);
+        risk_pool.total_coverage_liability = risk_pool.total_coverage_liability.saturating_sub(group_member.coverage_amount);
+    }
+    
+    msg!("Group member removed: {}, Blended risk: {}", 
+        group_member.member, group_policy.blended_risk_score);
+    Ok(())
+}
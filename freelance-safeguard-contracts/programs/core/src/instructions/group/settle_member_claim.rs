@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for settling a decided claim against a member certificate
+#[derive(Accounts)]
+pub struct SettleMemberClaim<'info> {
+    /// Program authority
+    #[account(
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Group policy PDA
+    #[account(
+        mut,
+        seeds = [GroupPolicy::SEED_PREFIX, group_policy.admin.as_ref(), group_policy.product.as_ref()],
+        bump = group_policy.bump
+    )]
+    pub group_policy: Account<'info, GroupPolicy>,
+    
+    /// Member certificate PDA
+    #[account(
+        mut,
+        seeds = [GroupMember::SEED_PREFIX, group_policy.key().as_ref(), group_member.member.as_ref()],
+        bump = group_member.bump,
+        constraint = group_member.status == PolicyStatus::ClaimPending @ FreelanceShieldError::PolicyNotInClaimPending
+    )]
+    pub group_member: Account<'info, GroupMember>,
+    
+    /// Product the group is insured under
+    #[account(
+        mut,
+        address = group_policy.product @ FreelanceShieldError::InvalidParameter
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Latest claim filed against the certificate
+    #[account(
+        mut,
+        seeds = [Claim::SEED_PREFIX, group_member.key().as_ref(), &[claim.index]],
+        bump = claim.bump,
+        constraint = claim.index + 1 == group_member.claims_count @ FreelanceShieldError::InvalidParameter,
+        constraint = (claim.status == ClaimStatus::Approved ||
+                     claim.status == ClaimStatus::Rejected)
+                     @ FreelanceShieldError::ClaimNotApproved
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Risk pool account PDA
+    #[account(
+        mut,
+        seeds = [RiskPool::SEED_PREFIX],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+    
+    /// Risk pool token account paying the claim
+    #[account(
+        mut,
+        constraint = program_token_account.owner == risk_pool.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = program_token_account.mint == group_policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub program_token_account: Account<'info, TokenAccount>,
+    
+    /// Member's token account receiving the payout
+    #[account(
+        mut,
+        constraint = member_token_account.owner == group_member.member @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = member_token_account.mint == group_policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub member_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pay an approved member claim, or release the certificate after a rejection
+pub fn handler(ctx: Context<SettleMemberClaim>) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    if ctx.accounts.claim.status == ClaimStatus::Rejected {
+        ctx.accounts.group_member.status = PolicyStatus::ClaimRejected;
+        msg!("Member claim rejected: Certificate released");
+        return Ok(());
+    }
+    
+    let amount = ctx.accounts.claim.amount;
+    require!(
+        amount <= ctx.accounts.group_policy.remaining_aggregate(),
+        FreelanceShieldError::GroupAggregateLimitExceeded
+    );
+    require!(
+        ctx.accounts.program_token_account.amount >= amount,
+        FreelanceShieldError::InsufficientFundsForTokenTransfer
+    );
+    
+    let seeds = &[
+        RiskPool::SEED_PREFIX,
+        &[ctx.accounts.risk_pool.bump]
+    ];
+    let signer = &[&seeds[..]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.program_token_account.to_account_info(),
+                to: ctx.accounts.member_token_account.to_account_info(),
+                authority: ctx.accounts.risk_pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+    
+    let claim = &mut ctx.accounts.claim;
+    claim.status = ClaimStatus::Paid;
+    claim.last_update_slot = clock.slot;
+    
+    // A paid certificate is spent, so its coverage leaves the liability totals
+    let group_member = &mut ctx.accounts.group_member;
+    group_member.status = PolicyStatus::ClaimPaid;
+    group_member.claims_paid += amount;
+    
+    let group_policy = &mut ctx.accounts.group_policy;
+    group_policy.total_claims_paid += amount;
+    
+    let product = &mut ctx.accounts.product;
+    product.claims_paid_amount = product.claims_paid_amount
+        .checked_add(amount)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+    if product.total_premiums > 0 {
+        product.loss_ratio = ((product.claims_paid_amount as u128 * 100) / product.total_premiums as u128) as u16;
+    }
+    
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_claims_paid += amount;
+    program_state.total_claims_paid_stable += program_state.stable_value(&group_policy.premium_mint, amount);
+    program_state.total_coverage_liability = program_state.total_coverage_liability
+        .saturating_sub(group_member.coverage_amount);
+    
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    risk_pool.total_claims_paid += amount;
+    risk_pool.total_coverage_liability = risk_pool.total_coverage_liability
+        .saturating_sub(group_member.coverage_amount);
+    
+    msg!("Member claim paid: Member: {}, Amount: {}", group_member.member, amount);
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::claim::intake::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for a member filing a claim against their certificate
+#[derive(Accounts)]
+pub struct SubmitMemberClaim<'info> {
+    /// Covered member
+    #[account(
+        mut,
+        constraint = group_member.member == member.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub member: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Group policy PDA
+    #[account(
+        seeds = [GroupPolicy::SEED_PREFIX, group_policy.admin.as_ref(), group_policy.product.as_ref()],
+        bump = group_policy.bump,
+        constraint = group_policy.status == PolicyStatus::Active @ FreelanceShieldError::PolicyNotActive,
+        constraint = Clock::get()?.unix_timestamp <= group_policy.claim_period_end @ FreelanceShieldError::ClaimPeriodEnded
+    )]
+    pub group_policy: Account<'info, GroupPolicy>,
+    
+    /// Member certificate PDA
+    #[account(
+        mut,
+        seeds = [GroupMember::SEED_PREFIX, group_policy.key().as_ref(), member.key().as_ref()],
+        bump = group_member.bump,
+        constraint = (group_member.status == PolicyStatus::Active ||
+                     group_member.status == PolicyStatus::ClaimRejected)
+                     @ FreelanceShieldError::PolicyNotActive
+    )]
+    pub group_member: Account<'info, GroupMember>,
+    
+    /// Product the group is insured under
+    #[account(
+        mut,
+        address = group_policy.product @ FreelanceShieldError::InvalidParameter
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Fraud model PDA used to score the claim
+    #[account(
+        seeds = [FraudModel::SEED_PREFIX],
+        bump = fraud_model.bump
+    )]
+    pub fraud_model: Account<'info, FraudModel>,
+    
+    /// Global claim circuit breaker PDA
+    #[account(
+        mut,
+        seeds = [CircuitBreaker::SEED_PREFIX, Pubkey::default().as_ref()],
+        bump = global_breaker.bump
+    )]
+    pub global_breaker: Account<'info, CircuitBreaker>,
+    
+    /// Product claim circuit breaker PDA
+    #[account(
+        mut,
+        seeds = [CircuitBreaker::SEED_PREFIX, product.key().as_ref()],
+        bump = product_breaker.bump
+    )]
+    pub product_breaker: Account<'info, CircuitBreaker>,
+    
+    /// Claim account PDA (keyed by the certificate in place of a policy)
+    #[account(
+        init,
+        payer = member,
+        space = Claim::BASE_SIZE + 
+                (4 + (MAX_EVIDENCE_ATTACHMENTS * (4 + MAX_EVIDENCE_HASH_LENGTH))) +
+                (4 + (MAX_CLAIM_VOTES * Vote::SIZE)),
+        seeds = [
+            Claim::SEED_PREFIX,
+            group_member.key().as_ref(),
+            &[group_member.claims_count]
+        ],
+        bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Submit a claim against a group member certificate
+/// The claim then follows the regular voting flow and is closed out with `settle_member_claim`
+pub fn handler(ctx: Context<SubmitMemberClaim>, params: SubmitClaimParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let claim = &mut ctx.accounts.claim;
+    let group_policy = &ctx.accounts.group_policy;
+    let group_member = &mut ctx.accounts.group_member;
+    let program_state = &mut ctx.accounts.program_state;
+    
+    // Scored against the member's own certificate, which runs from joining to the group's end
+    let subject = ClaimSubject {
+        key: group_member.key(),
+        risk_score: group_member.risk_score,
+        coverage_amount: group_member.coverage_amount,
+        start_date: group_member.joined_at,
+        end_date: group_policy.end_date,
+        claims_count: group_member.claims_count,
+    };
+    validate_submission(&params, &subject)?;
+    require!(
+        params.amount <= group_policy.remaining_aggregate(),
+        FreelanceShieldError::InvalidClaimAmount
+    );
+    
+    // Certificates are never escrow-bound, so any counterparty stays unverified
+    let amount = params.amount;
+    open_claim(
+        claim,
+        &subject,
+        ctx.accounts.member.key(),
+        params,
+        false,
+        program_state,
+        &ctx.accounts.product,
+        &clock,
+    )?;
+    score_claim(claim, &subject, &ctx.accounts.fraud_model, &mut ctx.accounts.product, &clock)?;
+    claim.bump = *ctx.bumps.get("claim").unwrap();
+    
+    // Update certificate
+    group_member.claims_count += 1;
+    group_member.status = PolicyStatus::ClaimPending;
+    
+    route_claim(
+        claim,
+        program_state,
+        &mut ctx.accounts.global_breaker,
+        &mut ctx.accounts.product_breaker,
+        &clock,
+    );
+    
+    msg!("Member claim submitted: Member: {}, Amount: {}, Risk Score: {}", 
+        claim.owner, amount, claim.risk_score);
+    Ok(())
+}
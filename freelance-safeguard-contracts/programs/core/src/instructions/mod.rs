@@ -5,6 +5,12 @@ pub mod claim;
 pub mod risk;
 pub mod treasury;
 pub mod reputation;
+pub mod arbitration;
+pub mod subrogation;
+pub mod fraud;
+pub mod circuit_breaker;
+pub mod referral;
+pub mod group;
 
 // Re-exports
 pub use program::*;
@@ -14,3 +20,9 @@ pub use claim::*;
 pub use risk::*;
 pub use treasury::*;
 pub use reputation::*;
+pub use arbitration::*;
+pub use subrogation::*;
+pub use fraud::*;
+pub use circuit_breaker::*;
+pub use referral::*;
+pub use group::*;
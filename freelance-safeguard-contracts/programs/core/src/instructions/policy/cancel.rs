@@ -38,13 +38,22 @@ pub struct CancelPolicy<'info> {
         seeds = [
             Policy::SEED_PREFIX, 
             policy.owner.as_ref(),
-            policy.product_id.as_ref()
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
         ],
         bump = policy.bump,
         constraint = policy.status == PolicyStatus::Active @ FreelanceShieldError::PolicyNotActive
     )]
     pub policy: Account<'info, Policy>,
     
+    /// Owner's policy index
+    #[account(
+        mut,
+        seeds = [PolicyIndex::SEED_PREFIX, policy.owner.as_ref()],
+        bump = policy_index.bump
+    )]
+    pub policy_index: Account<'info, PolicyIndex>,
+    
     /// Risk pool account
     #[account(
         mut,
@@ -54,13 +63,17 @@ pub struct CancelPolicy<'info> {
     pub risk_pool: Account<'info, RiskPool>,
     
     /// Program's token account for refund
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = program_token_account.mint == policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
     pub program_token_account: Account<'info, TokenAccount>,
     
     /// Owner's token account for receiving refund
     #[account(
         mut,
-        constraint = refund_token_account.owner == policy.owner @ FreelanceShieldError::InvalidTokenAccount
+        constraint = refund_token_account.owner == policy.owner @ FreelanceShieldError::InvalidTokenAccount,
+        constraint = refund_token_account.mint == policy.premium_mint @ FreelanceShieldError::MintMismatch
     )]
     pub refund_token_account: Account<'info, TokenAccount>,
     
@@ -113,6 +126,7 @@ pub fn handler(ctx: Context<CancelPolicy>) -> Result<()> {
     // Update policy status
     policy.status = PolicyStatus::Cancelled;
     policy.last_update_slot = clock.slot;
+    ctx.accounts.policy_index.remove_policy(&policy.key());
     
     // Update product statistics
     product.total_coverage -= policy.coverage_amount;
@@ -2,9 +2,15 @@ pub mod purchase;
 pub mod cancel;
 pub mod renew;
 pub mod tokenize;
+pub mod purchase_escrow;
+pub mod sync_escrow;
+pub mod prune_index;
 
 pub use purchase::*;
 pub use cancel::*;
 pub use renew::*;
 pub use tokenize::*;
+pub use purchase_escrow::*;
+pub use sync_escrow::*;
+pub use prune_index::*;
 
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for dropping a closed policy from its owner's index
+#[derive(Accounts)]
+pub struct PrunePolicyIndex<'info> {
+    /// Anyone may prune a closed policy
+    pub caller: Signer<'info>,
+    
+    /// Policy account PDA
+    #[account(
+        seeds = [
+            Policy::SEED_PREFIX,
+            policy.owner.as_ref(),
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
+        ],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Owner's policy index
+    #[account(
+        mut,
+        seeds = [PolicyIndex::SEED_PREFIX, policy.owner.as_ref()],
+        bump = policy_index.bump,
+        constraint = policy_index.policies.contains(&policy.key()) @ FreelanceShieldError::PolicyNotIndexed
+    )]
+    pub policy_index: Account<'info, PolicyIndex>,
+}
+
+/// Drop a policy that can no longer be claimed on from its owner's index
+/// Expired, cancelled and paid-out policies qualify, as do policies whose claim period has lapsed
+pub fn handler(ctx: Context<PrunePolicyIndex>) -> Result<()> {
+    let clock = Clock::get()?;
+    let policy = &ctx.accounts.policy;
+    
+    let closed = match policy.status {
+        PolicyStatus::Expired | PolicyStatus::Cancelled | PolicyStatus::ClaimPaid => true,
+        PolicyStatus::Active | PolicyStatus::GracePeriod | PolicyStatus::ClaimRejected =>
+            clock.unix_timestamp > policy.claim_period_end,
+        PolicyStatus::ClaimPending => false,
+    };
+    require!(closed, FreelanceShieldError::PolicyStillOpen);
+    
+    ctx.accounts.policy_index.remove_policy(&policy.key());
+    
+    msg!("Policy pruned from index: Owner: {}, Policy: {}", policy.owner, policy.key());
+    Ok(())
+}
@@ -14,6 +14,7 @@ pub struct PurchasePolicy<'info> {
     
     /// Program state PDA
     #[account(
+        mut,
         seeds = [ProgramState::SEED_PREFIX],
         bump = program_state.bump,
     )]
@@ -50,6 +51,16 @@ pub struct PurchasePolicy<'info> {
     )]
     pub protocol_treasury: SystemAccount<'info>,
     
+    /// Owner's policy index, created with the owner's first policy
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = PolicyIndex::SIZE,
+        seeds = [PolicyIndex::SEED_PREFIX, owner.key().as_ref()],
+        bump,
+    )]
+    pub policy_index: Account<'info, PolicyIndex>,
+    
     /// New policy account to be initialized
     #[account(
         init,
@@ -59,6 +70,7 @@ pub struct PurchasePolicy<'info> {
             Policy::SEED_PREFIX,
             owner.key().as_ref(),
             product.key().as_ref(),
+            &policy_index.next_nonce.to_le_bytes(),
         ],
         bump,
     )]
@@ -68,6 +80,7 @@ pub struct PurchasePolicy<'info> {
     #[account(
         mut,
         constraint = owner_token_account.owner == owner.key() @ FreelanceShieldError::Unauthorized,
+        constraint = owner_token_account.mint == product.premium_mint @ FreelanceShieldError::MintMismatch,
     )]
     pub owner_token_account: Account<'info, TokenAccount>,
     
@@ -75,9 +88,18 @@ pub struct PurchasePolicy<'info> {
     #[account(
         mut,
         constraint = risk_pool_token_account.owner == risk_pool.key() @ FreelanceShieldError::InvalidParameters,
+        constraint = risk_pool_token_account.mint == product.premium_mint @ FreelanceShieldError::MintMismatch,
     )]
     pub risk_pool_token_account: Account<'info, TokenAccount>,
     
+    /// Referrer credited with the sale (required when `params.referrer` is set)
+    #[account(mut)]
+    pub referrer: Option<Account<'info, Referrer>>,
+    
+    /// Referrer's payout token account (required for immediate commission payouts)
+    #[account(mut)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+    
     /// System program for creating the policy account
     pub system_program: Program<'info, System>,
     
@@ -102,6 +124,9 @@ pub struct PurchasePolicyParams {
     
     /// Optional authentication metadata for Privy integration
     pub auth_metadata: Option<String>,
+    
+    /// Optional referrer wallet credited with the sale
+    pub referrer: Option<Pubkey>,
 }
 
 /// Purchase an insurance policy
@@ -110,8 +135,9 @@ pub fn handler(ctx: Context<PurchasePolicy>, params: PurchasePolicyParams) -> Re
     let product = &ctx.accounts.product;
     let risk_pool = &mut ctx.accounts.risk_pool;
     let domain_treasury = &mut ctx.accounts.domain_treasury;
+    let policy_index = &mut ctx.accounts.policy_index;
     let owner = &ctx.accounts.owner;
-    let program_state = &ctx.accounts.program_state;
+    let program_state = &mut ctx.accounts.program_state;
     let clock = Clock::get()?;
     
     // Validate policy parameters
@@ -150,16 +176,75 @@ pub fn handler(ctx: Context<PurchasePolicy>, params: PurchasePolicyParams) -> Re
     // Initialize policy data
     policy.owner = owner.key();
     policy.product = product.key();
+    policy.nonce = policy_index.next_nonce;
     policy.coverage_amount = params.coverage_amount;
     policy.premium_amount = premium_calculation.total_premium;
+    policy.premium_mint = product.premium_mint;
     policy.start_date = clock.unix_timestamp;
     policy.end_date = clock.unix_timestamp + params.duration;
     policy.status = PolicyStatus::Active;
     policy.claims_count = 0;
     policy.active_claims_count = 0;
     policy.metadata = params.metadata;
+    policy.referrer = params.referrer;
     policy.bump = *ctx.bumps.get("policy").unwrap();
     
+    // Record the policy on the owner's index and advance the nonce
+    require!(
+        policy_index.policies.len() < MAX_POLICIES_PER_OWNER,
+        FreelanceShieldError::PolicyIndexFull
+    );
+    policy_index.owner = owner.key();
+    policy_index.next_nonce += 1;
+    policy_index.policies.push(policy.key());
+    policy_index.bump = *ctx.bumps.get("policy_index").unwrap();
+    
+    // Credit the referrer, paying the commission out of the premium if it settles immediately
+    let mut immediate_commission = 0;
+    if let Some(referrer_wallet) = params.referrer {
+        let referrer = ctx.accounts.referrer
+            .as_mut()
+            .ok_or(FreelanceShieldError::ReferrerAccountRequired)?;
+        require!(
+            referrer.wallet == referrer_wallet && referrer.active,
+            FreelanceShieldError::InvalidReferrer
+        );
+        require!(referrer_wallet != ctx.accounts.owner.key(), FreelanceShieldError::SelfReferral);
+        require!(referrer.mint == product.premium_mint, FreelanceShieldError::MintMismatch);
+        
+        let commission = referrer.commission_for(
+            premium_calculation.total_premium,
+            program_state.max_referral_commission_bps,
+        );
+        referrer.record_sale(premium_calculation.total_premium, commission, clock.unix_timestamp);
+        
+        if referrer.payout_mode == CommissionPayoutMode::Immediate && commission > 0 {
+            let referrer_token_account = ctx.accounts.referrer_token_account
+                .as_ref()
+                .ok_or(FreelanceShieldError::ReferrerAccountRequired)?;
+            require!(
+                referrer_token_account.key() == referrer.payout_token_account,
+                FreelanceShieldError::TokenAccountMismatch
+            );
+            
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: referrer_token_account.to_account_info(),
+                authority: owner.to_account_info(),
+            };
+            
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+            );
+            
+            token::transfer(cpi_ctx, commission)?;
+            immediate_commission = commission;
+        }
+        
+        msg!("Referral credited to {}: Commission: {}", referrer_wallet, commission);
+    }
+    
     // Transfer premium tokens
     let cpi_accounts = Transfer {
         from: ctx.accounts.owner_token_account.to_account_info(),
@@ -172,13 +257,19 @@ pub fn handler(ctx: Context<PurchasePolicy>, params: PurchasePolicyParams) -> Re
         cpi_accounts,
     );
     
-    token::transfer(cpi_ctx, premium_calculation.total_premium)?;
+    // Only the premium net of any commission already paid out reaches the pool
+    let net_premium = premium_calculation.total_premium - immediate_commission;
+    token::transfer(cpi_ctx, net_premium)?;
     
     // Update risk pool data
     risk_pool.total_policies += 1;
     risk_pool.active_policies += 1;
     risk_pool.total_coverage += params.coverage_amount;
-    risk_pool.total_premiums += premium_calculation.total_premium;
+    risk_pool.total_premiums += net_premium;
+    
+    // Update program state statistics
+    program_state.total_premiums += premium_calculation.total_premium;
+    program_state.total_premiums_stable += program_state.stable_value(&product.premium_mint, premium_calculation.total_premium);
     
     // Update domain treasury data
     domain_treasury.total_policies += 1;
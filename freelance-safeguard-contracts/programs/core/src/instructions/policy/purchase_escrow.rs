@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::utils::*;
+use crate::interfaces::escrow::{EscrowMode, EscrowStatus, EscrowView, ESCROW_PROGRAM_ID};
+use crate::FreelanceShieldError;
+
+/// Accounts for purchasing a policy bound to an escrow
+#[derive(Accounts)]
+pub struct PurchaseEscrowPolicy<'info> {
+    /// Freelancer on the escrow
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Product the policy is for
+    #[account(
+        mut,
+        constraint = product.active @ FreelanceShieldError::ProductInactive
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Escrow the policy covers
+    /// CHECK: owner is the escrow program; contents are decoded in the handler
+    #[account(
+        owner = ESCROW_PROGRAM_ID @ FreelanceShieldError::InvalidEscrowAccount
+    )]
+    pub escrow: UncheckedAccount<'info>,
+    
+    /// Marker binding the escrow to this policy; its existence blocks a second policy
+    #[account(
+        init,
+        payer = owner,
+        space = EscrowCover::SIZE,
+        seeds = [EscrowCover::SEED_PREFIX, escrow.key().as_ref()],
+        bump
+    )]
+    pub escrow_cover: Account<'info, EscrowCover>,
+    
+    /// Owner's policy index, created with the owner's first policy
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = PolicyIndex::SIZE,
+        seeds = [PolicyIndex::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub policy_index: Account<'info, PolicyIndex>,
+    
+    /// New policy account
+    #[account(
+        init,
+        payer = owner,
+        space = Policy::SIZE,
+        seeds = [
+            Policy::SEED_PREFIX,
+            owner.key().as_ref(),
+            product.key().as_ref(),
+            &policy_index.next_nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Risk pool account PDA
+    #[account(
+        mut,
+        seeds = [RiskPool::SEED_PREFIX],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+    
+    /// Owner's token account for paying the premium
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = owner_token_account.mint == product.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    
+    /// Risk pool token account receiving the premium
+    #[account(
+        mut,
+        constraint = risk_pool_token_account.owner == risk_pool.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = risk_pool_token_account.mint == product.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub risk_pool_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Purchase a policy whose coverage and term come from an escrow
+/// Coverage is the unreleased milestone value and the term ends at the escrow deadline
+pub fn handler(ctx: Context<PurchaseEscrowPolicy>, params: PurchaseEscrowPolicyParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let escrow = EscrowView::load(&ctx.accounts.escrow.to_account_info())?;
+    
+    require!(
+        escrow.freelancer == ctx.accounts.owner.key(),
+        FreelanceShieldError::Unauthorized
+    );
+    require!(
+        escrow.status == EscrowStatus::Active && escrow.deadline > clock.unix_timestamp,
+        FreelanceShieldError::EscrowNotActive
+    );
+    require!(
+        escrow.mint == ctx.accounts.product.premium_mint,
+        FreelanceShieldError::MintMismatch
+    );
+    require!(
+        escrow.mode == EscrowMode::Milestones,
+        FreelanceShieldError::StreamEscrowNotSupported
+    );
+    
+    let coverage_amount = escrow.unreleased_value();
+    require!(coverage_amount > 0, FreelanceShieldError::InvalidCoverageAmount);
+    
+    let product = &mut ctx.accounts.product;
+    let program_state = &mut ctx.accounts.program_state;
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    let policy_index = &mut ctx.accounts.policy_index;
+    let policy = &mut ctx.accounts.policy;
+    
+    require!(
+        coverage_amount <= product.max_coverage_amount,
+        FreelanceShieldError::InvalidCoverageAmount
+    );
+    
+    let period_days = (((escrow.deadline - clock.unix_timestamp) + 86399) / 86400) as u16;
+    require!(
+        period_days >= product.min_period_days && period_days <= product.max_period_days,
+        FreelanceShieldError::InvalidPeriod
+    );
+    
+    if let Some(details) = &params.policy_details {
+        require!(
+            details.len() <= Policy::MAX_POLICY_DETAILS_LENGTH,
+            FreelanceShieldError::InvalidParameter
+        );
+    }
+    
+    let premium_amount = calculate_premium(
+        coverage_amount,
+        period_days,
+        product.risk_factor,
+        product.base_premium_rate,
+        product.premium_multiplier,
+    )?;
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        to: ctx.accounts.risk_pool_token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    
+    token::transfer(cpi_ctx, premium_amount)?;
+    
+    // Initialize policy
+    policy.owner = ctx.accounts.owner.key();
+    policy.product_id = product.key();
+    policy.nonce = policy_index.next_nonce;
+    policy.coverage_amount = coverage_amount;
+    policy.premium_amount = premium_amount;
+    policy.premium_mint = product.premium_mint;
+    policy.start_date = clock.unix_timestamp;
+    policy.end_date = escrow.deadline;
+    policy.claim_period_end = escrow.deadline + (program_state.claim_period_days as i64 * 86400);
+    policy.status = PolicyStatus::Active;
+    policy.job_type = params.job_type;
+    policy.industry = params.industry;
+    policy.claims_count = 0;
+    policy.reputation_score = params.reputation_score.unwrap_or(50);
+    policy.risk_score = product.risk_factor;
+    policy.policy_details = params.policy_details.unwrap_or_default();
+    policy.creation_block = clock.slot;
+    policy.last_update_slot = clock.slot;
+    policy.nft_mint = None;
+    policy.referrer = None; // Escrow policies are bought directly; renewals check any referrer named then
+    policy.escrow = Some(ctx.accounts.escrow.key());
+    policy.bump = *ctx.bumps.get("policy").unwrap();
+    
+    let escrow_cover = &mut ctx.accounts.escrow_cover;
+    escrow_cover.escrow = ctx.accounts.escrow.key();
+    escrow_cover.policy = policy.key();
+    escrow_cover.bump = *ctx.bumps.get("escrow_cover").unwrap();
+    
+    // Record the policy on the owner's index and advance the nonce
+    require!(
+        policy_index.policies.len() < MAX_POLICIES_PER_OWNER,
+        FreelanceShieldError::PolicyIndexFull
+    );
+    policy_index.owner = ctx.accounts.owner.key();
+    policy_index.next_nonce += 1;
+    policy_index.policies.push(policy.key());
+    policy_index.bump = *ctx.bumps.get("policy_index").unwrap();
+    
+    // Update product statistics
+    product.active_policies += 1;
+    product.total_coverage += coverage_amount;
+    product.total_premiums += premium_amount;
+    
+    // Update program state statistics
+    program_state.total_policies += 1;
+    program_state.active_policies += 1;
+    program_state.total_coverage += coverage_amount;
+    program_state.total_coverage_liability += coverage_amount;
+    program_state.total_premiums += premium_amount;
+    program_state.total_premiums_stable += program_state.stable_value(&policy.premium_mint, premium_amount);
+    
+    // Update risk pool
+    risk_pool.total_premiums_collected += premium_amount;
+    risk_pool.total_coverage_liability += coverage_amount;
+    
+    msg!("Escrow policy purchased: Escrow: {}, Coverage: {}, Premium: {}", 
+        ctx.accounts.escrow.key(), coverage_amount, premium_amount);
+    Ok(())
+}
@@ -37,7 +37,8 @@ pub struct RenewPolicy<'info> {
         seeds = [
             Policy::SEED_PREFIX, 
             policy.owner.as_ref(),
-            policy.product_id.as_ref()
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
         ],
         bump = policy.bump,
         constraint = (policy.status == PolicyStatus::Active || 
@@ -47,6 +48,14 @@ pub struct RenewPolicy<'info> {
     )]
     pub policy: Account<'info, Policy>,
     
+    /// Owner's policy index
+    #[account(
+        mut,
+        seeds = [PolicyIndex::SEED_PREFIX, policy.owner.as_ref()],
+        bump = policy_index.bump
+    )]
+    pub policy_index: Account<'info, PolicyIndex>,
+    
     /// Risk pool account
     #[account(
         mut,
@@ -56,19 +65,33 @@ pub struct RenewPolicy<'info> {
     pub risk_pool: Account<'info, RiskPool>,
     
     /// Owner's token account for payment
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
     pub owner_token_account: Account<'info, TokenAccount>,
     
     /// Program's token account for receiving payment
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = program_token_account.mint == policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
     pub program_token_account: Account<'info, TokenAccount>,
     
+    /// Referrer credited with the renewal (required when the policy or renewal names one)
+    #[account(mut)]
+    pub referrer: Option<Account<'info, Referrer>>,
+    
+    /// Referrer's payout token account (required for immediate commission payouts)
+    #[account(mut)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+    
     /// Token program
     pub token_program: Program<'info, Token>,
 }
 
 /// Renew an insurance policy
-pub fn handler(ctx: Context<RenewPolicy>, period_days: u16) -> Result<()> {
+pub fn handler(ctx: Context<RenewPolicy>, period_days: u16, referrer: Option<Pubkey>) -> Result<()> {
     let clock = Clock::get()?;
     let policy = &mut ctx.accounts.policy;
     let product = &mut ctx.accounts.product;
@@ -108,6 +131,49 @@ pub fn handler(ctx: Context<RenewPolicy>, period_days: u16) -> Result<()> {
     // Apply loyalty discount (5% discount for renewal)
     let premium_amount = (base_premium * 95) / 100;
     
+    // A renewal can name a referrer; otherwise the original referrer is credited again
+    let referrer_wallet = referrer.or(policy.referrer);
+    let mut immediate_commission = 0;
+    if let Some(referrer_wallet) = referrer_wallet {
+        let referrer = ctx.accounts.referrer
+            .as_mut()
+            .ok_or(FreelanceShieldError::ReferrerAccountRequired)?;
+        require!(
+            referrer.wallet == referrer_wallet && referrer.active,
+            FreelanceShieldError::InvalidReferrer
+        );
+        require!(referrer_wallet != ctx.accounts.owner.key(), FreelanceShieldError::SelfReferral);
+        require!(referrer.mint == policy.premium_mint, FreelanceShieldError::MintMismatch);
+        
+        let commission = referrer.commission_for(premium_amount, program_state.max_referral_commission_bps);
+        referrer.record_sale(premium_amount, commission, current_time);
+        
+        if referrer.payout_mode == CommissionPayoutMode::Immediate && commission > 0 {
+            let referrer_token_account = ctx.accounts.referrer_token_account
+                .as_ref()
+                .ok_or(FreelanceShieldError::ReferrerAccountRequired)?;
+            require!(
+                referrer_token_account.key() == referrer.payout_token_account,
+                FreelanceShieldError::TokenAccountMismatch
+            );
+            
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: referrer_token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            
+            token::transfer(cpi_ctx, commission)?;
+            immediate_commission = commission;
+        }
+        
+        policy.referrer = Some(referrer_wallet);
+        msg!("Referral credited to {}: Commission: {}", referrer_wallet, commission);
+    }
+    
     // Transfer premium payment
     let cpi_accounts = Transfer {
         from: ctx.accounts.owner_token_account.to_account_info(),
@@ -118,7 +184,9 @@ pub fn handler(ctx: Context<RenewPolicy>, period_days: u16) -> Result<()> {
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     
-    token::transfer(cpi_ctx, premium_amount)?;
+    // Only the premium net of any commission already paid out reaches the pool
+    let net_premium = premium_amount - immediate_commission;
+    token::transfer(cpi_ctx, net_premium)?;
     
     // If policy was expired, we need to add the coverage back to the stats
     let was_expired = policy.status != PolicyStatus::Active;
@@ -131,6 +199,16 @@ pub fn handler(ctx: Context<RenewPolicy>, period_days: u16) -> Result<()> {
     policy.status = PolicyStatus::Active;
     policy.last_update_slot = clock.slot;
     
+    // A lapsed policy may have been pruned from the owner's index
+    let policy_index = &mut ctx.accounts.policy_index;
+    if !policy_index.policies.contains(&policy.key()) {
+        require!(
+            policy_index.policies.len() < MAX_POLICIES_PER_OWNER,
+            FreelanceShieldError::PolicyIndexFull
+        );
+        policy_index.policies.push(policy.key());
+    }
+    
     // Update product statistics
     if was_expired {
         product.total_coverage += policy.coverage_amount;
@@ -144,9 +222,10 @@ pub fn handler(ctx: Context<RenewPolicy>, period_days: u16) -> Result<()> {
         program_state.total_coverage_liability += policy.coverage_amount;
     }
     program_state.total_premiums += premium_amount;
+    program_state.total_premiums_stable += program_state.stable_value(&policy.premium_mint, premium_amount);
     
     // Update risk pool
-    risk_pool.total_premiums_collected += premium_amount;
+    risk_pool.total_premiums_collected += net_premium;
     if was_expired {
         risk_pool.total_coverage_liability += policy.coverage_amount;
     }
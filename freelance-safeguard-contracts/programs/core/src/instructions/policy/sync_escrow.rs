@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::interfaces::escrow::{EscrowStatus, EscrowView};
+use crate::FreelanceShieldError;
+
+/// Accounts for syncing an escrow-bound policy with its escrow
+#[derive(Accounts)]
+pub struct SyncEscrowPolicy<'info> {
+    /// Anyone may crank the sync
+    pub caller: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Product account PDA
+    #[account(
+        mut,
+        seeds = [Product::SEED_PREFIX, &policy.product_id.to_bytes()],
+        bump
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Policy account PDA
+    #[account(
+        mut,
+        seeds = [
+            Policy::SEED_PREFIX,
+            policy.owner.as_ref(),
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
+        ],
+        bump = policy.bump,
+        constraint = policy.escrow == Some(escrow.key()) @ FreelanceShieldError::NotEscrowPolicy,
+        constraint = policy.status == PolicyStatus::Active @ FreelanceShieldError::PolicyNotActive
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Escrow the policy is bound to
+    /// CHECK: validated against `policy.escrow` and decoded in the handler
+    pub escrow: UncheckedAccount<'info>,
+    
+    /// Owner's policy index
+    #[account(
+        mut,
+        seeds = [PolicyIndex::SEED_PREFIX, policy.owner.as_ref()],
+        bump = policy_index.bump
+    )]
+    pub policy_index: Account<'info, PolicyIndex>,
+    
+    /// Risk pool account PDA
+    #[account(
+        mut,
+        seeds = [RiskPool::SEED_PREFIX],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+    
+    /// Risk pool token account funding refunds
+    #[account(
+        mut,
+        constraint = program_token_account.owner == risk_pool.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = program_token_account.mint == policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub program_token_account: Account<'info, TokenAccount>,
+    
+    /// Owner's token account receiving any refund
+    #[account(
+        mut,
+        constraint = refund_token_account.owner == policy.owner @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = refund_token_account.mint == policy.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub refund_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Bring an escrow-bound policy in line with its escrow
+/// Released milestones reduce coverage; a completed escrow expires the policy and
+/// refunds the premium for the unused part of the term. Cancelled and resolved
+/// escrows leave the policy claimable until its claim period ends
+pub fn handler(ctx: Context<SyncEscrowPolicy>) -> Result<()> {
+    let clock = Clock::get()?;
+    let escrow = EscrowView::load(&ctx.accounts.escrow.to_account_info())?;
+    require!(escrow.mint == ctx.accounts.policy.premium_mint, FreelanceShieldError::MintMismatch);
+    
+    let policy = &mut ctx.accounts.policy;
+    let product = &mut ctx.accounts.product;
+    let program_state = &mut ctx.accounts.program_state;
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    
+    // Coverage only ever shrinks as milestones are released
+    let new_coverage = escrow.unreleased_value().min(policy.coverage_amount);
+    let released = policy.coverage_amount - new_coverage;
+    
+    product.total_coverage = product.total_coverage.saturating_sub(released);
+    program_state.total_coverage = program_state.total_coverage.saturating_sub(released);
+    program_state.total_coverage_liability = program_state.total_coverage_liability.saturating_sub(released);
+    risk_pool.total_coverage_liability = risk_pool.total_coverage_liability.saturating_sub(released);
+    policy.coverage_amount = new_coverage;
+    policy.last_update_slot = clock.slot;
+    
+    if escrow.status != EscrowStatus::Completed {
+        msg!("Escrow policy synced: Coverage: {}", new_coverage);
+        return Ok(());
+    }
+    
+    // Refund unused premium pro rata, unless the policy has been claimed on
+    let total_term = policy.end_date - policy.start_date;
+    let unused_term = (policy.end_date - clock.unix_timestamp).max(0);
+    let refund_amount = if policy.claims_count == 0 && total_term > 0 {
+        ((policy.premium_amount as u128 * unused_term as u128) / total_term as u128) as u64
+    } else {
+        0
+    };
+    
+    if refund_amount > 0 {
+        let seeds = &[
+            RiskPool::SEED_PREFIX,
+            &[risk_pool.bump]
+        ];
+        let signer = &[&seeds[..]];
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.program_token_account.to_account_info(),
+            to: ctx.accounts.refund_token_account.to_account_info(),
+            authority: risk_pool.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        
+        token::transfer(cpi_ctx, refund_amount)?;
+        
+        product.total_premiums = product.total_premiums.saturating_sub(refund_amount);
+        program_state.total_premiums = program_state.total_premiums.saturating_sub(refund_amount);
+        risk_pool.total_premiums_collected = risk_pool.total_premiums_collected.saturating_sub(refund_amount);
+    }
+    
+    policy.status = PolicyStatus::Expired;
+    policy.end_date = clock.unix_timestamp.min(policy.end_date);
+    ctx.accounts.policy_index.remove_policy(&policy.key());
+    
+    product.active_policies = product.active_policies.saturating_sub(1);
+    program_state.active_policies = program_state.active_policies.saturating_sub(1);
+    
+    msg!("Escrow completed: Policy expired, Refund amount: {}", refund_amount);
+    Ok(())
+}
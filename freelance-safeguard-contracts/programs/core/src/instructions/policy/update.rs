@@ -25,7 +25,8 @@ pub struct UpdatePolicy<'info> {
         seeds = [
             Policy::SEED_PREFIX, 
             owner.key().as_ref(),
-            policy.product_id.as_ref()
+            policy.product_id.as_ref(),
+            &policy.nonce.to_le_bytes()
         ],
         bump = policy.bump,
         constraint = policy.status == PolicyStatus::Active @ FreelanceShieldError::PolicyNotActive
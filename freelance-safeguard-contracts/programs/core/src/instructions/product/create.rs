@@ -51,6 +51,11 @@ pub fn handler(ctx: Context<CreateProduct>, params: CreateProductParams) -> Resu
         FreelanceShieldError::InvalidProductDescription
     );
     
+    require!(
+        program_state.allowed_mint(&params.premium_mint).is_some(),
+        FreelanceShieldError::MintNotAllowed
+    );
+    
     // Initialize product
     product.authority = ctx.accounts.authority.key();
     product.product_name = params.product_name;
@@ -61,6 +66,8 @@ pub fn handler(ctx: Context<CreateProduct>, params: CreateProductParams) -> Resu
     product.min_stake_to_capital_ratio = params.min_stake_to_capital_ratio;
     product.cover_terms = params.cover_terms;
     product.active = true;
+    product.commit_reveal_voting = false;
+    product.premium_mint = params.premium_mint;
     product.min_period_days = 30; // Set reasonable default values
     product.max_period_days = 365; // Set reasonable default values
     product.base_premium_rate = 100; // Set reasonable default values
@@ -71,6 +78,9 @@ pub fn handler(ctx: Context<CreateProduct>, params: CreateProductParams) -> Resu
     product.claims_count = 0;
     product.claims_paid_amount = 0;
     product.loss_ratio = 0;
+    product.claim_amount_samples = 0;
+    product.claim_amount_mean = 0;
+    product.claim_amount_m2 = 0;
     product.min_coverage_amount = 100; // Set reasonable default minimum
     product.max_coverage_amount = 10000000; // Set reasonable default maximum
     product.created_at = clock.unix_timestamp;
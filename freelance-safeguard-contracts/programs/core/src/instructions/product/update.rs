@@ -104,6 +104,10 @@ pub fn handler(ctx: Context<UpdateProduct>, params: UpdateProductParams) -> Resu
         product.active = active;
     }
     
+    if let Some(commit_reveal_voting) = params.commit_reveal_voting {
+        product.commit_reveal_voting = commit_reveal_voting;
+    }
+    
     // Update timestamp
     product.last_updated = clock.unix_timestamp;
     
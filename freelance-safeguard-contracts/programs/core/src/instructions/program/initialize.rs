@@ -51,6 +51,15 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     program_state.auto_process_threshold = params.auto_process_threshold;
     program_state.min_votes_required = params.min_votes_required;
     program_state.voting_period_days = params.voting_period_days;
+    program_state.commit_reveal_voting = false;
+    program_state.reveal_period_days = DEFAULT_REVEAL_PERIOD_DAYS;
+    program_state.vote_commit_bond = DEFAULT_VOTE_COMMIT_BOND;
+    program_state.arbitration_panel_size = DEFAULT_ARBITRATION_PANEL_SIZE;
+    program_state.min_arbitrator_stake = DEFAULT_MIN_ARBITRATOR_STAKE;
+    program_state.arbitration_period_days = DEFAULT_ARBITRATION_PERIOD_DAYS;
+    program_state.arbitrator_slash_percentage = DEFAULT_ARBITRATOR_SLASH_PERCENTAGE;
+    program_state.counterparty_response_days = DEFAULT_COUNTERPARTY_RESPONSE_DAYS;
+    program_state.max_referral_commission_bps = DEFAULT_MAX_REFERRAL_COMMISSION_BPS;
     
     // Set premium calculation parameters
     program_state.base_premium_rate = params.base_premium_rate;
@@ -74,12 +83,17 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     program_state.rejected_claims = 0;
     program_state.arbitrated_claims = 0;
     program_state.premium_to_claims_ratio = 100; // Default 100%
+    program_state.total_premiums_stable = 0;
+    program_state.total_claims_paid_stable = 0;
     
     // Initialize risk pool stats
     program_state.total_capital = 0;
     program_state.total_coverage_liability = 0;
     program_state.current_reserve_ratio = 0;
     
+    // Mints are added to the allowlist after initialization
+    program_state.allowed_mints = Vec::new();
+    
     // Set program status
     program_state.is_paused = false;
     program_state.last_update_timestamp = clock.unix_timestamp;
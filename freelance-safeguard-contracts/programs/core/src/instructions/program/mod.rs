@@ -1,6 +1,10 @@
 pub mod initialize;
 pub mod update;
+pub mod set_allowed_mint;
+pub mod remove_allowed_mint;
 
 pub use initialize::*;
 pub use update::*;
+pub use set_allowed_mint::*;
+pub use remove_allowed_mint::*;
 
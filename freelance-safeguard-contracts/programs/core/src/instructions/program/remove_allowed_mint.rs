@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for removing a mint from the allowlist
+#[derive(Accounts)]
+pub struct RemoveAllowedMint<'info> {
+    /// Program authority
+    #[account(
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+/// Remove a mint from the allowlist
+/// Existing policies keep their mint; new products can no longer use it
+pub fn handler(ctx: Context<RemoveAllowedMint>, mint: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_state = &mut ctx.accounts.program_state;
+    
+    require!(
+        program_state.allowed_mint(&mint).is_some(),
+        FreelanceShieldError::MintNotAllowed
+    );
+    
+    program_state.allowed_mints.retain(|m| m.mint != mint);
+    program_state.last_update_timestamp = clock.unix_timestamp;
+    
+    msg!("Allowed mint removed: {}", mint);
+    Ok(())
+}
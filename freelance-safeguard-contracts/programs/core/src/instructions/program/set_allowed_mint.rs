@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for adding a mint to the allowlist or updating its reporting value
+#[derive(Accounts)]
+pub struct SetAllowedMint<'info> {
+    /// Program authority
+    #[account(
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        mut,
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Token mint
+    pub mint: Account<'info, Mint>,
+}
+
+/// Add a mint to the allowlist, or update the reporting value of a listed mint
+pub fn handler(ctx: Context<SetAllowedMint>, stable_value_micros: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let mint = &ctx.accounts.mint;
+    let program_state = &mut ctx.accounts.program_state;
+    
+    let entry = AllowedMint {
+        mint: mint.key(),
+        decimals: mint.decimals,
+        stable_value_micros,
+        updated_at: clock.unix_timestamp,
+    };
+    
+    if let Some(existing) = program_state.allowed_mints.iter_mut().find(|m| m.mint == entry.mint) {
+        *existing = entry;
+    } else {
+        require!(
+            program_state.allowed_mints.len() < MAX_ALLOWED_MINTS,
+            FreelanceShieldError::MintAllowlistFull
+        );
+        program_state.allowed_mints.push(entry);
+    }
+    
+    program_state.last_update_timestamp = clock.unix_timestamp;
+    
+    msg!("Allowed mint set: {}, Stable value: {}", entry.mint, stable_value_micros);
+    Ok(())
+}
@@ -92,6 +92,58 @@ pub fn handler(ctx: Context<UpdateProgramParameters>, params: UpdateProgramParam
         program_state.voting_period_days = voting_period_days;
     }
     
+    if let Some(commit_reveal_voting) = params.commit_reveal_voting {
+        program_state.commit_reveal_voting = commit_reveal_voting;
+    }
+    
+    if let Some(reveal_period_days) = params.reveal_period_days {
+        require!(reveal_period_days > 0, FreelanceShieldError::InvalidParameter);
+        program_state.reveal_period_days = reveal_period_days;
+    }
+    
+    if let Some(vote_commit_bond) = params.vote_commit_bond {
+        program_state.vote_commit_bond = vote_commit_bond;
+    }
+    
+    if let Some(arbitration_panel_size) = params.arbitration_panel_size {
+        // Panels need an odd size so a strict majority always exists
+        require!(
+            arbitration_panel_size > 0 &&
+            arbitration_panel_size % 2 == 1 &&
+            arbitration_panel_size as usize <= MAX_PANEL_SIZE,
+            FreelanceShieldError::InvalidParameter
+        );
+        program_state.arbitration_panel_size = arbitration_panel_size;
+    }
+    
+    if let Some(min_arbitrator_stake) = params.min_arbitrator_stake {
+        program_state.min_arbitrator_stake = min_arbitrator_stake;
+    }
+    
+    if let Some(arbitration_period_days) = params.arbitration_period_days {
+        require!(arbitration_period_days > 0, FreelanceShieldError::InvalidParameter);
+        program_state.arbitration_period_days = arbitration_period_days;
+    }
+    
+    if let Some(arbitrator_slash_percentage) = params.arbitrator_slash_percentage {
+        require!(arbitrator_slash_percentage <= 100, FreelanceShieldError::InvalidParameter);
+        program_state.arbitrator_slash_percentage = arbitrator_slash_percentage;
+    }
+    
+    if let Some(counterparty_response_days) = params.counterparty_response_days {
+        require!(counterparty_response_days > 0, FreelanceShieldError::InvalidParameter);
+        program_state.counterparty_response_days = counterparty_response_days;
+    }
+    
+    if let Some(max_referral_commission_bps) = params.max_referral_commission_bps {
+        // Lowering the cap does not touch existing referrers; new sales are clamped to it
+        require!(
+            max_referral_commission_bps <= REFERRAL_COMMISSION_DENOMINATOR,
+            FreelanceShieldError::InvalidParameter
+        );
+        program_state.max_referral_commission_bps = max_referral_commission_bps;
+    }
+    
     // Update premium calculation parameters if provided
     if let Some(base_premium_rate) = params.base_premium_rate {
         program_state.base_premium_rate = base_premium_rate;
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for claiming accrued referral commission
+#[derive(Accounts)]
+pub struct ClaimReferralCommission<'info> {
+    /// Referrer wallet
+    pub wallet: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Referrer PDA
+    #[account(
+        mut,
+        seeds = [Referrer::SEED_PREFIX, wallet.key().as_ref()],
+        bump = referrer.bump,
+        constraint = referrer.commission_accrued > 0 @ FreelanceShieldError::NoCommissionAccrued
+    )]
+    pub referrer: Account<'info, Referrer>,
+    
+    /// Risk pool account PDA
+    #[account(
+        mut,
+        seeds = [RiskPool::SEED_PREFIX],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+    
+    /// Risk pool token account holding the accrued commission
+    #[account(
+        mut,
+        constraint = program_token_account.owner == risk_pool.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = program_token_account.mint == referrer.mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub program_token_account: Account<'info, TokenAccount>,
+    
+    /// Referrer's payout token account
+    #[account(
+        mut,
+        address = referrer.payout_token_account @ FreelanceShieldError::TokenAccountMismatch
+    )]
+    pub payout_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pay out all commission accrued by a referrer
+pub fn handler(ctx: Context<ClaimReferralCommission>) -> Result<()> {
+    let clock = Clock::get()?;
+    let amount = ctx.accounts.referrer.commission_accrued;
+    
+    require!(
+        ctx.accounts.program_token_account.amount >= amount,
+        FreelanceShieldError::InsufficientFundsForTokenTransfer
+    );
+    
+    let seeds = &[
+        RiskPool::SEED_PREFIX,
+        &[ctx.accounts.risk_pool.bump]
+    ];
+    let signer = &[&seeds[..]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.program_token_account.to_account_info(),
+                to: ctx.accounts.payout_token_account.to_account_info(),
+                authority: ctx.accounts.risk_pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+    
+    // Accrued commission was booked as premium when the sale settled
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    risk_pool.total_premiums_collected = risk_pool.total_premiums_collected.saturating_sub(amount);
+    
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.commission_accrued = 0;
+    referrer.last_update = clock.unix_timestamp;
+    
+    msg!("Referral commission claimed: {}, Amount: {}", referrer.wallet, amount);
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod register;
+pub mod update;
+pub mod claim_commission;
+
+pub use register::*;
+pub use update::*;
+pub use claim_commission::*;
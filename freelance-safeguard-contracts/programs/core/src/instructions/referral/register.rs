@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for registering a referrer
+#[derive(Accounts)]
+#[instruction(params: RegisterReferrerParams)]
+pub struct RegisterReferrer<'info> {
+    /// Program authority
+    #[account(
+        mut,
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Referrer PDA
+    #[account(
+        init,
+        payer = authority,
+        space = Referrer::SIZE,
+        seeds = [Referrer::SEED_PREFIX, params.wallet.as_ref()],
+        bump
+    )]
+    pub referrer: Account<'info, Referrer>,
+    
+    /// Token account commissions are paid to
+    #[account(
+        address = params.payout_token_account @ FreelanceShieldError::TokenAccountMismatch,
+        constraint = payout_token_account.mint == params.mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub payout_token_account: Account<'info, TokenAccount>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Register a referrer with its commission rate
+pub fn handler(ctx: Context<RegisterReferrer>, params: RegisterReferrerParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_state = &ctx.accounts.program_state;
+    
+    require!(
+        params.commission_bps <= program_state.max_referral_commission_bps,
+        FreelanceShieldError::ReferralCommissionTooHigh
+    );
+    require!(
+        program_state.allowed_mint(&params.mint).is_some(),
+        FreelanceShieldError::MintNotAllowed
+    );
+    
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.wallet = params.wallet;
+    referrer.mint = params.mint;
+    referrer.payout_token_account = params.payout_token_account;
+    referrer.commission_bps = params.commission_bps;
+    referrer.payout_mode = params.payout_mode;
+    referrer.active = true;
+    referrer.policies_sold = 0;
+    referrer.premium_volume = 0;
+    referrer.claims_paid = 0;
+    referrer.loss_ratio = 0;
+    referrer.commission_earned = 0;
+    referrer.commission_accrued = 0;
+    referrer.registered_at = clock.unix_timestamp;
+    referrer.last_update = clock.unix_timestamp;
+    referrer.bump = *ctx.bumps.get("referrer").unwrap();
+    
+    msg!("Referrer registered: {}, Commission: {} bps", referrer.wallet, referrer.commission_bps);
+    Ok(())
+}
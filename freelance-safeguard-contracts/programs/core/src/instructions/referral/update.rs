@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for updating a referrer
+#[derive(Accounts)]
+pub struct UpdateReferrer<'info> {
+    /// Program authority
+    #[account(
+        constraint = program_state.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Program state PDA
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Referrer PDA
+    #[account(
+        mut,
+        seeds = [Referrer::SEED_PREFIX, referrer.wallet.as_ref()],
+        bump = referrer.bump
+    )]
+    pub referrer: Account<'info, Referrer>,
+}
+
+/// Update a referrer's commission terms or status
+pub fn handler(ctx: Context<UpdateReferrer>, params: UpdateReferrerParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_state = &ctx.accounts.program_state;
+    let referrer = &mut ctx.accounts.referrer;
+    
+    if let Some(payout_token_account) = params.payout_token_account {
+        referrer.payout_token_account = payout_token_account;
+    }
+    
+    if let Some(commission_bps) = params.commission_bps {
+        require!(
+            commission_bps <= program_state.max_referral_commission_bps,
+            FreelanceShieldError::ReferralCommissionTooHigh
+        );
+        referrer.commission_bps = commission_bps;
+    }
+    
+    if let Some(payout_mode) = params.payout_mode {
+        // Anything already accrued stays claimable after switching modes
+        referrer.payout_mode = payout_mode;
+    }
+    
+    if let Some(active) = params.active {
+        referrer.active = active;
+    }
+    
+    referrer.last_update = clock.unix_timestamp;
+    
+    msg!("Referrer updated: {}", referrer.wallet);
+    Ok(())
+}
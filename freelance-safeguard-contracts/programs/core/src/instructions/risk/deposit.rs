@@ -42,11 +42,17 @@ pub struct DepositCapital<'info> {
     pub capital_provider: Account<'info, CapitalProvider>,
     
     /// Provider's token account for payment
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = program_state.allowed_mint(&provider_token_account.mint).is_some() @ FreelanceShieldError::MintNotAllowed
+    )]
     pub provider_token_account: Account<'info, TokenAccount>,
     
     /// Program's token account for receiving capital
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = program_token_account.mint == provider_token_account.mint @ FreelanceShieldError::MintMismatch
+    )]
     pub program_token_account: Account<'info, TokenAccount>,
     
     /// Token program
@@ -61,6 +61,8 @@ pub fn handler(
     risk_pool.treasury_allocation_percentage = treasury_allocation_percentage;
     risk_pool.treasury_wallet = treasury_wallet;
     risk_pool.is_paused = false;
+    risk_pool.total_slashed_stake = 0;
+    risk_pool.total_forfeited_bonds = 0;
     risk_pool.bump = *ctx.bumps.get("risk_pool").unwrap();
     
     msg!("Risk pool initialized");
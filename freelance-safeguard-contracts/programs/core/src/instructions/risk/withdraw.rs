@@ -41,13 +41,17 @@ pub struct WithdrawCapital<'info> {
     pub capital_provider: Account<'info, CapitalProvider>,
     
     /// Program's token account for capital
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = program_state.allowed_mint(&program_token_account.mint).is_some() @ FreelanceShieldError::MintNotAllowed
+    )]
     pub program_token_account: Account<'info, TokenAccount>,
     
     /// Provider's token account for receiving withdrawal
     #[account(
         mut,
-        constraint = provider_token_account.owner == provider.key() @ FreelanceShieldError::InvalidTokenAccount
+        constraint = provider_token_account.owner == provider.key() @ FreelanceShieldError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == program_token_account.mint @ FreelanceShieldError::MintMismatch
     )]
     pub provider_token_account: Account<'info, TokenAccount>,
     
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for flagging a client who missed the subrogation repayment deadline
+#[derive(Accounts)]
+pub struct FlagSubrogationDefault<'info> {
+    /// Anyone can flag an overdue subrogation
+    pub payer: Signer<'info>,
+    
+    /// Subrogation record PDA
+    #[account(
+        mut,
+        seeds = [Subrogation::SEED_PREFIX, subrogation.claim.as_ref()],
+        bump = subrogation.bump,
+        constraint = matches!(
+            subrogation.status,
+            SubrogationStatus::Open | SubrogationStatus::PartiallyRecovered
+        ) @ FreelanceShieldError::SubrogationNotOverdue,
+        constraint = Clock::get()?.unix_timestamp > subrogation.repayment_deadline @ FreelanceShieldError::SubrogationNotOverdue
+    )]
+    pub subrogation: Account<'info, Subrogation>,
+    
+    /// Claim the subrogation recovers
+    #[account(
+        address = subrogation.claim @ FreelanceShieldError::InvalidParameter,
+        constraint = claim.recoverable_from_counterparty() @ FreelanceShieldError::ClaimNotRecoverable
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Debt summary of the client
+    #[account(
+        mut,
+        seeds = [DebtorRecord::SEED_PREFIX, subrogation.client.as_ref()],
+        bump = debtor_record.bump
+    )]
+    pub debtor_record: Account<'info, DebtorRecord>,
+}
+
+/// Mark an overdue subrogation as defaulted and flag the client as a bad debtor
+pub fn handler(ctx: Context<FlagSubrogationDefault>) -> Result<()> {
+    let clock = Clock::get()?;
+    let subrogation = &mut ctx.accounts.subrogation;
+    let debtor_record = &mut ctx.accounts.debtor_record;
+    
+    subrogation.status = SubrogationStatus::Defaulted;
+    
+    debtor_record.is_bad_debtor = true;
+    debtor_record.last_update = clock.unix_timestamp;
+    
+    msg!("Subrogation defaulted: Client: {}, Outstanding: {}",
+        subrogation.client, subrogation.outstanding());
+    Ok(())
+}
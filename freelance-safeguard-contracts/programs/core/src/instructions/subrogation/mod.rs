@@ -0,0 +1,5 @@
+pub mod repay;
+pub mod flag_default;
+
+pub use repay::*;
+pub use flag_default::*;
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::FreelanceShieldError;
+
+/// Accounts for repaying a subrogated claim into the risk pool
+#[derive(Accounts)]
+pub struct RepaySubrogation<'info> {
+    /// Payer (usually the client, but anyone may repay on their behalf)
+    pub payer: Signer<'info>,
+    
+    /// Subrogation record PDA
+    #[account(
+        mut,
+        seeds = [Subrogation::SEED_PREFIX, subrogation.claim.as_ref()],
+        bump = subrogation.bump,
+        constraint = subrogation.status != SubrogationStatus::Recovered @ FreelanceShieldError::SubrogationAlreadyRecovered
+    )]
+    pub subrogation: Account<'info, Subrogation>,
+    
+    /// Debt summary of the client
+    #[account(
+        mut,
+        seeds = [DebtorRecord::SEED_PREFIX, subrogation.client.as_ref()],
+        bump = debtor_record.bump
+    )]
+    pub debtor_record: Account<'info, DebtorRecord>,
+    
+    /// Product that absorbed the payout
+    #[account(
+        mut,
+        address = subrogation.product @ FreelanceShieldError::InvalidParameter
+    )]
+    pub product: Account<'info, Product>,
+    
+    /// Risk pool account PDA
+    #[account(
+        mut,
+        seeds = [RiskPool::SEED_PREFIX],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+    
+    /// Payer's token account
+    #[account(
+        mut,
+        constraint = payer_token_account.owner == payer.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = payer_token_account.mint == product.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+    
+    /// Risk pool token account receiving the repayment
+    #[account(
+        mut,
+        constraint = program_token_account.owner == risk_pool.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = program_token_account.mint == product.premium_mint @ FreelanceShieldError::MintMismatch
+    )]
+    pub program_token_account: Account<'info, TokenAccount>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Repay all or part of a subrogated claim
+pub fn handler(ctx: Context<RepaySubrogation>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // Never take more than is still owed
+    let amount = amount.min(ctx.accounts.subrogation.outstanding());
+    require!(amount > 0, FreelanceShieldError::InvalidRepaymentAmount);
+    
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer_token_account.to_account_info(),
+                to: ctx.accounts.program_token_account.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let subrogation = &mut ctx.accounts.subrogation;
+    subrogation.amount_recovered = subrogation.amount_recovered
+        .checked_add(amount)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+    subrogation.last_repayment_at = clock.unix_timestamp;
+    
+    let fully_recovered = subrogation.outstanding() == 0;
+    subrogation.status = if fully_recovered {
+        SubrogationStatus::Recovered
+    } else if subrogation.status == SubrogationStatus::Defaulted {
+        SubrogationStatus::Defaulted
+    } else {
+        SubrogationStatus::PartiallyRecovered
+    };
+    
+    // Recoveries reduce the product's net claims paid
+    let product = &mut ctx.accounts.product;
+    product.claims_paid_amount = product.claims_paid_amount.saturating_sub(amount);
+    if product.total_premiums > 0 {
+        product.loss_ratio = ((product.claims_paid_amount as u128 * 100) / product.total_premiums as u128) as u16;
+    }
+    
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    risk_pool.total_capital = risk_pool.total_capital
+        .checked_add(amount)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+    
+    let debtor_record = &mut ctx.accounts.debtor_record;
+    debtor_record.total_recovered = debtor_record.total_recovered
+        .checked_add(amount)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+    if fully_recovered {
+        debtor_record.open_subrogations = debtor_record.open_subrogations.saturating_sub(1);
+    }
+    // A client who has cleared every debt is no longer flagged
+    if debtor_record.open_subrogations == 0 {
+        debtor_record.is_bad_debtor = false;
+    }
+    debtor_record.last_update = clock.unix_timestamp;
+    
+    msg!("Subrogation repaid: Amount: {}, Outstanding: {}", amount, subrogation.outstanding());
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::domain_treasury::DomainTreasury;
+use crate::FreelanceShieldError;
+
+#[derive(Accounts)]
+pub struct ApplyTreasurySplits<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            DomainTreasury::DOMAIN_TREASURY_SEED.as_bytes(),
+            domain_treasury.domain.as_bytes()
+        ],
+        bump = domain_treasury.bump,
+        constraint = domain_treasury.authority == authority.key() @ FreelanceShieldError::Unauthorized,
+        constraint = !domain_treasury.pending_recipients.is_empty() @ FreelanceShieldError::NoPendingTreasurySplit
+    )]
+    pub domain_treasury: Account<'info, DomainTreasury>,
+}
+
+/// Apply a proposed premium split once its timelock has expired
+pub fn handler(ctx: Context<ApplyTreasurySplits>) -> Result<()> {
+    let clock = Clock::get()?;
+    let domain_treasury = &mut ctx.accounts.domain_treasury;
+    
+    require!(
+        clock.unix_timestamp >= domain_treasury.pending_recipients_timestamp,
+        FreelanceShieldError::TimelockNotExpired
+    );
+    
+    domain_treasury.recipients = std::mem::take(&mut domain_treasury.pending_recipients);
+    domain_treasury.pending_recipients_timestamp = 0;
+    domain_treasury.last_updated = clock.unix_timestamp;
+    
+    msg!("Applied premium split with {} recipients for {}",
+        domain_treasury.recipients.len(),
+        domain_treasury.domain
+    );
+    
+    Ok(())
+}
@@ -47,6 +47,11 @@ pub fn handler(
     domain_treasury.admin_wallet = admin_wallet;
     domain_treasury.risk_pool = risk_pool_program_id;
     domain_treasury.domain = domain;
+    // Premium splits are configured through the timelocked proposal flow
+    domain_treasury.recipients = Vec::new();
+    domain_treasury.pending_recipients = Vec::new();
+    domain_treasury.pending_recipients_timestamp = 0;
+    domain_treasury.total_routed = 0;
     domain_treasury.last_updated = Clock::get()?.unix_timestamp;
     domain_treasury.bump = bump;
     
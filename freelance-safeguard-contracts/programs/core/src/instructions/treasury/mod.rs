@@ -1,7 +1,13 @@
 pub mod initialize;
 pub mod update;
 pub mod send_payment;
+pub mod propose_splits;
+pub mod apply_splits;
+pub mod route_premium;
 
 pub use initialize::*;
 pub use update::*;
 pub use send_payment::*;
+pub use propose_splits::*;
+pub use apply_splits::*;
+pub use route_premium::*;
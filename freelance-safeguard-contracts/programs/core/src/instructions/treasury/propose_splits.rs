@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::domain_treasury::{DomainTreasury, TreasuryRecipient};
+use crate::FreelanceShieldError;
+use crate::TIMELOCK_DURATION;
+
+#[derive(Accounts)]
+pub struct ProposeTreasurySplits<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            DomainTreasury::DOMAIN_TREASURY_SEED.as_bytes(),
+            domain_treasury.domain.as_bytes()
+        ],
+        bump = domain_treasury.bump,
+        constraint = domain_treasury.authority == authority.key() @ FreelanceShieldError::Unauthorized
+    )]
+    pub domain_treasury: Account<'info, DomainTreasury>,
+}
+
+/// Propose a new premium split across treasury recipients
+/// The split only takes effect once the timelock has expired and it is applied
+pub fn handler(
+    ctx: Context<ProposeTreasurySplits>,
+    recipients: Vec<TreasuryRecipient>,
+) -> Result<()> {
+    require!(
+        DomainTreasury::validate_recipients(&recipients),
+        FreelanceShieldError::InvalidTreasurySplit
+    );
+    
+    let clock = Clock::get()?;
+    let domain_treasury = &mut ctx.accounts.domain_treasury;
+    
+    domain_treasury.pending_recipients = recipients;
+    domain_treasury.pending_recipients_timestamp = clock.unix_timestamp + TIMELOCK_DURATION;
+    domain_treasury.last_updated = clock.unix_timestamp;
+    
+    msg!("Proposed premium split with {} recipients for {}, executable after {}",
+        domain_treasury.pending_recipients.len(),
+        domain_treasury.domain,
+        domain_treasury.pending_recipients_timestamp
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::ProgramState;
+use crate::state::domain_treasury::{DomainTreasury, TreasuryRecipientKind};
+use crate::FreelanceShieldError;
+
+#[derive(Accounts)]
+pub struct RoutePremium<'info> {
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [ProgramState::SEED_PREFIX],
+        bump = program_state.bump,
+        constraint = !program_state.is_paused @ FreelanceShieldError::ProgramPaused
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            DomainTreasury::DOMAIN_TREASURY_SEED.as_bytes(),
+            domain_treasury.domain.as_bytes()
+        ],
+        bump = domain_treasury.bump,
+        constraint = !domain_treasury.recipients.is_empty() @ FreelanceShieldError::TreasurySplitNotConfigured
+    )]
+    pub domain_treasury: Account<'info, DomainTreasury>,
+    
+    /// Token account the premium is paid from
+    #[account(
+        mut,
+        constraint = payer_token_account.owner == payer.key() @ FreelanceShieldError::InvalidTokenAccountOwner,
+        constraint = program_state.allowed_mint(&payer_token_account.mint).is_some() @ FreelanceShieldError::MintNotAllowed
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    // Remaining accounts: the recipients' token accounts, in split order
+}
+
+/// Fan an incoming premium out to every treasury recipient in one transaction
+/// Rounding dust goes to the risk pool (or the first recipient if there is none)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RoutePremium<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, FreelanceShieldError::InvalidParameter);
+    
+    let recipients = ctx.accounts.domain_treasury.recipients.clone();
+    require!(
+        ctx.remaining_accounts.len() == recipients.len(),
+        FreelanceShieldError::TokenAccountMismatch
+    );
+    
+    // Work out every share up front so the split always sums to `amount`
+    let mut shares = Vec::with_capacity(recipients.len());
+    for recipient in recipients.iter() {
+        let share = (amount as u128 * recipient.share_bps as u128) / DomainTreasury::SPLIT_DENOMINATOR as u128;
+        shares.push(share as u64);
+    }
+    let dust = amount - shares.iter().sum::<u64>();
+    let dust_index = recipients
+        .iter()
+        .position(|r| r.kind == TreasuryRecipientKind::RiskPool)
+        .unwrap_or(0);
+    shares[dust_index] += dust;
+    
+    let mint = ctx.accounts.payer_token_account.mint;
+    for (i, recipient) in recipients.iter().enumerate() {
+        let destination = &ctx.remaining_accounts[i];
+        
+        require!(
+            destination.key() == recipient.token_account && *destination.owner == token::ID,
+            FreelanceShieldError::TokenAccountMismatch
+        );
+        {
+            let data = destination.try_borrow_data()?;
+            let token_account = TokenAccount::try_deserialize(&mut &data[..])?;
+            require!(token_account.mint == mint, FreelanceShieldError::MintMismatch);
+        }
+        
+        if shares[i] == 0 {
+            continue;
+        }
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            to: destination.clone(),
+            authority: ctx.accounts.payer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, shares[i])?;
+        
+        msg!("Routed {} to {:?} recipient {}", shares[i], recipient.kind, recipient.token_account);
+    }
+    
+    let domain_treasury = &mut ctx.accounts.domain_treasury;
+    domain_treasury.total_routed = domain_treasury.total_routed
+        .checked_add(amount)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+    
+    msg!("Premium of {} routed across {} recipients via {}",
+        amount,
+        recipients.len(),
+        domain_treasury.domain
+    );
+    
+    Ok(())
+}
@@ -52,7 +52,8 @@ pub struct SendPremiumToRiskPool<'info> {
     /// USDC token account of the risk pool
     #[account(
         mut,
-        constraint = token_to.owner == risk_pool_account.key() @ FreelanceShieldError::InvalidTokenAccount
+        constraint = token_to.owner == risk_pool_account.key() @ FreelanceShieldError::InvalidTokenAccount,
+        constraint = token_to.mint == token_from.mint @ FreelanceShieldError::MintMismatch
     )]
     pub token_to: Account<'info, TokenAccount>,
     
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::FreelanceShieldError;
+
+/// Escrow program that owns the `Escrow` accounts policies can bind to
+pub const ESCROW_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("HbRjXVmWEkbjCejgGLaPsWpECgwoHhXVH1gV5NUzGVSH");
+
+/// Mirror of `escrow_program::EscrowStatus`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscrowStatus {
+    Active,
+    Disputed,
+    Completed,
+    Cancelled,
+    Resolved,
+}
+
+/// Mirror of `escrow_program::MilestoneStatus`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscrowMilestoneStatus {
+    Pending,
+    Submitted,
+    RevisionRequested,
+    Approved,
+    Released,
+    Disputed,
+}
+
+/// Mirror of `escrow_program::Milestone`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EscrowMilestone {
+    pub title: String,
+    pub description: String,
+    pub amount: u64,
+    pub deadline: i64,
+    pub status: EscrowMilestoneStatus,
+    pub completed_at: Option<i64>,
+    pub feedback: Option<String>,
+    pub submitted_at: Option<i64>,
+    pub deliverable_hash: Option<[u8; 32]>,
+    pub review_window_days: u16,
+    pub review_deadline: Option<i64>,
+    pub revision_count: u8,
+}
+
+/// Mirror of `escrow_program::CancellationProposal`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EscrowCancellationProposal {
+    pub proposed_by: Pubkey,
+    pub kill_fee: u64,
+    pub proposed_at: i64,
+}
+
+/// Mirror of `escrow_program::EscrowMode`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscrowMode {
+    Milestones,
+    Stream,
+}
+
+/// Mirror of `escrow_program::DisputeResolution`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EscrowDisputeResolution {
+    pub resolved_at: i64,
+    pub client_percentage: u8,
+    pub freelancer_percentage: u8,
+    pub resolver: Pubkey,
+    pub notes: String,
+}
+
+/// Mirror of `escrow_program::DisputeDetails`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EscrowDisputeDetails {
+    pub initiated_by: Pubkey,
+    pub initiated_at: i64,
+    pub reason: String,
+    pub resolution: Option<EscrowDisputeResolution>,
+}
+
+/// Leading fields of `escrow_program::Escrow`, up to and including `mode`
+/// Only the prefix core needs is decoded, so trailing fields may change freely
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EscrowView {
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub amount: u64,
+    pub description: String,
+    pub created_at: i64,
+    pub deadline: i64,
+    pub status: EscrowStatus,
+    pub milestones: Vec<EscrowMilestone>,
+    pub completed_milestones: u8,
+    pub disputed: bool,
+    pub auto_release_date: i64,
+    pub dispute_details: Option<EscrowDisputeDetails>,
+    pub completion_date: Option<i64>,
+    pub last_activity_at: i64,
+    pub history_pages: u32,
+    pub last_notification_at: i64,
+    pub client_confirmed_completion: bool,
+    pub freelancer_confirmed_completion: bool,
+    pub mint: Pubkey,
+    pub cancellation: Option<EscrowCancellationProposal>,
+    pub amendment_count: u32,
+    pub partner: Option<Pubkey>,
+    pub fee_bps_override: Option<u16>,
+    pub fees_paid: u64,
+    pub mode: EscrowMode,
+}
+
+impl EscrowView {
+    /// Decode an escrow account, checking its owner and discriminator
+    pub fn load(escrow_info: &AccountInfo) -> Result<Self> {
+        require!(
+            *escrow_info.owner == ESCROW_PROGRAM_ID,
+            FreelanceShieldError::InvalidEscrowAccount
+        );
+        
+        let data = escrow_info.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == hash(b"account:Escrow").to_bytes()[..8],
+            FreelanceShieldError::InvalidEscrowAccount
+        );
+        
+        Self::deserialize(&mut &data[8..])
+            .map_err(|_| error!(FreelanceShieldError::InvalidEscrowAccount))
+    }
+    
+    /// Value of milestones not yet released to the freelancer
+    /// Stream escrows vest over time rather than by milestone, so they have none.
+    /// A completed escrow has paid out in full; cancelled and resolved escrows keep
+    /// the value the freelancer may still claim for
+    pub fn unreleased_value(&self) -> u64 {
+        match self.status {
+            EscrowStatus::Completed => 0,
+            EscrowStatus::Active | EscrowStatus::Disputed |
+            EscrowStatus::Cancelled | EscrowStatus::Resolved => self.milestones
+                .iter()
+                .filter(|m| m.status != EscrowMilestoneStatus::Released)
+                .map(|m| m.amount)
+                .sum(),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 pub mod reputation;
 pub mod authentication;
+pub mod escrow;
 
 // Re-export interfaces for easier imports
 pub use reputation::*;
 pub use authentication::*;
+pub use escrow::*;
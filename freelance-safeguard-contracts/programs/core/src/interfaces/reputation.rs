@@ -1,4 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::FreelanceShieldError;
+
+/// Reputation program that owns the `UserProfile` accounts
+pub const REPUTATION_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("ErqSUDAvmuZicGV9zBBAV9eSUo6sJoj7zjuWgASJQxD9");
+
+/// Score assumed for users without a reputation profile
+pub const DEFAULT_REPUTATION_SCORE: u8 = 50;
 
 /// ReputationProvider trait defines the interface for reputation scoring systems
 /// This abstraction allows switching between different reputation implementations
@@ -82,3 +90,48 @@ impl Default for ReputationScore {
         }
     }
 }
+
+/// Leading fields of `reputation_program::UserProfile`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UserProfileView {
+    pub user: Pubkey,
+    pub reputation_score: u8,
+}
+
+impl UserProfileView {
+    pub const SEED_PREFIX: &'static [u8] = b"user_profile";
+    
+    /// Read a user's reputation score from their profile PDA
+    /// A profile that was never created scores `DEFAULT_REPUTATION_SCORE`
+    pub fn load_score(profile_info: &AccountInfo, user: &Pubkey) -> Result<u8> {
+        let (expected, _) = Pubkey::find_program_address(
+            &[Self::SEED_PREFIX, user.as_ref()],
+            &REPUTATION_PROGRAM_ID,
+        );
+        require!(
+            profile_info.key() == expected,
+            FreelanceShieldError::InvalidReputationProfile
+        );
+        
+        if profile_info.data_is_empty() {
+            return Ok(DEFAULT_REPUTATION_SCORE);
+        }
+        
+        require!(
+            *profile_info.owner == REPUTATION_PROGRAM_ID,
+            FreelanceShieldError::InvalidReputationProfile
+        );
+        
+        let data = profile_info.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == hash(b"account:UserProfile").to_bytes()[..8],
+            FreelanceShieldError::InvalidReputationProfile
+        );
+        
+        let profile = Self::deserialize(&mut &data[8..])
+            .map_err(|_| error!(FreelanceShieldError::InvalidReputationProfile))?;
+        require!(profile.user == *user, FreelanceShieldError::InvalidReputationProfile);
+        
+        Ok(profile.reputation_score.min(100))
+    }
+}
@@ -13,16 +13,25 @@ pub mod adapters;
 // Use specific imports instead of glob imports to avoid ambiguity
 pub use instructions::{
     claim::{
+        add_evidence::AddClaimEvidence,
         arbitrate::ArbitrateClaim,
+        commit_vote::CommitVote,
         dispute::DisputeClaim,
+        finalize_vote::FinalizeClaimVote,
+        forfeit_vote::ForfeitUnrevealedVote,
         pay::PayClaim,
         process::ProcessClaim,
+        respond::RespondToClaim,
+        reveal_vote::RevealVote,
         submit::SubmitClaim,
         vote::VoteOnClaim,
     },
     policy::{
         cancel::CancelPolicy,
         purchase::PurchasePolicy,
+        purchase_escrow::PurchaseEscrowPolicy,
+        sync_escrow::SyncEscrowPolicy,
+        prune_index::PrunePolicyIndex,
     },
     product::{
         create::CreateProduct,
@@ -30,6 +39,8 @@ pub use instructions::{
     },
     program::{
         initialize::Initialize,
+        remove_allowed_mint::RemoveAllowedMint,
+        set_allowed_mint::SetAllowedMint,
         update::UpdateProgramParameters,
     },
     risk::{
@@ -43,21 +54,69 @@ pub use instructions::{
         initialize::InitializeDomainTreasury,
         update::UpdateDomainTreasury,
         send_payment::SendPremiumToRiskPool,
+        propose_splits::ProposeTreasurySplits,
+        apply_splits::ApplyTreasurySplits,
+        route_premium::RoutePremium,
     },
     reputation::{
         fetch_ethos_score::FetchEthosScore,
         simulate_ethos_reputation::SimulateEthosReputation,
     },
+    arbitration::{
+        assign_panel::AssignArbitrationPanel,
+        draw_panel::DrawArbitrationPanel,
+        commit_decision::CommitArbitrationDecision,
+        initialize_registry::InitializeArbitratorRegistry,
+        register::RegisterArbitrator,
+        settle_panel::SettleArbitrationPanel,
+        stake::AddArbitratorStake,
+        update_status::UpdateArbitratorStatus,
+        withdraw_stake::WithdrawArbitratorStake,
+        CommitArbitrationDecisionParams,
+    },
+    subrogation::{
+        flag_default::FlagSubrogationDefault,
+        repay::RepaySubrogation,
+    },
+    fraud::{
+        initialize_model::InitializeFraudModel,
+        update_model::UpdateFraudModel,
+    },
+    circuit_breaker::{
+        configure::ConfigureCircuitBreaker,
+        initialize::InitializeCircuitBreaker,
+        reset::ResetCircuitBreaker,
+    },
+    referral::{
+        claim_commission::ClaimReferralCommission,
+        register::RegisterReferrer,
+        update::UpdateReferrer,
+    },
+    group::{
+        add_member::AddGroupMember,
+        arbitrate_member_claim::ArbitrateMemberClaim,
+        create::CreateGroupPolicy,
+        remove_member::RemoveGroupMember,
+        settle_member_claim::SettleMemberClaim,
+        submit_member_claim::SubmitMemberClaim,
+    },
 };
 
 // Import parameter structs with specific namespaces
 pub use state::params::*;
 pub use state::product::Product;
-pub use state::policy::Policy;
-pub use state::claim::Claim;
+pub use state::policy::{Policy, PolicyIndex};
+pub use state::claim::{Claim, VoteCommitment};
 pub use state::risk_pool::RiskPool;
 pub use state::program_state::ProgramState;
 pub use state::feature_flags::FeatureFlags;
+pub use state::arbitrator::{Arbitrator, ArbitratorRegistry, ArbitrationPanel};
+pub use state::evidence::Evidence;
+pub use state::subrogation::{Subrogation, DebtorRecord};
+pub use state::fraud_model::FraudModel;
+pub use state::circuit_breaker::CircuitBreaker;
+pub use state::referral::Referrer;
+pub use state::group_policy::{GroupPolicy, GroupMember};
 
 // Add explicit exports for utility modules
 pub use crate::error_helpers::*;
@@ -122,6 +181,28 @@ pub mod freelance_shield_core {
         instructions::policy::cancel::handler(ctx)
     }
     
+    /// Purchase a policy bound to an escrow's milestones and deadline
+    pub fn purchase_escrow_policy(
+        ctx: Context<PurchaseEscrowPolicy>,
+        params: PurchaseEscrowPolicyParams,
+    ) -> Result<()> {
+        instructions::policy::purchase_escrow::handler(ctx, params)
+    }
+    
+    /// Sync an escrow-bound policy's coverage, expiring it once the escrow completes
+    pub fn sync_escrow_policy(
+        ctx: Context<SyncEscrowPolicy>,
+    ) -> Result<()> {
+        instructions::policy::sync_escrow::handler(ctx)
+    }
+    
+    /// Drop a policy that can no longer be claimed on from its owner's index
+    pub fn prune_policy_index(
+        ctx: Context<PrunePolicyIndex>,
+    ) -> Result<()> {
+        instructions::policy::prune_index::handler(ctx)
+    }
+    
     // ===== CLAIMS MANAGEMENT =====
     
     /// Submit a new insurance claim
@@ -186,6 +267,254 @@ pub mod freelance_shield_core {
         instructions::claim::dispute::handler(ctx, reason)
     }
     
+    /// Commit a sealed vote on a claim using commit-reveal voting
+    pub fn commit_vote(
+        ctx: Context<CommitVote>,
+        params: CommitVoteParams,
+    ) -> Result<()> {
+        instructions::claim::commit_vote::handler(ctx, params)
+    }
+    
+    /// Reveal a previously committed vote
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        params: RevealVoteParams,
+    ) -> Result<()> {
+        instructions::claim::reveal_vote::handler(ctx, params)
+    }
+    
+    /// Tally the votes on a claim once its voting window has ended
+    pub fn tally_claim_votes(
+        ctx: Context<FinalizeClaimVote>,
+    ) -> Result<()> {
+        instructions::claim::finalize_vote::handler(ctx)
+    }
+    
+    /// Forfeit the bond of a vote that was not revealed in time
+    pub fn forfeit_unrevealed_vote(
+        ctx: Context<ForfeitUnrevealedVote>,
+    ) -> Result<()> {
+        instructions::claim::forfeit_vote::handler(ctx)
+    }
+    
+    /// Attach content-addressed evidence to a claim (claimant or counterparty)
+    pub fn add_claim_evidence(
+        ctx: Context<AddClaimEvidence>,
+        params: AddClaimEvidenceParams,
+    ) -> Result<()> {
+        instructions::claim::add_evidence::handler(ctx, params)
+    }
+    
+    /// Acknowledge or contest a claim as its counterparty (client)
+    pub fn respond_to_claim(
+        ctx: Context<RespondToClaim>,
+        params: RespondToClaimParams,
+    ) -> Result<()> {
+        instructions::claim::respond::handler(ctx, params)
+    }
+    
+    // ===== ARBITRATION =====
+    
+    /// Initialize the arbitrator registry
+    pub fn initialize_arbitrator_registry(
+        ctx: Context<InitializeArbitratorRegistry>,
+    ) -> Result<()> {
+        instructions::arbitration::initialize_registry::handler(ctx)
+    }
+    
+    /// Register as a stake-backed arbitrator
+    pub fn register_arbitrator(
+        ctx: Context<RegisterArbitrator>,
+        stake_amount: u64,
+    ) -> Result<()> {
+        instructions::arbitration::register::handler(ctx, stake_amount)
+    }
+    
+    /// Add stake to an arbitrator account
+    pub fn add_arbitrator_stake(
+        ctx: Context<AddArbitratorStake>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::arbitration::stake::handler(ctx, amount)
+    }
+    
+    /// Activate or deactivate an arbitrator
+    pub fn update_arbitrator_status(
+        ctx: Context<UpdateArbitratorStatus>,
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::arbitration::update_status::handler(ctx, is_active)
+    }
+    
+    /// Withdraw a deactivated arbitrator's stake
+    pub fn withdraw_arbitrator_stake(
+        ctx: Context<WithdrawArbitratorStake>,
+    ) -> Result<()> {
+        instructions::arbitration::withdraw_stake::handler(ctx)
+    }
+    
+    /// Open an arbitration panel for a disputed claim, committing to a future draw slot
+    pub fn assign_arbitration_panel(
+        ctx: Context<AssignArbitrationPanel>,
+    ) -> Result<()> {
+        instructions::arbitration::assign_panel::handler(ctx)
+    }
+    
+    /// Draw an opened panel's members once its draw slot has passed
+    pub fn draw_arbitration_panel(
+        ctx: Context<DrawArbitrationPanel>,
+    ) -> Result<()> {
+        instructions::arbitration::draw_panel::handler(ctx)
+    }
+    
+    /// Commit a panel arbitrator's decision
+    pub fn commit_arbitration_decision(
+        ctx: Context<CommitArbitrationDecision>,
+        params: CommitArbitrationDecisionParams,
+    ) -> Result<()> {
+        instructions::arbitration::commit_decision::handler(ctx, params)
+    }
+    
+    /// Settle a panel after its deadline and slash no-shows
+    pub fn settle_arbitration_panel(
+        ctx: Context<SettleArbitrationPanel>,
+    ) -> Result<()> {
+        instructions::arbitration::settle_panel::handler(ctx)
+    }
+    
+    // ===== SUBROGATION =====
+    
+    /// Repay all or part of a subrogated claim into the risk pool
+    pub fn repay_subrogation(
+        ctx: Context<RepaySubrogation>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::subrogation::repay::handler(ctx, amount)
+    }
+    
+    /// Flag a client who missed the subrogation repayment deadline
+    pub fn flag_subrogation_default(
+        ctx: Context<FlagSubrogationDefault>,
+    ) -> Result<()> {
+        instructions::subrogation::flag_default::handler(ctx)
+    }
+    
+    // ===== FRAUD MODEL =====
+    
+    /// Initialize the claim fraud model with default weights
+    pub fn initialize_fraud_model(
+        ctx: Context<InitializeFraudModel>,
+    ) -> Result<()> {
+        instructions::fraud::initialize_model::handler(ctx)
+    }
+    
+    /// Update fraud model weights and thresholds (admin or governance)
+    pub fn update_fraud_model(
+        ctx: Context<UpdateFraudModel>,
+        params: UpdateFraudModelParams,
+    ) -> Result<()> {
+        instructions::fraud::update_model::handler(ctx, params)
+    }
+    
+    // ===== CIRCUIT BREAKER =====
+    
+    /// Initialize the global claim circuit breaker or one scoped to a product
+    pub fn initialize_circuit_breaker(
+        ctx: Context<InitializeCircuitBreaker>,
+        scope: Pubkey,
+    ) -> Result<()> {
+        instructions::circuit_breaker::initialize::handler(ctx, scope)
+    }
+    
+    /// Configure circuit breaker thresholds
+    pub fn configure_circuit_breaker(
+        ctx: Context<ConfigureCircuitBreaker>,
+        params: ConfigureCircuitBreakerParams,
+    ) -> Result<()> {
+        instructions::circuit_breaker::configure::handler(ctx, params)
+    }
+    
+    /// Clear a tripped circuit breaker (admin or governance)
+    pub fn reset_circuit_breaker(
+        ctx: Context<ResetCircuitBreaker>,
+    ) -> Result<()> {
+        instructions::circuit_breaker::reset::handler(ctx)
+    }
+    
+    // ===== REFERRALS =====
+    
+    /// Register a referrer with its commission rate
+    pub fn register_referrer(
+        ctx: Context<RegisterReferrer>,
+        params: RegisterReferrerParams,
+    ) -> Result<()> {
+        instructions::referral::register::handler(ctx, params)
+    }
+    
+    /// Update a referrer's commission terms or status
+    pub fn update_referrer(
+        ctx: Context<UpdateReferrer>,
+        params: UpdateReferrerParams,
+    ) -> Result<()> {
+        instructions::referral::update::handler(ctx, params)
+    }
+    
+    /// Claim accrued referral commission
+    pub fn claim_referral_commission(
+        ctx: Context<ClaimReferralCommission>,
+    ) -> Result<()> {
+        instructions::referral::claim_commission::handler(ctx)
+    }
+    
+    // ===== GROUP POLICIES =====
+    
+    /// Create an agency master policy
+    pub fn create_group_policy(
+        ctx: Context<CreateGroupPolicy>,
+        params: CreateGroupPolicyParams,
+    ) -> Result<()> {
+        instructions::group::create::handler(ctx, params)
+    }
+    
+    /// Add a member certificate to a group policy
+    pub fn add_group_member(
+        ctx: Context<AddGroupMember>,
+        params: AddGroupMemberParams,
+    ) -> Result<()> {
+        instructions::group::add_member::handler(ctx, params)
+    }
+    
+    /// Remove a member certificate from a group policy
+    pub fn remove_group_member(
+        ctx: Context<RemoveGroupMember>,
+    ) -> Result<()> {
+        instructions::group::remove_member::handler(ctx)
+    }
+    
+    /// Submit a claim against a member certificate
+    pub fn submit_member_claim(
+        ctx: Context<SubmitMemberClaim>,
+        params: SubmitClaimParams,
+    ) -> Result<()> {
+        instructions::group::submit_member_claim::handler(ctx, params)
+    }
+    
+    /// Pay or release a decided member claim
+    pub fn settle_member_claim(
+        ctx: Context<SettleMemberClaim>,
+    ) -> Result<()> {
+        instructions::group::settle_member_claim::handler(ctx)
+    }
+    
+    /// Decide a member claim that ended in dispute or arbitration
+    pub fn arbitrate_member_claim(
+        ctx: Context<ArbitrateMemberClaim>,
+        approved: bool,
+        reason: String,
+    ) -> Result<()> {
+        instructions::group::arbitrate_member_claim::handler(ctx, approved, reason)
+    }
+    
     // ===== RISK POOL MANAGEMENT =====
     
     /// Initialize a new risk pool
@@ -255,6 +584,29 @@ pub mod freelance_shield_core {
         instructions::treasury::send_payment::handler(ctx, amount)
     }
     
+    /// Propose a new premium split (takes effect after the timelock)
+    pub fn propose_treasury_splits(
+        ctx: Context<ProposeTreasurySplits>,
+        recipients: Vec<TreasuryRecipient>,
+    ) -> Result<()> {
+        instructions::treasury::propose_splits::handler(ctx, recipients)
+    }
+    
+    /// Apply a proposed premium split once its timelock has expired
+    pub fn apply_treasury_splits(
+        ctx: Context<ApplyTreasurySplits>,
+    ) -> Result<()> {
+        instructions::treasury::apply_splits::handler(ctx)
+    }
+    
+    /// Route a premium to all treasury recipients according to the split
+    pub fn route_premium<'info>(
+        ctx: Context<'_, '_, '_, 'info, RoutePremium<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::treasury::route_premium::handler(ctx, amount)
+    }
+    
     // ===== PROGRAM MANAGEMENT =====
     
     /// Update program parameters
@@ -265,6 +617,22 @@ pub mod freelance_shield_core {
         instructions::program::update::handler(ctx, params)
     }
     
+    /// Add a mint to the premium/payout allowlist or update its reporting value
+    pub fn set_allowed_mint(
+        ctx: Context<SetAllowedMint>,
+        stable_value_micros: u64,
+    ) -> Result<()> {
+        instructions::program::set_allowed_mint::handler(ctx, stable_value_micros)
+    }
+    
+    /// Remove a mint from the premium/payout allowlist
+    pub fn remove_allowed_mint(
+        ctx: Context<RemoveAllowedMint>,
+        mint: Pubkey,
+    ) -> Result<()> {
+        instructions::program::remove_allowed_mint::handler(ctx, mint)
+    }
+    
     // ===== FEATURE FLAG MANAGEMENT =====
     
     /// Enable a feature flag
@@ -380,6 +748,30 @@ pub enum FreelanceShieldError {
     #[msg("Insufficient premium amount")]
     InsufficientPremiumAmount,
     
+    #[msg("Owner's policy index is full")]
+    PolicyIndexFull,
+    
+    #[msg("Policy can still be claimed on and cannot be pruned")]
+    PolicyStillOpen,
+    
+    #[msg("Policy is not on the owner's index")]
+    PolicyNotIndexed,
+    
+    #[msg("Invalid escrow account")]
+    InvalidEscrowAccount,
+    
+    #[msg("Invalid reputation profile account")]
+    InvalidReputationProfile,
+    
+    #[msg("Escrow is not active")]
+    EscrowNotActive,
+    
+    #[msg("Policy is not bound to this escrow")]
+    NotEscrowPolicy,
+    
+    #[msg("Stream escrows cannot back a policy")]
+    StreamEscrowNotSupported,
+    
     // Claim errors
     #[msg("Claim not found")]
     ClaimNotFound,
@@ -432,6 +824,48 @@ pub enum FreelanceShieldError {
     #[msg("Invalid reason")]
     InvalidReason,
     
+    #[msg("Too many votes on this claim")]
+    TooManyVotes,
+    
+    #[msg("Claim requires commit-reveal voting")]
+    CommitRevealVotingRequired,
+    
+    #[msg("Commit-reveal voting not enabled for this claim")]
+    CommitRevealVotingDisabled,
+    
+    #[msg("Commit phase ended")]
+    CommitPhaseEnded,
+    
+    #[msg("Reveal phase not started")]
+    RevealPhaseNotStarted,
+    
+    #[msg("Reveal phase ended")]
+    RevealPhaseEnded,
+    
+    #[msg("Reveal phase not ended")]
+    RevealPhaseNotEnded,
+    
+    #[msg("Revealed vote does not match commitment")]
+    CommitmentMismatch,
+    
+    #[msg("Evidence submission closed for this claim")]
+    EvidenceSubmissionClosed,
+    
+    #[msg("Invalid evidence URI")]
+    InvalidEvidenceUri,
+    
+    #[msg("Invalid evidence amendment")]
+    InvalidEvidenceAmendment,
+    
+    #[msg("Invalid counterparty")]
+    InvalidCounterparty,
+    
+    #[msg("Counterparty already responded to this claim")]
+    CounterpartyAlreadyResponded,
+    
+    #[msg("Counterparty response window ended")]
+    ResponseWindowEnded,
+    
     // Risk pool errors
     #[msg("Risk pool paused")]
     RiskPoolPaused,
@@ -458,6 +892,15 @@ pub enum FreelanceShieldError {
     InvalidTokenAccount,
     
     // Token errors
+    #[msg("Token mint does not match the policy mint")]
+    MintMismatch,
+    
+    #[msg("Token mint not on the allowlist")]
+    MintNotAllowed,
+    
+    #[msg("Mint allowlist is full")]
+    MintAllowlistFull,
+    
     #[msg("Token transfer failed")]
     TokenTransferFailed,
     
@@ -486,6 +929,15 @@ pub enum FreelanceShieldError {
     #[msg("Invalid risk pool account")]
     InvalidRiskPoolAccount,
     
+    #[msg("Treasury split must have unique recipients summing to 10000 basis points")]
+    InvalidTreasurySplit,
+    
+    #[msg("Treasury split not configured")]
+    TreasurySplitNotConfigured,
+    
+    #[msg("No pending treasury split")]
+    NoPendingTreasurySplit,
+    
     // Additional error variants needed for policy renewal and claims
     #[msg("Product is inactive")]
     ProductInactive,
@@ -555,4 +1007,115 @@ pub enum FreelanceShieldError {
     
     #[msg("Timelock not expired")]
     TimelockNotExpired,
+    
+    // Arbitration errors
+    #[msg("Arbitrator registry is full")]
+    ArbitratorRegistryFull,
+    
+    #[msg("Arbitrator already active")]
+    ArbitratorAlreadyActive,
+    
+    #[msg("Arbitrator not active")]
+    ArbitratorNotActive,
+    
+    #[msg("Arbitrator still active")]
+    ArbitratorStillActive,
+    
+    #[msg("Insufficient arbitrator stake")]
+    InsufficientArbitratorStake,
+    
+    #[msg("Arbitrator stake is still locked")]
+    StakeWithdrawalLocked,
+    
+    #[msg("Not enough active arbitrators to draw a panel")]
+    NotEnoughArbitrators,
+    
+    #[msg("Signer is not a member of this arbitration panel")]
+    NotPanelMember,
+    
+    #[msg("Arbitrator already committed a decision")]
+    ArbitratorAlreadyDecided,
+    
+    #[msg("Arbitration deadline passed")]
+    ArbitrationDeadlinePassed,
+    
+    #[msg("Arbitration deadline not reached")]
+    ArbitrationDeadlineNotReached,
+    
+    #[msg("Arbitration panel already settled")]
+    ArbitrationPanelSettled,
+    
+    #[msg("Arbitration panel is still deciding this claim")]
+    ArbitrationPanelActive,
+    
+    #[msg("Claim must be decided by an arbitration panel")]
+    ArbitrationPanelRequired,
+    
+    #[msg("Invalid arbitrator account")]
+    InvalidArbitratorAccount,
+    
+    #[msg("Arbitration panel has not been drawn yet")]
+    ArbitrationPanelNotDrawn,
+    
+    #[msg("Arbitration panel has already been drawn")]
+    ArbitrationPanelAlreadyDrawn,
+    
+    #[msg("Panel draw slot has not passed yet")]
+    PanelDrawSlotNotReached,
+    
+    // Subrogation errors
+    #[msg("Subrogation accounts required for non-payment claims recoverable from the counterparty")]
+    SubrogationAccountsRequired,
+    
+    #[msg("Claim is not recoverable from an unverified or contesting counterparty")]
+    ClaimNotRecoverable,
+    
+    #[msg("Subrogation already recovered")]
+    SubrogationAlreadyRecovered,
+    
+    #[msg("Subrogation not overdue")]
+    SubrogationNotOverdue,
+    
+    #[msg("Invalid repayment amount")]
+    InvalidRepaymentAmount,
+    
+    #[msg("Debtor record does not belong to the claim counterparty")]
+    InvalidDebtorRecord,
+    
+    // Fraud model errors
+    #[msg("Fraud model weights must sum to 10000 basis points")]
+    InvalidFraudModelWeights,
+    
+    // Circuit breaker errors
+    #[msg("Circuit breaker is not tripped")]
+    CircuitBreakerNotTripped,
+    
+    // Referral errors
+    #[msg("Referral commission exceeds the global cap")]
+    ReferralCommissionTooHigh,
+    
+    #[msg("Referrer account required")]
+    ReferrerAccountRequired,
+    
+    #[msg("Invalid or inactive referrer")]
+    InvalidReferrer,
+    
+    #[msg("Policy owner cannot be their own referrer")]
+    SelfReferral,
+    
+    #[msg("No referral commission accrued")]
+    NoCommissionAccrued,
+    
+    // Group policy errors
+    #[msg("Aggregate limit must be at least the per-member limit")]
+    InvalidGroupLimits,
+    
+    #[msg("Group aggregate limit exceeded")]
+    GroupAggregateLimitExceeded,
+    
+    #[msg("Group member limit reached")]
+    GroupMemberLimitReached,
+    
+    #[msg("Group member has a pending claim")]
+    GroupMemberClaimPending,
 }
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use crate::state::claim::MAX_REASON_LENGTH;
+
+/// Maximum number of arbitrators that can be active in the registry at once
+pub const MAX_ACTIVE_ARBITRATORS: usize = 64;
+/// Maximum number of arbitrators drawn onto a single panel
+pub const MAX_PANEL_SIZE: usize = 7;
+/// Slots between opening a panel and the slot whose hash seeds its draw
+pub const PANEL_DRAW_DELAY_SLOTS: u64 = 32;
+
+/// Stake-backed arbitrator account
+#[account]
+#[derive(Default)]
+pub struct Arbitrator {
+    /// Arbitrator wallet
+    pub authority: Pubkey,
+    /// Stake currently held by this account (in lamports)
+    pub stake_amount: u64,
+    /// Whether the arbitrator is eligible for panel selection
+    pub is_active: bool,
+    /// Number of panels this arbitrator has been settled on
+    pub cases_assigned: u64,
+    /// Number of decisions committed before the deadline
+    pub cases_decided: u64,
+    /// Number of decisions that matched the panel majority
+    pub majority_decisions: u64,
+    /// Number of panels where the deadline was missed
+    pub cases_missed: u64,
+    /// Total stake slashed for missed deadlines (in lamports)
+    pub total_slashed: u64,
+    /// Registration timestamp
+    pub registered_at: i64,
+    /// Timestamp of the last deactivation (0 while active)
+    pub deactivated_at: i64,
+    /// Timestamp of the last committed decision
+    pub last_decision_at: i64,
+    /// Drawn panels not yet settled; stake can't be withdrawn while any remain
+    pub active_panels: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Arbitrator {
+    pub const SEED_PREFIX: &'static [u8] = b"arbitrator";
+
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // authority
+        8 +  // stake_amount
+        1 +  // is_active
+        8 +  // cases_assigned
+        8 +  // cases_decided
+        8 +  // majority_decisions
+        8 +  // cases_missed
+        8 +  // total_slashed
+        8 +  // registered_at
+        8 +  // deactivated_at
+        8 +  // last_decision_at
+        4 +  // active_panels
+        1;   // bump
+}
+
+/// Registry of arbitrators eligible for random panel selection
+#[account]
+#[derive(Default)]
+pub struct ArbitratorRegistry {
+    /// Arbitrators currently eligible for selection
+    pub active_arbitrators: Vec<Pubkey>,
+    /// Total number of arbitrators ever registered
+    pub total_registered: u64,
+    /// Total number of panels drawn
+    pub total_panels: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ArbitratorRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"arbitrator_registry";
+
+    pub const SIZE: usize = 8 + // discriminator
+        (4 + MAX_ACTIVE_ARBITRATORS * 32) + // active_arbitrators
+        8 +  // total_registered
+        8 +  // total_panels
+        1;   // bump
+
+    /// Add an arbitrator to the selection pool
+    pub fn activate(&mut self, arbitrator: Pubkey) -> Result<()> {
+        require!(
+            !self.active_arbitrators.contains(&arbitrator),
+            crate::FreelanceShieldError::ArbitratorAlreadyActive
+        );
+        require!(
+            self.active_arbitrators.len() < MAX_ACTIVE_ARBITRATORS,
+            crate::FreelanceShieldError::ArbitratorRegistryFull
+        );
+
+        self.active_arbitrators.push(arbitrator);
+        Ok(())
+    }
+
+    /// Remove an arbitrator from the selection pool
+    pub fn deactivate(&mut self, arbitrator: &Pubkey) {
+        self.active_arbitrators.retain(|a| a != arbitrator);
+    }
+
+    /// Active arbitrators eligible to sit on a claim's panel; parties to the claim are excluded
+    pub fn eligible_for(&self, claim: &crate::state::Claim) -> Vec<Pubkey> {
+        self.active_arbitrators
+            .iter()
+            .filter(|a| **a != claim.owner && Some(**a) != claim.counterparty)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Panel of arbitrators drawn for a single disputed claim
+#[account]
+#[derive(Default)]
+pub struct ArbitrationPanel {
+    /// Claim being arbitrated
+    pub claim: Pubkey,
+    /// Arbitrators drawn for this panel
+    pub members: Vec<Pubkey>,
+    /// Decisions committed by panel members
+    pub decisions: Vec<PanelDecision>,
+    /// Seed used for the random draw (slot hash mixed with the claim key)
+    pub selection_seed: [u8; 32],
+    /// Slot whose hash seeded the draw
+    pub selection_slot: u64,
+    /// Earliest slot whose hash may seed the draw, fixed when the panel is opened
+    pub draw_slot: u64,
+    /// Assignment timestamp
+    pub assigned_at: i64,
+    /// Deadline for committing decisions
+    pub decision_deadline: i64,
+    /// Majority outcome, once reached (None if undecided or tied)
+    pub outcome: Option<bool>,
+    /// Whether the claim has been resolved by this panel
+    pub is_resolved: bool,
+    /// Whether track records and slashing have been settled
+    pub is_settled: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ArbitrationPanel {
+    pub const SEED_PREFIX: &'static [u8] = b"arbitration_panel";
+
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // claim
+        (4 + MAX_PANEL_SIZE * 32) + // members
+        (4 + MAX_PANEL_SIZE * PanelDecision::SIZE) + // decisions
+        32 + // selection_seed
+        8 +  // selection_slot
+        8 +  // draw_slot
+        8 +  // assigned_at
+        8 +  // decision_deadline
+        (1 + 1) + // outcome
+        1 +  // is_resolved
+        1 +  // is_settled
+        1;   // bump
+
+    /// Whether the given arbitrator sits on this panel
+    pub fn is_member(&self, arbitrator: &Pubkey) -> bool {
+        self.members.contains(arbitrator)
+    }
+
+    /// Whether the given arbitrator has already committed a decision
+    pub fn has_decided(&self, arbitrator: &Pubkey) -> bool {
+        self.decisions.iter().any(|d| d.arbitrator == *arbitrator)
+    }
+
+    /// Count (approve, reject) decisions
+    pub fn tally(&self) -> (usize, usize) {
+        let approvals = self.decisions.iter().filter(|d| d.approve).count();
+        (approvals, self.decisions.len() - approvals)
+    }
+
+    /// Outcome once one side holds a strict majority of the full panel
+    pub fn majority_outcome(&self) -> Option<bool> {
+        let (approvals, rejections) = self.tally();
+        let majority = self.members.len() / 2 + 1;
+
+        if approvals >= majority {
+            Some(true)
+        } else if rejections >= majority {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// A single arbitrator decision on a panel
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PanelDecision {
+    /// Arbitrator who committed the decision
+    pub arbitrator: Pubkey,
+    /// Whether the arbitrator approves the claim
+    pub approve: bool,
+    /// Reason for the decision
+    pub reason: String,
+    /// Timestamp of the decision
+    pub timestamp: i64,
+}
+
+impl PanelDecision {
+    pub const SIZE: usize = 32 + // arbitrator
+        1 +  // approve
+        (4 + MAX_REASON_LENGTH) + // reason
+        8;   // timestamp
+}
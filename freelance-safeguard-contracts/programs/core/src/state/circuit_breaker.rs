@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+
+/// Length of the short rate window (in seconds)
+pub const HOURLY_WINDOW_SECONDS: i64 = 3_600;
+/// Length of the long rate window (in seconds)
+pub const DAILY_WINDOW_SECONDS: i64 = 86_400;
+
+/// Claim velocity circuit breaker, either global or scoped to one product
+/// Tripping it pauses auto-approval of claims; submission stays open
+#[account]
+#[derive(Default)]
+pub struct CircuitBreaker {
+    /// Product PDA this breaker covers (`Pubkey::default()` for the global breaker)
+    pub scope: Pubkey,
+    /// Optional DAO governance key allowed to clear the breaker alongside the admin
+    pub governance_authority: Option<Pubkey>,
+    /// Claims and claimed amounts over the last hour
+    pub hourly: RateWindow,
+    /// Claims and claimed amounts over the last day
+    pub daily: RateWindow,
+    /// Maximum claims per hour before tripping (0 disables)
+    pub max_claims_per_hour: u32,
+    /// Maximum claims per day before tripping (0 disables)
+    pub max_claims_per_day: u32,
+    /// Maximum claimed amount per hour before tripping (0 disables)
+    pub max_amount_per_hour: u64,
+    /// Maximum claimed amount per day before tripping (0 disables)
+    pub max_amount_per_day: u64,
+    /// Whether auto-approval is currently paused
+    pub auto_approval_paused: bool,
+    /// Why auto-approval was paused
+    pub pause_reason: PauseReason,
+    /// Timestamp the breaker last tripped
+    pub tripped_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CircuitBreaker {
+    pub const SEED_PREFIX: &'static [u8] = b"circuit_breaker";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // scope
+        (1 + 32) + // governance_authority
+        RateWindow::SIZE + // hourly
+        RateWindow::SIZE + // daily
+        4 +  // max_claims_per_hour
+        4 +  // max_claims_per_day
+        8 +  // max_amount_per_hour
+        8 +  // max_amount_per_day
+        1 +  // auto_approval_paused
+        1 +  // pause_reason
+        8 +  // tripped_at
+        1;   // bump
+    
+    /// Record a submitted claim and trip the breaker if a threshold is crossed
+    /// Returns whether auto-approval is paused afterwards
+    pub fn record_claim(&mut self, now: i64, amount: u64) -> bool {
+        self.hourly.record(now, HOURLY_WINDOW_SECONDS, amount);
+        self.daily.record(now, DAILY_WINDOW_SECONDS, amount);
+        
+        if !self.auto_approval_paused {
+            let claim_velocity =
+                exceeds(self.hourly.estimated_count(now, HOURLY_WINDOW_SECONDS), self.max_claims_per_hour as u64) ||
+                exceeds(self.daily.estimated_count(now, DAILY_WINDOW_SECONDS), self.max_claims_per_day as u64);
+            let amount_velocity =
+                exceeds(self.hourly.estimated_amount(now, HOURLY_WINDOW_SECONDS), self.max_amount_per_hour) ||
+                exceeds(self.daily.estimated_amount(now, DAILY_WINDOW_SECONDS), self.max_amount_per_day);
+            
+            if claim_velocity || amount_velocity {
+                self.auto_approval_paused = true;
+                self.pause_reason = if claim_velocity {
+                    PauseReason::ClaimVelocity
+                } else {
+                    PauseReason::AmountVelocity
+                };
+                self.tripped_at = now;
+            }
+        }
+        
+        self.auto_approval_paused
+    }
+    
+    /// Whether the given signer may clear the breaker
+    pub fn can_reset(&self, signer: &Pubkey, admin: &Pubkey) -> bool {
+        signer == admin || self.governance_authority.as_ref() == Some(signer)
+    }
+}
+
+/// Whether `value` is over a limit, treating a zero limit as disabled
+fn exceeds(value: u64, limit: u64) -> bool {
+    limit > 0 && value > limit
+}
+
+/// Sliding window counter
+/// Keeps the current and previous fixed windows and weights the previous one
+/// by how much of it still overlaps the trailing window
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct RateWindow {
+    /// Start of the current fixed window (Unix timestamp)
+    pub window_start: i64,
+    /// Claims in the current window
+    pub current_count: u32,
+    /// Claimed amount in the current window
+    pub current_amount: u64,
+    /// Claims in the previous window
+    pub previous_count: u32,
+    /// Claimed amount in the previous window
+    pub previous_amount: u64,
+}
+
+impl RateWindow {
+    pub const SIZE: usize = 8 + // window_start
+        4 +  // current_count
+        8 +  // current_amount
+        4 +  // previous_count
+        8;   // previous_amount
+    
+    /// Advance the window to `now`
+    fn roll(&mut self, now: i64, length: i64) {
+        let elapsed = now - self.window_start;
+        if elapsed < length {
+            return;
+        }
+        
+        if elapsed < 2 * length {
+            self.previous_count = self.current_count;
+            self.previous_amount = self.current_amount;
+            self.window_start += length;
+        } else {
+            self.previous_count = 0;
+            self.previous_amount = 0;
+            self.window_start = now;
+        }
+        self.current_count = 0;
+        self.current_amount = 0;
+    }
+    
+    /// Record a claim
+    pub fn record(&mut self, now: i64, length: i64, amount: u64) {
+        self.roll(now, length);
+        self.current_count = self.current_count.saturating_add(1);
+        self.current_amount = self.current_amount.saturating_add(amount);
+    }
+    
+    /// Share of the previous window still inside the trailing window (bps)
+    fn previous_weight_bps(&self, now: i64, length: i64) -> u128 {
+        let elapsed = (now - self.window_start).clamp(0, length);
+        ((length - elapsed) as u128 * 10_000) / length as u128
+    }
+    
+    /// Estimated claims over the trailing window
+    pub fn estimated_count(&self, now: i64, length: i64) -> u64 {
+        let previous = (self.previous_count as u128 * self.previous_weight_bps(now, length)) / 10_000;
+        previous as u64 + self.current_count as u64
+    }
+    
+    /// Estimated claimed amount over the trailing window
+    pub fn estimated_amount(&self, now: i64, length: i64) -> u64 {
+        let previous = (self.previous_amount as u128 * self.previous_weight_bps(now, length)) / 10_000;
+        (previous as u64).saturating_add(self.current_amount)
+    }
+}
+
+/// Reason auto-approval was paused
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum PauseReason {
+    /// Not paused
+    None,
+    /// Too many claims within a window
+    ClaimVelocity,
+    /// Too much claimed value within a window
+    AmountVelocity,
+    /// Paused manually by the admin or governance
+    AdminPause,
+}
+
+impl Default for PauseReason {
+    fn default() -> Self {
+        PauseReason::None
+    }
+}
+
+/// Parameters for configuring a circuit breaker
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ConfigureCircuitBreakerParams {
+    /// DAO governance key (Some(None) clears it)
+    pub governance_authority: Option<Option<Pubkey>>,
+    /// Maximum claims per hour (0 disables)
+    pub max_claims_per_hour: Option<u32>,
+    /// Maximum claims per day (0 disables)
+    pub max_claims_per_day: Option<u32>,
+    /// Maximum claimed amount per hour (0 disables)
+    pub max_amount_per_hour: Option<u64>,
+    /// Maximum claimed amount per day (0 disables)
+    pub max_amount_per_day: Option<u64>,
+    /// Manually pause auto-approval
+    pub pause_auto_approval: Option<bool>,
+}
@@ -9,6 +9,8 @@ pub const MAX_EVIDENCE_HASH_LENGTH: usize = 64;
 pub const MAX_EVIDENCE_ATTACHMENTS: usize = 5;
 /// Maximum length for reason
 pub const MAX_REASON_LENGTH: usize = 256;
+/// Maximum number of votes stored on a claim
+pub const MAX_CLAIM_VOTES: usize = 10;
 
 /// Insurance claim account
 #[account]
@@ -42,10 +44,30 @@ pub struct Claim {
     pub transaction_signature: Option<String>,
     /// Risk score (0-100) for fraud detection
     pub risk_score: u8,
+    /// Version of the fraud model that produced the risk score
+    pub fraud_model_version: u32,
     /// Creation slot
     pub creation_slot: u64,
     /// Last update slot
     pub last_update_slot: u64,
+    /// Whether votes on this claim use commit-reveal
+    pub commit_reveal: bool,
+    /// Reveal phase end date (Unix timestamp, commit-reveal only)
+    pub reveal_end_date: i64,
+    /// Number of vote commitments received
+    pub commitment_count: u16,
+    /// Number of commitments revealed
+    pub revealed_count: u16,
+    /// Client whose non-payment or breach triggered the claim (if known)
+    pub counterparty: Option<Pubkey>,
+    /// Whether the counterparty was taken from the policy's bound escrow
+    pub counterparty_verified: bool,
+    /// Number of evidence records attached to the claim
+    pub evidence_count: u16,
+    /// Deadline for the counterparty to respond (Unix timestamp)
+    pub response_deadline: i64,
+    /// Counterparty response (None until the counterparty responds)
+    pub counterparty_response: Option<CounterpartyResponse>,
     /// Claim index within the policy (same as policy.claims_count at creation)
     pub index: u8,
     /// Reentrancy guard to prevent multiple processing
@@ -70,14 +92,35 @@ impl Claim {
         8 +  // voting_end_date
         (1 + (4 + 64)) + // Option<String> for transaction_signature (assuming max 64 chars)
         1 +  // risk_score
+        4 +  // fraud_model_version
         8 +  // creation_slot
         8 +  // last_update_slot
+        1 +  // commit_reveal
+        8 +  // reveal_end_date
+        2 +  // commitment_count
+        2 +  // revealed_count
+        (1 + 32) + // Option<Pubkey> for counterparty
+        1 +  // counterparty_verified
+        2 +  // evidence_count
+        8 +  // response_deadline
+        (1 + 1 + 8) + // Option<CounterpartyResponse>
         1 +  // index
         1 +  // is_processing
         1;   // bump
         
     // Note: Vec sizes are dynamic and allocated separately
     // evidence_hashes and votes will be allocated dynamically
+    
+    /// Whether a payout can be recovered from the counterparty: the client must be
+    /// verified, and must not have contested the claim unless it was adjudicated
+    pub fn recoverable_from_counterparty(&self) -> bool {
+        let contested = self.counterparty_response.map_or(false, |response| !response.acknowledged);
+        let adjudicated = self.verdict
+            .as_ref()
+            .map_or(false, |verdict| verdict.processor != ProcessorType::Automated);
+        
+        self.counterparty.is_some() && self.counterparty_verified && (!contested || adjudicated)
+    }
 }
 
 /// Claim verdict structure
@@ -100,6 +143,15 @@ impl Verdict {
         1;  // processor
 }
 
+/// Counterparty response to a claim
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Debug)]
+pub struct CounterpartyResponse {
+    /// Whether the counterparty acknowledged the claim
+    pub acknowledged: bool,
+    /// Response timestamp
+    pub responded_at: i64,
+}
+
 /// Claim vote structure
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct Vote {
@@ -120,6 +172,47 @@ impl Vote {
         8;   // timestamp
 }
 
+/// Sealed vote on a claim in commit-reveal mode
+/// Holds the voter's bond until the vote is revealed
+#[account]
+#[derive(Default)]
+pub struct VoteCommitment {
+    /// Claim being voted on
+    pub claim: Pubkey,
+    /// Voter public key
+    pub voter: Pubkey,
+    /// hash(approve, reason_hash, salt)
+    pub commitment: [u8; 32],
+    /// Bond posted with the commitment (in lamports)
+    pub bond_amount: u64,
+    /// Timestamp of the commitment
+    pub committed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VoteCommitment {
+    pub const SEED_PREFIX: &'static [u8] = b"vote_commitment";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // claim
+        32 + // voter
+        32 + // commitment
+        8 +  // bond_amount
+        8 +  // committed_at
+        1;   // bump
+    
+    /// Compute the commitment for a vote
+    /// `reason_hash` is the SHA-256 of the vote reason
+    pub fn compute(approve: bool, reason_hash: &[u8; 32], salt: &[u8; 32]) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hashv(&[
+            &[approve as u8],
+            reason_hash.as_ref(),
+            salt.as_ref(),
+        ]).to_bytes()
+    }
+}
+
 /// Parameters for submitting a claim
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct SubmitClaimParams {
@@ -133,6 +226,17 @@ pub struct SubmitClaimParams {
     pub evidence_hashes: Vec<String>,
     /// Claim category
     pub claim_category: ClaimCategory,
+    /// Client whose non-payment or breach triggered the claim (optional)
+    pub counterparty: Option<Pubkey>,
+}
+
+/// Parameters for a counterparty responding to a claim
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RespondToClaimParams {
+    /// Whether the counterparty acknowledges the claim (false contests it)
+    pub acknowledge: bool,
+    /// Reason for the response
+    pub reason: String,
 }
 
 /// Parameters for voting on a claim
@@ -144,6 +248,24 @@ pub struct VoteOnClaimParams {
     pub reason: String,
 }
 
+/// Parameters for committing a sealed vote
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CommitVoteParams {
+    /// hash(approve, reason_hash, salt)
+    pub commitment: [u8; 32],
+}
+
+/// Parameters for revealing a committed vote
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RevealVoteParams {
+    /// Whether to approve the claim
+    pub approve: bool,
+    /// Reason for the vote
+    pub reason: String,
+    /// Salt used when computing the commitment
+    pub salt: [u8; 32],
+}
+
 /// Parameters for arbitrating a claim
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct ArbitrateClaimParams {
@@ -118,6 +118,26 @@ impl Default for Industry {
 }
 
 impl Industry {
+    /// Every industry, in index order
+    pub const ALL: [Industry; 7] = [
+        Industry::Technology,
+        Industry::Finance,
+        Industry::Healthcare,
+        Industry::Ecommerce,
+        Industry::Entertainment,
+        Industry::Education,
+        Industry::Other,
+    ];
+    
+    /// Industry carrying the highest configured risk weight
+    pub fn riskiest(weights: &[u8; 7]) -> Industry {
+        Self::ALL
+            .iter()
+            .copied()
+            .max_by_key(|industry| industry.risk_weight(weights))
+            .unwrap_or_default()
+    }
+    
     /// Get risk weight for industry
     pub fn risk_weight(&self, weights: &[u8; 7]) -> u8 {
         weights[self.as_index()]
@@ -13,6 +13,8 @@ pub const MAX_CLAIMS_PER_POLICY: usize = 5;
 // Default values
 pub const DEFAULT_MIN_VOTES_REQUIRED: u8 = 3;
 pub const DEFAULT_VOTING_PERIOD_DAYS: u8 = 7;
+pub const DEFAULT_REVEAL_PERIOD_DAYS: u8 = 2;
+pub const DEFAULT_VOTE_COMMIT_BOND: u64 = 10_000_000; // 0.01 SOL bond per sealed vote
 pub const DEFAULT_CLAIM_PERIOD_DAYS: u16 = 30;
 pub const DEFAULT_BASE_PREMIUM_RATE: u16 = 500; // 5% annual rate per 10,000 units of coverage
 pub const DEFAULT_RISK_BUFFER_PERCENTAGE: u8 = 20; // 20% buffer for risk calculations
@@ -31,3 +33,30 @@ pub const DEFAULT_TREASURY_ALLOCATION_PERCENTAGE: u8 = 30; // 30% to treasury
 pub const DEFAULT_MAX_AUTO_APPROVE_AMOUNT: u64 = 1_000_000; // 1M auto-approve threshold
 pub const DEFAULT_CANCELLATION_FEE_PERCENTAGE: u8 = 10; // 10% cancellation fee
 
+// Arbitration constants
+pub const DEFAULT_ARBITRATION_PANEL_SIZE: u8 = 3; // 3 arbitrators per panel
+pub const DEFAULT_MIN_ARBITRATOR_STAKE: u64 = 1_000_000_000; // 1 SOL minimum stake
+pub const DEFAULT_ARBITRATION_PERIOD_DAYS: u8 = 3; // 3 days to commit a decision
+pub const DEFAULT_ARBITRATOR_SLASH_PERCENTAGE: u8 = 10; // 10% of stake slashed per missed deadline
+
+// Counterparty constants
+pub const DEFAULT_COUNTERPARTY_RESPONSE_DAYS: u8 = 3; // 3 days for the client to acknowledge or contest
+pub const SUBROGATION_REPAYMENT_DAYS: i64 = 30; // 30 days for the client to repay before being flagged
+
+// Referral constants
+pub const DEFAULT_MAX_REFERRAL_COMMISSION_BPS: u16 = 1_500; // 15% of premium at most
+pub const REFERRAL_COMMISSION_DENOMINATOR: u16 = 10_000;
+
+// Fraud model defaults (weights in basis points)
+pub const DEFAULT_FRAUD_POLICY_RISK_WEIGHT_BPS: u16 = 3_000;
+pub const DEFAULT_FRAUD_AMOUNT_RATIO_WEIGHT_BPS: u16 = 2_500;
+pub const DEFAULT_FRAUD_POLICY_AGE_WEIGHT_BPS: u16 = 2_000;
+pub const DEFAULT_FRAUD_CLAIMS_HISTORY_WEIGHT_BPS: u16 = 1_500;
+pub const DEFAULT_FRAUD_ANOMALY_WEIGHT_BPS: u16 = 1_000;
+pub const DEFAULT_FRAUD_CLAIMS_HISTORY_STEP: u8 = 20; // +20 per previous claim
+pub const DEFAULT_FRAUD_ANOMALY_SATURATION_SIGMA_BPS: u32 = 30_000; // saturate at 3 sigma
+pub const DEFAULT_FRAUD_MIN_ANOMALY_SAMPLES: u64 = 10; // 10 claims before trusting history
+
+// Circuit breaker defaults (amount limits are disabled until configured)
+pub const DEFAULT_BREAKER_MAX_CLAIMS_PER_HOUR: u32 = 20; // 20 claims per hour
+pub const DEFAULT_BREAKER_MAX_CLAIMS_PER_DAY: u32 = 100; // 100 claims per day
@@ -12,6 +12,14 @@ pub struct DomainTreasury {
     pub risk_pool: Pubkey,
     /// The domain name this treasury is associated with (freelanceshield.xyz)
     pub domain: String,
+    /// Active premium split across recipients
+    pub recipients: Vec<TreasuryRecipient>,
+    /// Proposed premium split, applied once the timelock expires
+    pub pending_recipients: Vec<TreasuryRecipient>,
+    /// Timestamp after which the pending split can be applied (0 if none)
+    pub pending_recipients_timestamp: i64,
+    /// Total premiums routed through the split
+    pub total_routed: u64,
     /// Last time this account was updated
     pub last_updated: i64,
     /// Bump seed for PDA derivation
@@ -24,6 +32,10 @@ impl DomainTreasury {
         32 + // admin_wallet
         32 + // risk_pool
         4 + 64 + // domain (string with max length)
+        (4 + Self::MAX_RECIPIENTS * TreasuryRecipient::SIZE) + // recipients
+        (4 + Self::MAX_RECIPIENTS * TreasuryRecipient::SIZE) + // pending_recipients
+        8 + // pending_recipients_timestamp
+        8 + // total_routed
         8 + // last_updated
         1; // bump
 
@@ -38,4 +50,55 @@ impl DomainTreasury {
     
     /// Maximum domain length to validate against
     pub const MAX_DOMAIN_LENGTH: usize = 64;
+    
+    /// Maximum number of premium split recipients
+    pub const MAX_RECIPIENTS: usize = 6;
+    
+    /// Basis-point denominator for premium splits
+    pub const SPLIT_DENOMINATOR: u16 = 10_000;
+    
+    /// Validate a proposed premium split
+    pub fn validate_recipients(recipients: &[TreasuryRecipient]) -> bool {
+        if recipients.is_empty() || recipients.len() > Self::MAX_RECIPIENTS {
+            return false;
+        }
+        
+        let total: u32 = recipients.iter().map(|r| r.share_bps as u32).sum();
+        let no_empty_shares = recipients.iter().all(|r| r.share_bps > 0);
+        let unique_destinations = recipients.iter().enumerate().all(|(i, r)| {
+            recipients[..i].iter().all(|other| other.token_account != r.token_account)
+        });
+        
+        total == Self::SPLIT_DENOMINATOR as u32 && no_empty_shares && unique_destinations
+    }
+}
+
+/// Recipient of a share of routed premiums
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TreasuryRecipient {
+    /// Role of the recipient
+    pub kind: TreasuryRecipientKind,
+    /// Destination token account
+    pub token_account: Pubkey,
+    /// Share of each premium (in basis points)
+    pub share_bps: u16,
+}
+
+impl TreasuryRecipient {
+    pub const SIZE: usize = 1 + // kind
+        32 + // token_account
+        2;   // share_bps
+}
+
+/// Role of a premium split recipient
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TreasuryRecipientKind {
+    /// Risk pool capital
+    RiskPool,
+    /// Staking rewards
+    StakingRewards,
+    /// Protocol treasury
+    ProtocolTreasury,
+    /// Referral partner
+    ReferralPartner,
 }
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of evidence records per claim
+pub const MAX_CLAIM_EVIDENCE: u16 = 32;
+/// Maximum length for an evidence MIME type
+pub const MAX_MIME_TYPE_LENGTH: usize = 64;
+/// Maximum length for an evidence URI (without the scheme prefix)
+pub const MAX_EVIDENCE_URI_LENGTH: usize = 200;
+
+/// Content-addressed evidence attachment for a claim
+/// Records are indexed per claim so voters can enumerate them in order
+#[account]
+#[derive(Default)]
+pub struct Evidence {
+    /// Claim the evidence belongs to
+    pub claim: Pubkey,
+    /// Index of this record within the claim (0..claim.evidence_count)
+    pub index: u16,
+    /// Wallet that submitted the evidence
+    pub submitter: Pubkey,
+    /// Which side of the claim submitted the evidence
+    pub party: EvidenceParty,
+    /// SHA-256 of the attachment content
+    pub content_hash: [u8; 32],
+    /// MIME type of the attachment
+    pub mime_type: String,
+    /// Storage scheme of the attachment
+    pub storage_scheme: StorageScheme,
+    /// Location of the attachment within the storage scheme
+    pub uri: String,
+    /// Index of the earlier record this one amends (if any)
+    pub amends: Option<u16>,
+    /// Submission timestamp
+    pub submitted_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Evidence {
+    pub const SEED_PREFIX: &'static [u8] = b"evidence";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // claim
+        2 +  // index
+        32 + // submitter
+        1 +  // party
+        32 + // content_hash
+        (4 + MAX_MIME_TYPE_LENGTH) + // mime_type
+        1 +  // storage_scheme
+        (4 + MAX_EVIDENCE_URI_LENGTH) + // uri
+        (1 + 2) + // amends
+        8 +  // submitted_at
+        1;   // bump
+}
+
+/// Side of the claim that submitted a piece of evidence
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum EvidenceParty {
+    /// Freelancer who filed the claim
+    Claimant,
+    /// Client named as the claim counterparty
+    Counterparty,
+}
+
+impl Default for EvidenceParty {
+    fn default() -> Self {
+        EvidenceParty::Claimant
+    }
+}
+
+/// Storage scheme for evidence attachments
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum StorageScheme {
+    /// IPFS content identifier
+    Ipfs,
+    /// Arweave transaction ID
+    Arweave,
+    /// HTTPS URL
+    Https,
+}
+
+impl Default for StorageScheme {
+    fn default() -> Self {
+        StorageScheme::Ipfs
+    }
+}
+
+/// Parameters for adding evidence to a claim
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AddClaimEvidenceParams {
+    /// SHA-256 of the attachment content
+    pub content_hash: [u8; 32],
+    /// MIME type of the attachment
+    pub mime_type: String,
+    /// Storage scheme of the attachment
+    pub storage_scheme: StorageScheme,
+    /// Location of the attachment within the storage scheme
+    pub uri: String,
+    /// Index of an earlier record by the same submitter that this amends
+    pub amends: Option<u16>,
+}
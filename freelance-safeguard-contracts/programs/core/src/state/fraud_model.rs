@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+/// Basis-point denominator for fraud model weights
+pub const FRAUD_WEIGHT_DENOMINATOR: u16 = 10_000;
+
+/// Configurable claim fraud scoring model
+/// Weights are fixed-point basis points and must sum to `FRAUD_WEIGHT_DENOMINATOR`
+#[account]
+#[derive(Default)]
+pub struct FraudModel {
+    /// Model version, incremented on every update and recorded on scored claims
+    pub version: u32,
+    /// Optional DAO governance key allowed to update the model alongside the admin
+    pub governance_authority: Option<Pubkey>,
+    /// Weight of the policy's risk score (bps)
+    pub policy_risk_weight_bps: u16,
+    /// Weight of the claim amount to coverage ratio (bps)
+    pub amount_ratio_weight_bps: u16,
+    /// Weight of how early in the policy term the claim is filed (bps)
+    pub policy_age_weight_bps: u16,
+    /// Weight of the policy's previous claims (bps)
+    pub claims_history_weight_bps: u16,
+    /// Weight of the claim amount anomaly against the product's history (bps)
+    pub anomaly_weight_bps: u16,
+    /// Claims history factor added per previous claim (0-100)
+    pub claims_history_step: u8,
+    /// Deviation above the product mean at which the anomaly factor saturates
+    /// (in bps of a standard deviation, e.g. 30_000 = 3 sigma)
+    pub anomaly_saturation_sigma_bps: u32,
+    /// Minimum claims observed on a product before its history is trusted
+    pub min_anomaly_samples: u64,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// Signer of the last update
+    pub updated_by: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FraudModel {
+    pub const SEED_PREFIX: &'static [u8] = b"fraud_model";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        4 +  // version
+        (1 + 32) + // governance_authority
+        2 +  // policy_risk_weight_bps
+        2 +  // amount_ratio_weight_bps
+        2 +  // policy_age_weight_bps
+        2 +  // claims_history_weight_bps
+        2 +  // anomaly_weight_bps
+        1 +  // claims_history_step
+        4 +  // anomaly_saturation_sigma_bps
+        8 +  // min_anomaly_samples
+        8 +  // updated_at
+        32 + // updated_by
+        1;   // bump
+    
+    /// Whether the weights sum to the basis-point denominator
+    pub fn weights_valid(&self) -> bool {
+        self.policy_risk_weight_bps as u32 +
+            self.amount_ratio_weight_bps as u32 +
+            self.policy_age_weight_bps as u32 +
+            self.claims_history_weight_bps as u32 +
+            self.anomaly_weight_bps as u32 == FRAUD_WEIGHT_DENOMINATOR as u32
+    }
+    
+    /// Whether the given signer may update the model
+    pub fn can_update(&self, signer: &Pubkey, admin: &Pubkey) -> bool {
+        signer == admin || self.governance_authority.as_ref() == Some(signer)
+    }
+}
+
+/// Parameters for updating the fraud model
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateFraudModelParams {
+    /// DAO governance key (admin only; Some(None) clears it)
+    pub governance_authority: Option<Option<Pubkey>>,
+    /// Weight of the policy's risk score (bps)
+    pub policy_risk_weight_bps: Option<u16>,
+    /// Weight of the claim amount to coverage ratio (bps)
+    pub amount_ratio_weight_bps: Option<u16>,
+    /// Weight of how early in the policy term the claim is filed (bps)
+    pub policy_age_weight_bps: Option<u16>,
+    /// Weight of the policy's previous claims (bps)
+    pub claims_history_weight_bps: Option<u16>,
+    /// Weight of the claim amount anomaly (bps)
+    pub anomaly_weight_bps: Option<u16>,
+    /// Claims history factor added per previous claim
+    pub claims_history_step: Option<u8>,
+    /// Anomaly saturation point (bps of a standard deviation)
+    pub anomaly_saturation_sigma_bps: Option<u32>,
+    /// Minimum claims observed before a product's history is trusted
+    pub min_anomaly_samples: Option<u64>,
+}
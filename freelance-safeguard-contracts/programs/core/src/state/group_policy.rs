@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use crate::state::common::*;
+
+/// Maximum number of members on a single group policy
+pub const MAX_GROUP_MEMBERS: u32 = 500;
+
+/// Master policy held by an agency, covering its freelancers through
+/// member certificates
+#[account]
+#[derive(Default)]
+pub struct GroupPolicy {
+    /// Agency admin that manages membership and pays premiums
+    pub admin: Pubkey,
+    /// Product the group is insured under
+    pub product: Pubkey,
+    /// Mint premiums and payouts are denominated in
+    pub premium_mint: Pubkey,
+    /// Maximum coverage any single member can hold
+    pub per_member_limit: u64,
+    /// Maximum coverage across all members, and maximum total payout
+    pub aggregate_limit: u64,
+    /// Coverage currently allocated to members
+    pub allocated_coverage: u64,
+    /// Sum of member coverage weighted by risk score (for blending)
+    pub risk_weighted_coverage: u128,
+    /// Coverage-weighted average risk score of the members (0-100)
+    pub blended_risk_score: u8,
+    /// Number of current members
+    pub member_count: u32,
+    /// Total premiums paid for members
+    pub total_premiums: u64,
+    /// Total claims paid to members
+    pub total_claims_paid: u64,
+    /// Group status
+    pub status: PolicyStatus,
+    /// Coverage start date (Unix timestamp)
+    pub start_date: i64,
+    /// Coverage end date (Unix timestamp)
+    pub end_date: i64,
+    /// Claim period end date (Unix timestamp)
+    pub claim_period_end: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GroupPolicy {
+    pub const SEED_PREFIX: &'static [u8] = b"group_policy";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // admin
+        32 + // product
+        32 + // premium_mint
+        8 +  // per_member_limit
+        8 +  // aggregate_limit
+        8 +  // allocated_coverage
+        16 + // risk_weighted_coverage
+        1 +  // blended_risk_score
+        4 +  // member_count
+        8 +  // total_premiums
+        8 +  // total_claims_paid
+        1 +  // status
+        8 +  // start_date
+        8 +  // end_date
+        8 +  // claim_period_end
+        1;   // bump
+    
+    /// Add a member's coverage to the blend
+    pub fn add_member_risk(&mut self, coverage_amount: u64, risk_score: u8) {
+        self.allocated_coverage += coverage_amount;
+        self.risk_weighted_coverage += coverage_amount as u128 * risk_score as u128;
+        self.member_count += 1;
+        self.update_blended_risk();
+    }
+    
+    /// Remove a member's coverage from the blend
+    pub fn remove_member_risk(&mut self, coverage_amount: u64, risk_score: u8) {
+        self.allocated_coverage = self.allocated_coverage.saturating_sub(coverage_amount);
+        self.risk_weighted_coverage = self.risk_weighted_coverage
+            .saturating_sub(coverage_amount as u128 * risk_score as u128);
+        self.member_count = self.member_count.saturating_sub(1);
+        self.update_blended_risk();
+    }
+    
+    /// Payout capacity left under the aggregate limit
+    pub fn remaining_aggregate(&self) -> u64 {
+        self.aggregate_limit.saturating_sub(self.total_claims_paid)
+    }
+    
+    fn update_blended_risk(&mut self) {
+        self.blended_risk_score = if self.allocated_coverage > 0 {
+            (self.risk_weighted_coverage / self.allocated_coverage as u128).min(100) as u8
+        } else {
+            0
+        };
+    }
+}
+
+/// Member certificate under a group policy
+#[account]
+#[derive(Default)]
+pub struct GroupMember {
+    /// Group policy the certificate belongs to
+    pub group: Pubkey,
+    /// Covered freelancer
+    pub member: Pubkey,
+    /// Coverage amount for this member
+    pub coverage_amount: u64,
+    /// Member's individual risk score (0-100)
+    pub risk_score: u8,
+    /// Job type (for risk calculation)
+    pub job_type: JobType,
+    /// Industry (for risk calculation)
+    pub industry: Industry,
+    /// Premium paid for this member
+    pub premium_paid: u64,
+    /// Certificate status
+    pub status: PolicyStatus,
+    /// Number of claims filed against this certificate
+    pub claims_count: u8,
+    /// Total claims paid to this member
+    pub claims_paid: u64,
+    /// Timestamp the member joined
+    pub joined_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GroupMember {
+    pub const SEED_PREFIX: &'static [u8] = b"group_member";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // group
+        32 + // member
+        8 +  // coverage_amount
+        1 +  // risk_score
+        1 +  // job_type
+        1 +  // industry
+        8 +  // premium_paid
+        1 +  // status
+        1 +  // claims_count
+        8 +  // claims_paid
+        8 +  // joined_at
+        1;   // bump
+}
+
+/// Parameters for creating a group policy
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateGroupPolicyParams {
+    /// Maximum coverage any single member can hold
+    pub per_member_limit: u64,
+    /// Maximum coverage across all members
+    pub aggregate_limit: u64,
+    /// Coverage period in days
+    pub period_days: u16,
+}
+
+/// Parameters for adding a member to a group policy
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AddGroupMemberParams {
+    /// Freelancer to cover
+    pub member: Pubkey,
+    /// Coverage amount for the member
+    pub coverage_amount: u64,
+    /// Job type
+    pub job_type: JobType,
+}
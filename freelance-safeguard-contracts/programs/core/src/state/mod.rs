@@ -6,6 +6,14 @@ pub mod risk_pool;
 pub mod capital_provider;
 pub mod common;
 pub mod constants;
+pub mod arbitrator;
+pub mod evidence;
+pub mod subrogation;
+pub mod fraud_model;
+pub mod circuit_breaker;
+pub mod domain_treasury;
+pub mod referral;
+pub mod group_policy;
 
 // Re-exports
 pub use program_state::*;
@@ -16,4 +24,12 @@ pub use risk_pool::*;
 pub use capital_provider::*;
 pub use common::*;
 pub use constants::*;
+pub use arbitrator::*;
+pub use evidence::*;
+pub use subrogation::*;
+pub use fraud_model::*;
+pub use circuit_breaker::*;
+pub use domain_treasury::*;
+pub use referral::*;
+pub use group_policy::*;
 
@@ -9,10 +9,14 @@ pub struct Policy {
     pub owner: Pubkey,
     /// Product ID that this policy is based on
     pub product_id: Pubkey,
+    /// Per-owner policy number, part of the PDA seed
+    pub nonce: u32,
     /// Coverage amount in lamports
     pub coverage_amount: u64,
     /// Premium amount paid in lamports
     pub premium_amount: u64,
+    /// Mint the premium was paid in (and payouts are made in)
+    pub premium_mint: Pubkey,
     /// Policy start date (Unix timestamp)
     pub start_date: i64,
     /// Policy end date (Unix timestamp)
@@ -39,6 +43,10 @@ pub struct Policy {
     pub last_update_slot: u64,
     /// NFT mint address (if tokenized)
     pub nft_mint: Option<Pubkey>,
+    /// Referrer credited with the sale (if any)
+    pub referrer: Option<Pubkey>,
+    /// Escrow the policy is bound to (coverage tracks its unreleased milestones)
+    pub escrow: Option<Pubkey>,
     /// PDA bump seed
     pub bump: u8,
 }
@@ -50,8 +58,10 @@ impl Policy {
     pub const SIZE: usize = 8 + // discriminator
         32 + // owner
         32 + // product_id
+        4 +  // nonce
         8 +  // coverage_amount
         8 +  // premium_amount
+        32 + // premium_mint
         8 +  // start_date
         8 +  // end_date
         8 +  // claim_period_end
@@ -65,6 +75,74 @@ impl Policy {
         8 +  // creation_block
         8 +  // last_update_slot
         (1 + 32) + // Option<Pubkey> for nft_mint
+        (1 + 32) + // Option<Pubkey> for referrer
+        (1 + 32) + // Option<Pubkey> for escrow
+        1;   // bump
+}
+
+/// Parameters for purchasing a policy bound to an escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PurchaseEscrowPolicyParams {
+    /// Job type
+    pub job_type: JobType,
+    /// Industry
+    pub industry: Industry,
+    /// Optional reputation score
+    pub reputation_score: Option<u8>,
+    /// Optional additional details/metadata
+    pub policy_details: Option<String>,
+}
+
+/// Maximum number of open policies listed on an owner's index
+pub const MAX_POLICIES_PER_OWNER: usize = 32;
+
+/// Owner-level index of policies, which also hands out policy nonces
+#[account]
+#[derive(Default)]
+pub struct PolicyIndex {
+    /// Policy owner
+    pub owner: Pubkey,
+    /// Nonce assigned to the owner's next policy
+    pub next_nonce: u32,
+    /// Policies the owner holds (closed policies are dropped)
+    pub policies: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PolicyIndex {
+    pub const SEED_PREFIX: &'static [u8] = b"policy_index";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // owner
+        4 +  // next_nonce
+        (4 + MAX_POLICIES_PER_OWNER * 32) + // policies
+        1;   // bump
+    
+    /// Drop a policy from the index
+    pub fn remove_policy(&mut self, policy: &Pubkey) {
+        self.policies.retain(|p| p != policy);
+    }
+}
+
+/// Marks an escrow as insured, so each escrow backs at most one policy
+#[account]
+#[derive(Default)]
+pub struct EscrowCover {
+    /// Escrow the policy covers
+    pub escrow: Pubkey,
+    /// Policy bound to the escrow
+    pub policy: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl EscrowCover {
+    pub const SEED_PREFIX: &'static [u8] = b"escrow_cover";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // escrow
+        32 + // policy
         1;   // bump
 }
 
@@ -89,5 +167,7 @@ pub struct PurchasePolicyParams {
     pub policy_details: Option<String>,
     /// Whether to mint a policy NFT
     pub mint_nft: bool,
+    /// Optional referrer wallet credited with the sale
+    pub referrer: Option<Pubkey>,
 }
 
@@ -36,6 +36,10 @@ pub struct Product {
     pub total_premiums: u64,
     /// Whether the product is active
     pub active: bool,
+    /// Whether claims under this product use commit-reveal voting
+    pub commit_reveal_voting: bool,
+    /// Mint that premiums and payouts for this product are denominated in
+    pub premium_mint: Pubkey,
     /// Minimum period in days for policies
     pub min_period_days: u16,
     /// Maximum period in days for policies
@@ -50,6 +54,12 @@ pub struct Product {
     pub claims_paid_amount: u64,
     /// Loss ratio (claims paid / premiums collected) * 100
     pub loss_ratio: u16,
+    /// Number of claim amounts observed (for anomaly detection)
+    pub claim_amount_samples: u64,
+    /// Running mean of claim amounts
+    pub claim_amount_mean: u64,
+    /// Running sum of squared deviations of claim amounts (Welford's M2)
+    pub claim_amount_m2: u128,
     /// Minimum coverage amount allowed for this product
     pub min_coverage_amount: u64,
     /// Maximum coverage amount allowed for this product
@@ -80,6 +90,8 @@ impl Product {
         8 + // total_coverage
         8 + // total_premiums
         1 + // active
+        1 + // commit_reveal_voting
+        32 + // premium_mint
         2 + // min_period_days
         2 + // max_period_days
         2 + // base_premium_rate
@@ -87,11 +99,34 @@ impl Product {
         8 + // claims_count
         8 + // claims_paid_amount
         2 + // loss_ratio
+        8 + // claim_amount_samples
+        8 + // claim_amount_mean
+        16 + // claim_amount_m2
         8 + // min_coverage_amount
         8 + // max_coverage_amount
         8 + // created_at
         8 + // last_updated
         1;  // bump
+    
+    /// Fold a claim amount into the running mean and variance (Welford)
+    pub fn record_claim_amount(&mut self, amount: u64) {
+        self.claim_amount_samples += 1;
+        
+        let delta = amount as i128 - self.claim_amount_mean as i128;
+        let mean = self.claim_amount_mean as i128 + delta / self.claim_amount_samples as i128;
+        let delta_after = amount as i128 - mean;
+        
+        self.claim_amount_mean = mean as u64;
+        self.claim_amount_m2 = (self.claim_amount_m2 as i128 + delta * delta_after).max(0) as u128;
+    }
+    
+    /// Sample standard deviation of observed claim amounts
+    pub fn claim_amount_std_dev(&self) -> u64 {
+        if self.claim_amount_samples < 2 {
+            return 0;
+        }
+        crate::utils::integer_sqrt(self.claim_amount_m2 / (self.claim_amount_samples as u128 - 1))
+    }
 }
 
 /// Parameters for creating a new insurance product
@@ -111,6 +146,8 @@ pub struct CreateProductParams {
     pub min_stake_to_capital_ratio: u8,
     /// Detailed insurance terms and conditions
     pub cover_terms: String,
+    /// Mint for premiums and payouts (must be on the allowlist)
+    pub premium_mint: Pubkey,
 }
 
 /// Parameters for updating an insurance product
@@ -138,4 +175,6 @@ pub struct UpdateProductParams {
     pub max_period_days: Option<u16>,
     /// Optional updated active status
     pub active: Option<bool>,
+    /// Optional updated commit-reveal voting toggle
+    pub commit_reveal_voting: Option<bool>,
 }
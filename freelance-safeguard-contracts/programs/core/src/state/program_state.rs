@@ -44,6 +44,24 @@ pub struct ProgramState {
     pub min_votes_required: u8,
     /// Voting period in days
     pub voting_period_days: u8,
+    /// Whether commit-reveal voting is enabled for all claims
+    pub commit_reveal_voting: bool,
+    /// Reveal period following the commit phase (in days)
+    pub reveal_period_days: u8,
+    /// Bond posted with each vote commitment, forfeited if not revealed (in lamports)
+    pub vote_commit_bond: u64,
+    /// Number of arbitrators drawn for a dispute panel
+    pub arbitration_panel_size: u8,
+    /// Minimum stake required to register as an arbitrator (in lamports)
+    pub min_arbitrator_stake: u64,
+    /// Period arbitrators have to commit a decision (in days)
+    pub arbitration_period_days: u8,
+    /// Percentage of stake slashed when an arbitrator misses the deadline
+    pub arbitrator_slash_percentage: u8,
+    /// Window for a claim counterparty to acknowledge or contest (in days)
+    pub counterparty_response_days: u8,
+    /// Global cap on referrer commission rates (in basis points)
+    pub max_referral_commission_bps: u16,
     
     // === Premium Calculation Parameters ===
     /// Base premium rate (in lamports)
@@ -84,6 +102,10 @@ pub struct ProgramState {
     pub total_arbitration_fees: u64,
     /// Premium to claims ratio (x100)
     pub premium_to_claims_ratio: u16,
+    /// Total premiums collected across all mints (in micro-USD)
+    pub total_premiums_stable: u64,
+    /// Total claims paid across all mints (in micro-USD)
+    pub total_claims_paid_stable: u64,
     
     // === Risk Pool Stats ===
     /// Total capital in the risk pool
@@ -93,6 +115,10 @@ pub struct ProgramState {
     /// Current reserve ratio
     pub current_reserve_ratio: u8,
     
+    // === Token Parameters ===
+    /// Mints accepted for premiums and payouts
+    pub allowed_mints: Vec<AllowedMint>,
+    
     // === Program Status ===
     /// Whether the program is paused
     pub is_paused: bool,
@@ -134,6 +160,15 @@ impl ProgramState {
         1 +  // auto_process_threshold
         1 +  // min_votes_required
         1 +  // voting_period_days
+        1 +  // commit_reveal_voting
+        1 +  // reveal_period_days
+        8 +  // vote_commit_bond
+        1 +  // arbitration_panel_size
+        8 +  // min_arbitrator_stake
+        1 +  // arbitration_period_days
+        1 +  // arbitrator_slash_percentage
+        1 +  // counterparty_response_days
+        2 +  // max_referral_commission_bps
         8 +  // base_premium_rate
         1 +  // risk_curve_exponent
         1 +  // reputation_impact_weight
@@ -152,9 +187,12 @@ impl ProgramState {
         8 +  // arbitrated_claims
         8 +  // total_arbitration_fees
         2 +  // premium_to_claims_ratio
+        8 +  // total_premiums_stable
+        8 +  // total_claims_paid_stable
         8 +  // total_capital
         8 +  // total_coverage_liability
         1 +  // current_reserve_ratio
+        (4 + MAX_ALLOWED_MINTS * AllowedMint::SIZE) + // allowed_mints
         1 +  // is_paused
         8 +  // last_update_timestamp
         1 +  // base_reserve_ratio option
@@ -173,6 +211,15 @@ impl ProgramState {
         2 +  // auto_process_threshold option
         2 +  // min_votes_required option
         2 +  // voting_period_days option
+        2 +  // commit_reveal_voting option
+        2 +  // reveal_period_days option
+        9 +  // vote_commit_bond option
+        2 +  // arbitration_panel_size option
+        9 +  // min_arbitrator_stake option
+        2 +  // arbitration_period_days option
+        2 +  // arbitrator_slash_percentage option
+        2 +  // counterparty_response_days option
+        3 +  // max_referral_commission_bps option
         9 +  // base_premium_rate option
         2 +  // risk_curve_exponent option
         2 +  // reputation_impact_weight option
@@ -186,6 +233,35 @@ impl ProgramState {
         1;   // bump
 }
 
+/// Maximum number of mints on the allowlist
+pub const MAX_ALLOWED_MINTS: usize = 8;
+
+/// Mint accepted for premiums and payouts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct AllowedMint {
+    /// Token mint
+    pub mint: Pubkey,
+    /// Mint decimals
+    pub decimals: u8,
+    /// Reporting value of one whole token (in micro-USD)
+    pub stable_value_micros: u64,
+    /// Timestamp the reporting value was last set
+    pub updated_at: i64,
+}
+
+impl AllowedMint {
+    pub const SIZE: usize = 32 + // mint
+        1 +  // decimals
+        8 +  // stable_value_micros
+        8;   // updated_at
+    
+    /// Convert a raw token amount into its reporting value (in micro-USD)
+    pub fn stable_value(&self, amount: u64) -> u64 {
+        let value = (amount as u128 * self.stable_value_micros as u128) / 10u128.pow(self.decimals as u32);
+        value.min(u64::MAX as u128) as u64
+    }
+}
+
 impl ProgramState {
     /// Create a new program state
     pub fn new(
@@ -231,6 +307,16 @@ impl ProgramState {
         Ok(())
     }
     
+    /// Look up a mint on the allowlist
+    pub fn allowed_mint(&self, mint: &Pubkey) -> Option<&AllowedMint> {
+        self.allowed_mints.iter().find(|m| m.mint == *mint)
+    }
+    
+    /// Reporting value of a token amount, or zero for mints off the allowlist
+    pub fn stable_value(&self, mint: &Pubkey, amount: u64) -> u64 {
+        self.allowed_mint(mint).map(|m| m.stable_value(amount)).unwrap_or(0)
+    }
+    
     /// Update feature flags
     pub fn update_feature_flags(&mut self, new_flags: FeatureFlags) {
         self.feature_flags = new_flags;
@@ -353,6 +439,24 @@ pub struct UpdateProgramParamsParams {
     pub min_votes_required: Option<u8>,
     /// Voting period in days
     pub voting_period_days: Option<u8>,
+    /// Whether commit-reveal voting is enabled for all claims
+    pub commit_reveal_voting: Option<bool>,
+    /// Reveal period following the commit phase (in days)
+    pub reveal_period_days: Option<u8>,
+    /// Bond posted with each vote commitment (in lamports)
+    pub vote_commit_bond: Option<u64>,
+    /// Number of arbitrators drawn for a dispute panel
+    pub arbitration_panel_size: Option<u8>,
+    /// Minimum stake required to register as an arbitrator (in lamports)
+    pub min_arbitrator_stake: Option<u64>,
+    /// Period arbitrators have to commit a decision (in days)
+    pub arbitration_period_days: Option<u8>,
+    /// Percentage of stake slashed when an arbitrator misses the deadline
+    pub arbitrator_slash_percentage: Option<u8>,
+    /// Window for a claim counterparty to acknowledge or contest (in days)
+    pub counterparty_response_days: Option<u8>,
+    /// Global cap on referrer commission rates (in basis points)
+    pub max_referral_commission_bps: Option<u16>,
     /// Base premium rate (in lamports)
     pub base_premium_rate: Option<u64>,
     /// Risk curve exponent for non-linear pricing
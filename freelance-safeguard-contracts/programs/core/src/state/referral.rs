@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use crate::state::constants::REFERRAL_COMMISSION_DENOMINATOR;
+
+/// Registered referrer (freelance platform, agency, affiliate) that
+/// earns a commission on the policies it brings in
+#[account]
+#[derive(Default)]
+pub struct Referrer {
+    /// Referrer wallet
+    pub wallet: Pubkey,
+    /// Mint commissions are earned in (referred policies must use it)
+    pub mint: Pubkey,
+    /// Token account commissions are paid to
+    pub payout_token_account: Pubkey,
+    /// Commission rate on premiums (in basis points)
+    pub commission_bps: u16,
+    /// How commissions are settled
+    pub payout_mode: CommissionPayoutMode,
+    /// Whether the referrer can earn on new sales
+    pub active: bool,
+    /// Policies sold or renewed through this referrer
+    pub policies_sold: u64,
+    /// Premium volume brought in (in tokens)
+    pub premium_volume: u64,
+    /// Claims paid on referred policies (in tokens)
+    pub claims_paid: u64,
+    /// Loss ratio of referred policies (claims paid / premiums) * 100
+    pub loss_ratio: u16,
+    /// Total commission earned (in tokens)
+    pub commission_earned: u64,
+    /// Commission accrued but not yet claimed (in tokens)
+    pub commission_accrued: u64,
+    /// Registration timestamp
+    pub registered_at: i64,
+    /// Last update timestamp
+    pub last_update: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Referrer {
+    pub const SEED_PREFIX: &'static [u8] = b"referrer";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // wallet
+        32 + // mint
+        32 + // payout_token_account
+        2 +  // commission_bps
+        1 +  // payout_mode
+        1 +  // active
+        8 +  // policies_sold
+        8 +  // premium_volume
+        8 +  // claims_paid
+        2 +  // loss_ratio
+        8 +  // commission_earned
+        8 +  // commission_accrued
+        8 +  // registered_at
+        8 +  // last_update
+        1;   // bump
+    
+    /// Commission owed on a premium, clamped to the global cap
+    pub fn commission_for(&self, premium: u64, max_commission_bps: u16) -> u64 {
+        let bps = self.commission_bps.min(max_commission_bps);
+        ((premium as u128 * bps as u128) / REFERRAL_COMMISSION_DENOMINATOR as u128) as u64
+    }
+    
+    /// Record a sale and the commission it earned
+    pub fn record_sale(&mut self, premium: u64, commission: u64, timestamp: i64) {
+        self.policies_sold += 1;
+        self.premium_volume += premium;
+        self.commission_earned += commission;
+        if self.payout_mode == CommissionPayoutMode::Accrued {
+            self.commission_accrued += commission;
+        }
+        self.update_loss_ratio();
+        self.last_update = timestamp;
+    }
+    
+    /// Record a claim paid on a referred policy
+    pub fn record_claim_paid(&mut self, amount: u64, timestamp: i64) {
+        self.claims_paid += amount;
+        self.update_loss_ratio();
+        self.last_update = timestamp;
+    }
+    
+    fn update_loss_ratio(&mut self) {
+        if self.premium_volume > 0 {
+            self.loss_ratio = ((self.claims_paid as u128 * 100) / self.premium_volume as u128)
+                .min(u16::MAX as u128) as u16;
+        }
+    }
+}
+
+/// How a referrer's commission is settled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum CommissionPayoutMode {
+    /// Split out of the premium at purchase time
+    Immediate,
+    /// Kept in the risk pool and claimed later by the referrer
+    Accrued,
+}
+
+impl Default for CommissionPayoutMode {
+    fn default() -> Self {
+        CommissionPayoutMode::Accrued
+    }
+}
+
+/// Parameters for registering a referrer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisterReferrerParams {
+    /// Referrer wallet
+    pub wallet: Pubkey,
+    /// Mint commissions are earned in (must be on the allowlist)
+    pub mint: Pubkey,
+    /// Token account commissions are paid to
+    pub payout_token_account: Pubkey,
+    /// Commission rate on premiums (in basis points)
+    pub commission_bps: u16,
+    /// How commissions are settled
+    pub payout_mode: CommissionPayoutMode,
+}
+
+/// Parameters for updating a referrer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateReferrerParams {
+    /// Optional updated payout token account
+    pub payout_token_account: Option<Pubkey>,
+    /// Optional updated commission rate (in basis points)
+    pub commission_bps: Option<u16>,
+    /// Optional updated payout mode
+    pub payout_mode: Option<CommissionPayoutMode>,
+    /// Optional updated active status
+    pub active: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn referrer(commission_bps: u16) -> Referrer {
+        Referrer {
+            commission_bps,
+            ..Default::default()
+        }
+    }
+    
+    #[test]
+    fn test_commission_for() {
+        assert_eq!(referrer(500).commission_for(10_000, 1_000), 500);
+        assert_eq!(referrer(0).commission_for(10_000, 1_000), 0);
+    }
+    
+    #[test]
+    fn test_commission_for_clamps_to_cap() {
+        assert_eq!(referrer(500).commission_for(10_000, 200), 200);
+        assert_eq!(referrer(500).commission_for(10_000, 0), 0);
+    }
+    
+    #[test]
+    fn test_commission_for_rounding_and_overflow() {
+        assert_eq!(referrer(100).commission_for(199, 1_000), 1); // Rounds down
+        assert_eq!(referrer(10_000).commission_for(u64::MAX, 10_000), u64::MAX);
+    }
+}
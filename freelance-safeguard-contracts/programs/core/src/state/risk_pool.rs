@@ -30,6 +30,10 @@ pub struct RiskPool {
     pub treasury_wallet: Pubkey,
     /// Whether the risk pool is paused
     pub is_paused: bool,
+    /// Total arbitrator stake slashed into the pool (in lamports)
+    pub total_slashed_stake: u64,
+    /// Total unrevealed vote bonds forfeited into the pool (in lamports)
+    pub total_forfeited_bonds: u64,
     /// PDA bump seed
     pub bump: u8,
 }
@@ -51,6 +55,8 @@ impl RiskPool {
         1 +  // treasury_allocation_percentage
         32 + // treasury_wallet
         1 +  // is_paused
+        8 +  // total_slashed_stake
+        8 +  // total_forfeited_bonds
         1;   // bump
 }
 
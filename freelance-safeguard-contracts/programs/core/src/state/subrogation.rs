@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+/// Recovery claim against a defaulting client, opened when a
+/// non-payment claim is paid out
+#[account]
+#[derive(Default)]
+pub struct Subrogation {
+    /// Paid claim this record recovers
+    pub claim: Pubkey,
+    /// Product that absorbed the payout
+    pub product: Pubkey,
+    /// Client who owes the amount
+    pub client: Pubkey,
+    /// Amount owed by the client (in tokens)
+    pub amount_owed: u64,
+    /// Amount recovered so far (in tokens)
+    pub amount_recovered: u64,
+    /// Recovery status
+    pub status: SubrogationStatus,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// Deadline for repayment before the client is flagged (Unix timestamp)
+    pub repayment_deadline: i64,
+    /// Timestamp of the last repayment
+    pub last_repayment_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Subrogation {
+    pub const SEED_PREFIX: &'static [u8] = b"subrogation";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // claim
+        32 + // product
+        32 + // client
+        8 +  // amount_owed
+        8 +  // amount_recovered
+        1 +  // status
+        8 +  // created_at
+        8 +  // repayment_deadline
+        8 +  // last_repayment_at
+        1;   // bump
+    
+    /// Amount still outstanding
+    pub fn outstanding(&self) -> u64 {
+        self.amount_owed.saturating_sub(self.amount_recovered)
+    }
+}
+
+/// Subrogation recovery status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum SubrogationStatus {
+    /// Nothing recovered yet
+    Open,
+    /// Part of the amount has been recovered
+    PartiallyRecovered,
+    /// Amount fully recovered
+    Recovered,
+    /// Repayment deadline passed with an outstanding balance
+    Defaulted,
+}
+
+impl Default for SubrogationStatus {
+    fn default() -> Self {
+        SubrogationStatus::Open
+    }
+}
+
+/// Per-client debt summary, readable by the reputation program
+#[account]
+#[derive(Default)]
+pub struct DebtorRecord {
+    /// Client wallet
+    pub client: Pubkey,
+    /// Subrogations not yet fully recovered
+    pub open_subrogations: u16,
+    /// Total owed across all subrogations (in tokens)
+    pub total_owed: u64,
+    /// Total recovered across all subrogations (in tokens)
+    pub total_recovered: u64,
+    /// Whether the client has defaulted on a repayment
+    pub is_bad_debtor: bool,
+    /// Last update timestamp
+    pub last_update: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DebtorRecord {
+    pub const SEED_PREFIX: &'static [u8] = b"debtor";
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // client
+        2 +  // open_subrogations
+        8 +  // total_owed
+        8 +  // total_recovered
+        1 +  // is_bad_debtor
+        8 +  // last_update
+        1;   // bump
+}
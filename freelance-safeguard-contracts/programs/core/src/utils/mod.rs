@@ -1,5 +1,7 @@
 pub mod risk_calculations;
 pub mod arbitration_fees;
+pub mod panel_selection;
 
 pub use risk_calculations::*;
 pub use arbitration_fees::*;
+pub use panel_selection::*;
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::FreelanceShieldError;
+
+/// Derive a panel selection seed from the hash of the first slot at or after `draw_slot`
+/// The draw slot is fixed when the panel is opened, so whoever opens it can't
+/// pick the hash. The claim key is mixed in so draws sharing a slot differ.
+/// Returns `None` once that slot has aged out of the SlotHashes sysvar.
+pub fn derive_selection_seed(
+    slot_hashes: &AccountInfo,
+    draw_slot: u64,
+    claim: &Pubkey,
+) -> Result<Option<([u8; 32], u64)>> {
+    let data = slot_hashes.try_borrow_data()?;
+
+    Ok(find_slot_hash(&data, draw_slot)
+        .map(|(slot, slot_hash)| (hashv(&[slot_hash, claim.as_ref()]).to_bytes(), slot)))
+}
+
+/// Find the hash of the first recorded slot at or after `draw_slot`
+/// SlotHashes layout: u64 entry count, then (slot: u64, hash: [u8; 32]) entries,
+/// newest first. The match only counts if an older entry (or `draw_slot` itself)
+/// is still recorded, proving no earlier slot at or after `draw_slot` was dropped.
+fn find_slot_hash(data: &[u8], draw_slot: u64) -> Option<(u64, &[u8])> {
+    let mut count_bytes = [0u8; 8];
+    count_bytes.copy_from_slice(data.get(..8)?);
+    let count = u64::from_le_bytes(count_bytes) as usize;
+
+    let mut found: Option<(u64, &[u8])> = None;
+    for entry in data.get(8..)?.chunks_exact(40).take(count) {
+        let mut slot_bytes = [0u8; 8];
+        slot_bytes.copy_from_slice(&entry[..8]);
+        let slot = u64::from_le_bytes(slot_bytes);
+
+        if slot < draw_slot {
+            return found;
+        }
+        found = Some((slot, &entry[8..]));
+        if slot == draw_slot {
+            return found;
+        }
+    }
+
+    None
+}
+
+/// Draw `panel_size` distinct arbitrators from `candidates` using the given seed
+/// Runs a partial Fisher-Yates shuffle, re-hashing the seed for every draw
+pub fn select_panel(
+    candidates: &[Pubkey],
+    panel_size: usize,
+    seed: &[u8; 32],
+) -> Result<Vec<Pubkey>> {
+    require!(
+        panel_size > 0 && candidates.len() >= panel_size,
+        FreelanceShieldError::NotEnoughArbitrators
+    );
+
+    let mut pool = candidates.to_vec();
+    let mut panel = Vec::with_capacity(panel_size);
+
+    for round in 0..panel_size {
+        let draw = hashv(&[seed.as_ref(), &(round as u64).to_le_bytes()]).to_bytes();
+        let mut random_bytes = [0u8; 8];
+        random_bytes.copy_from_slice(&draw[..8]);
+
+        let remaining = pool.len() - round;
+        let pick = round + (u64::from_le_bytes(random_bytes) % remaining as u64) as usize;
+
+        pool.swap(round, pick);
+        panel.push(pool[round]);
+    }
+
+    Ok(panel)
+}
+
+/// Calculate the portion of an arbitrator's stake slashed for a missed deadline
+pub fn calculate_slash_amount(stake_amount: u64, slash_percentage: u8) -> Result<u64> {
+    require!(slash_percentage <= 100, FreelanceShieldError::InvalidParameter);
+
+    let slash = (stake_amount as u128)
+        .checked_mul(slash_percentage as u128)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?
+        .checked_div(100)
+        .ok_or(FreelanceShieldError::ArithmeticOverflow)?;
+
+    Ok(slash as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn test_find_slot_hash_uses_first_slot_at_or_after_draw() {
+        let data = slot_hashes(&[105, 104, 102, 99]);
+
+        assert_eq!(find_slot_hash(&data, 104), Some((104, &[104u8; 32][..])));
+        assert_eq!(find_slot_hash(&data, 100), Some((102, &[102u8; 32][..]))); // Skipped slots fall through
+        assert_eq!(find_slot_hash(&data, 106), None); // Not produced yet
+    }
+
+    #[test]
+    fn test_find_slot_hash_rejects_aged_out_slots() {
+        let data = slot_hashes(&[105, 104, 102]);
+
+        assert_eq!(find_slot_hash(&data, 102), Some((102, &[102u8; 32][..])));
+        assert_eq!(find_slot_hash(&data, 101), None); // Slot 101 may have been dropped
+    }
+
+    #[test]
+    fn test_select_panel_without_replacement() {
+        let pool = candidates(12);
+        let panel = select_panel(&pool, 7, &[3u8; 32]).unwrap();
+
+        assert_eq!(panel.len(), 7);
+        for (i, member) in panel.iter().enumerate() {
+            assert!(pool.contains(member));
+            assert!(!panel[i + 1..].contains(member)); // No arbitrator is drawn twice
+        }
+    }
+
+    #[test]
+    fn test_select_panel_full_draw_is_permutation() {
+        let pool = candidates(5);
+        let mut panel = select_panel(&pool, 5, &[9u8; 32]).unwrap();
+
+        panel.sort();
+        let mut expected = pool.clone();
+        expected.sort();
+        assert_eq!(panel, expected);
+    }
+
+    #[test]
+    fn test_select_panel_deterministic() {
+        let pool = candidates(10);
+        assert_eq!(
+            select_panel(&pool, 3, &[42u8; 32]).unwrap(),
+            select_panel(&pool, 3, &[42u8; 32]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_select_panel_rejects_invalid_sizes() {
+        let pool = candidates(3);
+        assert!(select_panel(&pool, 0, &[1u8; 32]).is_err());
+        assert!(select_panel(&pool, 4, &[1u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_calculate_slash_amount() {
+        assert_eq!(calculate_slash_amount(1_000, 10).unwrap(), 100);
+        assert_eq!(calculate_slash_amount(999, 10).unwrap(), 99); // Rounds down
+        assert_eq!(calculate_slash_amount(u64::MAX, 100).unwrap(), u64::MAX);
+        assert!(calculate_slash_amount(1_000, 101).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::FreelanceShieldError;
+use crate::state::fraud_model::{FraudModel, FRAUD_WEIGHT_DENOMINATOR};
 
 /// Calculate the minimum capital requirement based on risk parameters
 pub fn calculate_min_capital_requirement(
@@ -204,16 +205,18 @@ pub fn calculate_risk_score(
     Ok(risk_score as u8)
 }
 
-/// Calculate risk score for a claim based on multiple weighted factors
+/// Calculate risk score for a claim using the configured fraud model
+/// All math is integer; `amount_stats` is the product's (samples, mean, std dev)
 /// Returns a risk score from 0-100 (higher = riskier)
 pub fn calculate_claim_risk_score(
+    model: &FraudModel,
     policy_risk_score: u8,
     claim_amount: u64,
     coverage_amount: u64,
     policy_duration_seconds: i64,
     time_since_policy_start: i64,
     claims_count: u8,
-    avg_claim_amount: Option<u64>,
+    amount_stats: (u64, u64, u64),
 ) -> Result<u8> {
     // Validate inputs
     require!(
@@ -224,7 +227,7 @@ pub fn calculate_claim_risk_score(
     // Factor 1: Amount ratio (claim amount as percentage of coverage)
     // Higher percentage = higher risk
     let amount_ratio = if coverage_amount > 0 {
-        ((claim_amount as f64 / coverage_amount as f64) * 100.0) as u8
+        ((claim_amount as u128 * 100) / coverage_amount as u128).min(100) as u64
     } else {
         100 // Maximum risk if coverage amount is zero
     };
@@ -232,53 +235,63 @@ pub fn calculate_claim_risk_score(
     // Factor 2: Policy age factor
     // Newer policies with claims are riskier
     let policy_age_factor = if policy_duration_seconds > 0 {
-        let policy_age_percentage = ((time_since_policy_start as f64 / policy_duration_seconds as f64) * 100.0) as u8;
+        let elapsed = time_since_policy_start.max(0) as u128;
+        let policy_age_percentage = ((elapsed * 100) / policy_duration_seconds as u128).min(100) as u64;
         // Invert so newer policies (lower percentage) have higher risk
-        100 - policy_age_percentage.min(100)
+        100 - policy_age_percentage
     } else {
         100 // Maximum risk if policy duration is zero
     };
     
     // Factor 3: Previous claims factor
     // More previous claims = higher risk
-    let claims_factor = (claims_count * 20).min(100);
+    let claims_factor = (claims_count as u64 * model.claims_history_step as u64).min(100);
     
     // Factor 4: Anomaly detection
-    // Compare claim amount to average claim amount
-    let anomaly_factor = if let Some(avg) = avg_claim_amount {
-        if avg > 0 {
-            let deviation = if claim_amount > avg {
-                ((claim_amount as f64 / avg as f64) - 1.0) * 100.0
-            } else {
-                0.0
-            };
-            (deviation as u8).min(100)
-        } else {
-            50 // Neutral if no average data
-        }
+    // Distance above the product's mean claim amount, in standard deviations
+    let (samples, mean, std_dev) = amount_stats;
+    let anomaly_factor = if samples < model.min_anomaly_samples.max(2) {
+        50 // Neutral until enough history exists
+    } else if claim_amount <= mean {
+        0
+    } else if std_dev == 0 || model.anomaly_saturation_sigma_bps == 0 {
+        100 // Any amount above a constant history is anomalous
     } else {
-        50 // Neutral if no average data
+        let sigma_bps = ((claim_amount - mean) as u128 * FRAUD_WEIGHT_DENOMINATOR as u128) / std_dev as u128;
+        ((sigma_bps * 100) / model.anomaly_saturation_sigma_bps as u128).min(100) as u64
     };
     
     // Calculate weighted risk score
-    // Base policy risk: 30%
-    // Amount ratio: 25%
-    // Policy age: 20%
-    // Claims history: 15%
-    // Anomaly detection: 10%
     let risk_score = (
-        (policy_risk_score as u16 * 30) +
-        (amount_ratio as u16 * 25) +
-        (policy_age_factor as u16 * 20) +
-        (claims_factor as u16 * 15) +
-        (anomaly_factor as u16 * 10)
-    ) / 100;
+        policy_risk_score as u64 * model.policy_risk_weight_bps as u64 +
+        amount_ratio * model.amount_ratio_weight_bps as u64 +
+        policy_age_factor * model.policy_age_weight_bps as u64 +
+        claims_factor * model.claims_history_weight_bps as u64 +
+        anomaly_factor * model.anomaly_weight_bps as u64
+    ) / FRAUD_WEIGHT_DENOMINATOR as u64;
     
     // Log detailed risk factors for transparency
-    msg!("Risk factors - Policy: {}, Amount: {}, Age: {}, Claims: {}, Anomaly: {}", 
-        policy_risk_score, amount_ratio, policy_age_factor, claims_factor, anomaly_factor);
+    msg!("Risk factors (model v{}) - Policy: {}, Amount: {}, Age: {}, Claims: {}, Anomaly: {}", 
+        model.version, policy_risk_score, amount_ratio, policy_age_factor, claims_factor, anomaly_factor);
     
-    Ok(risk_score as u8)
+    Ok(risk_score.min(100) as u8)
+}
+
+/// Integer square root (floor)
+pub fn integer_sqrt(value: u128) -> u64 {
+    if value < 2 {
+        return value as u64;
+    }
+    
+    // Newton's method, starting from an over-estimate
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    
+    x as u64
 }
 
 /// Simple version of claim risk calculation for backward compatibility
@@ -316,3 +329,74 @@ pub fn calculate_claim_risk_score_simple(
     
     Ok(risk_score as u8)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Model with the given weights (policy, amount, age, claims, anomaly)
+    fn model(weights: [u16; 5]) -> FraudModel {
+        FraudModel {
+            policy_risk_weight_bps: weights[0],
+            amount_ratio_weight_bps: weights[1],
+            policy_age_weight_bps: weights[2],
+            claims_history_weight_bps: weights[3],
+            anomaly_weight_bps: weights[4],
+            claims_history_step: 20,
+            anomaly_saturation_sigma_bps: 30_000,
+            min_anomaly_samples: 10,
+            ..Default::default()
+        }
+    }
+    
+    const NO_HISTORY: (u64, u64, u64) = (0, 0, 0);
+    
+    #[test]
+    fn test_claim_risk_amount_ratio() {
+        let model = model([0, 10_000, 0, 0, 0]);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 250, 1_000, 100, 50, 0, NO_HISTORY).unwrap(), 25);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 2_000, 1_000, 100, 50, 0, NO_HISTORY).unwrap(), 100);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 250, 0, 100, 50, 0, NO_HISTORY).unwrap(), 100);
+    }
+    
+    #[test]
+    fn test_claim_risk_policy_age() {
+        let model = model([0, 0, 10_000, 0, 0]);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 100, 1_000, 100, 25, 0, NO_HISTORY).unwrap(), 75);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 100, 1_000, 100, -5, 0, NO_HISTORY).unwrap(), 100);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 100, 1_000, 100, 200, 0, NO_HISTORY).unwrap(), 0);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 100, 1_000, 0, 25, 0, NO_HISTORY).unwrap(), 100);
+    }
+    
+    #[test]
+    fn test_claim_risk_claims_history() {
+        let model = model([0, 0, 0, 10_000, 0]);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 100, 1_000, 100, 50, 0, NO_HISTORY).unwrap(), 0);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 100, 1_000, 100, 50, 2, NO_HISTORY).unwrap(), 40);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 100, 1_000, 100, 50, 10, NO_HISTORY).unwrap(), 100);
+    }
+    
+    #[test]
+    fn test_claim_risk_anomaly() {
+        let model = model([0, 0, 0, 0, 10_000]);
+        let history = (20, 100, 10);
+        
+        // Neutral until the product has enough samples
+        assert_eq!(calculate_claim_risk_score(&model, 0, 500, 1_000, 100, 50, 0, (5, 100, 10)).unwrap(), 50);
+        // At or below the mean is not anomalous
+        assert_eq!(calculate_claim_risk_score(&model, 0, 90, 1_000, 100, 50, 0, history).unwrap(), 0);
+        // 1.5 sigma above the mean is half way to the 3 sigma saturation point
+        assert_eq!(calculate_claim_risk_score(&model, 0, 115, 1_000, 100, 50, 0, history).unwrap(), 50);
+        assert_eq!(calculate_claim_risk_score(&model, 0, 200, 1_000, 100, 50, 0, history).unwrap(), 100);
+        // Anything above a constant history saturates
+        assert_eq!(calculate_claim_risk_score(&model, 0, 101, 1_000, 100, 50, 0, (20, 100, 0)).unwrap(), 100);
+    }
+    
+    #[test]
+    fn test_claim_risk_weighted_blend() {
+        let model = model([2_000; 5]);
+        // Policy 50, amount 50, age 50, claims 0, anomaly neutral 50
+        assert_eq!(calculate_claim_risk_score(&model, 50, 500, 1_000, 100, 50, 0, NO_HISTORY).unwrap(), 40);
+        assert!(calculate_claim_risk_score(&model, 101, 500, 1_000, 100, 50, 0, NO_HISTORY).is_err());
+    }
+}
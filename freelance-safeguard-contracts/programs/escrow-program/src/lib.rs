@@ -1,15 +1,46 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_lang::solana_program::program::{invoke, invoke_signed};
-use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
 use anchor_lang::solana_program::system_instruction;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use freelance_insurance::program::FreelanceInsurance;
+use freelance_shield_core::ArbitratorRegistry;
+use freelance_shield_core::state::arbitrator::PANEL_DRAW_DELAY_SLOTS;
+use freelance_shield_core::utils::panel_selection::{derive_selection_seed, select_panel};
+use reputation_program::program::ReputationProgram;
+use reputation_program::{ReputationState, UserProfile};
 
 declare_id!("HbRjXVmWEkbjCejgGLaPsWpECgwoHhXVH1gV5NUzGVSH");
 
 // Define program IDs for cross-program invocation
-pub const INSURANCE_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("2vFoxWTSRERwtcfwEb6Zgm2iWS3ewU1Y94K224Gw7CJm");
-pub const REPUTATION_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("jq3B5tb6Teg9A1oDqsD2fGnuhb357vceeMrAuYEmz9d");
+pub const CLAIMS_PROCESSOR_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("GRySeLQy7do9nMfJWbH9YmBRS1MHGF79AnmAHKFiJRaN");
+pub const CORE_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("VLemBYrguAkGx1NUpviKW5epn9zJRTLKvfEzmVvpupD");
+pub const STAKING_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("kNKBPewNmis6KmUpSGnpzm7hGXDxWa3p55vwBJF4Yy4");
+
+// Platform fee destinations
+pub const PROTOCOL_DOMAIN: &str = "freelanceshield.xyz";
+
+// Dispute panel parameters
+pub const ESCROW_DISPUTE_SCOPE: u8 = u8::MAX; // Panel scope for a whole-escrow dispute
+pub const DISPUTE_PANEL_SIZE: usize = 3;
+pub const PANEL_COMMIT_PERIOD_DAYS: i64 = 3;
+pub const PANEL_DECISION_PERIOD_DAYS: i64 = 2;
+
+// Milestone amendment limits
+pub const INITIAL_MILESTONE_CAPACITY: usize = 10; // Milestones covered by Escrow::SIZE before reallocation
+pub const MAX_MILESTONES: usize = 20;
+pub const MAX_AMENDMENT_CHANGES: usize = 5;
+pub const MAX_MILESTONE_TITLE_LENGTH: usize = 50;
+pub const MAX_MILESTONE_DESCRIPTION_LENGTH: usize = 100;
+
+// Transaction records held by each escrow history page
+pub const HISTORY_PAGE_CAPACITY: usize = 32;
+pub const MAX_RECORDS_PER_INSTRUCTION: usize = 2; // Most records any single instruction appends
+
+// Platform take-rate ceiling, in basis points
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
 
 #[program]
 pub mod escrow_program {
@@ -32,6 +63,10 @@ pub mod escrow_program {
         escrow_state.successful_escrows = 0;
         escrow_state.disputed_escrows = 0;
         escrow_state.average_completion_time = 0;
+        escrow_state.platform_fee_bps = 0;
+        escrow_state.fee_destination = FeeDestination::DomainTreasury;
+        escrow_state.fee_recipient = FeeDestination::DomainTreasury.recipient();
+        escrow_state.total_fees_collected = 0;
         escrow_state.is_paused = false;
         escrow_state.last_update_timestamp = 0;
         escrow_state.bump = *ctx.bumps.get("escrow_state").unwrap();
@@ -47,24 +82,37 @@ pub mod escrow_program {
         description: String,
         deadline: i64,
     ) -> Result<()> {
-        let escrow_state = &ctx.accounts.escrow_state;
         let escrow = &mut ctx.accounts.escrow;
         let clock = Clock::get()?;
         
         // Validate parameters
-        require!(!escrow_state.is_paused, EscrowError::ProgramPaused);
+        require!(!ctx.accounts.escrow_state.is_paused, EscrowError::ProgramPaused);
         require!(amount > 0, EscrowError::InvalidAmount);
         require!(deadline > clock.unix_timestamp, EscrowError::InvalidDeadline);
         require!(!milestones.is_empty(), EscrowError::NoMilestones);
+        require!(milestones.len() <= INITIAL_MILESTONE_CAPACITY, EscrowError::TooManyMilestones);
         
         // Validate milestone amounts sum to total
         let milestone_sum: u64 = milestones.iter().map(|m| m.amount).sum();
         require!(milestone_sum == amount, EscrowError::MilestoneAmountMismatch);
         
+        // Every milestone starts pending; those without a review window fall back
+        // to the escrow's auto-release date
+        let mut milestones = milestones;
+        for milestone in milestones.iter_mut() {
+            milestone.status = MilestoneStatus::Pending;
+            milestone.completed_at = None;
+            milestone.submitted_at = None;
+            milestone.deliverable_hash = None;
+            milestone.review_deadline = None;
+            milestone.revision_count = 0;
+        }
+        
         // Initialize escrow
         escrow.client = ctx.accounts.client.key();
         escrow.freelancer = ctx.accounts.freelancer.key();
         escrow.amount = amount;
+        escrow.mint = ctx.accounts.mint.key();
         escrow.description = description;
         escrow.created_at = clock.unix_timestamp;
         escrow.deadline = deadline;
@@ -72,14 +120,31 @@ pub mod escrow_program {
         escrow.milestones = milestones;
         escrow.completed_milestones = 0;
         escrow.disputed = false;
-        escrow.auto_release_date = clock.unix_timestamp + (escrow_state.auto_release_days as i64 * 86400);
+        escrow.auto_release_date = clock.unix_timestamp + (ctx.accounts.escrow_state.auto_release_days as i64 * 86400);
         escrow.last_activity_at = clock.unix_timestamp;
-        escrow.transaction_signatures = vec![];
+        escrow.history_pages = 0;
         escrow.last_notification_at = 0;
         escrow.client_confirmed_completion = false;
         escrow.freelancer_confirmed_completion = false;
+        escrow.cancellation = None;
+        escrow.amendment_count = 0;
+        escrow.partner = None;
+        escrow.fee_bps_override = None;
+        escrow.fees_paid = 0;
+        escrow.mode = EscrowMode::Milestones;
+        escrow.stream = None;
+        escrow.reputation_reported = false;
+        escrow.milestone_disputed = false;
         escrow.bump = *ctx.bumps.get("escrow").unwrap();
         
+        // Escrows created through a partner platform carry its negotiated take-rate
+        if let Some(partner_platform) = ctx.accounts.partner_platform.as_mut() {
+            require!(partner_platform.is_active, EscrowError::PartnerInactive);
+            escrow.partner = Some(partner_platform.partner);
+            escrow.fee_bps_override = Some(partner_platform.fee_bps);
+            partner_platform.escrows_created += 1;
+        }
+        
         // Transfer funds from client to escrow account
         let transfer_ctx = CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -95,46 +160,24 @@ pub mod escrow_program {
         // Record transaction
         let transaction_record = TransactionRecord {
             timestamp: clock.unix_timestamp,
-            signature: ctx.accounts.transaction_signature.to_string(),
+            slot: clock.slot,
+            recipient: escrow.key(),
             transaction_type: TransactionType::Deposit,
             amount,
             milestone_index: None,
+            auto: false,
         };
-        escrow.transaction_signatures.push(transaction_record);
+        record_transaction(escrow, None, transaction_record)?;
         
         // Update escrow state
-        let escrow_state_account = ctx.accounts.escrow_state.to_account_info();
-        let mut escrow_data = escrow_state_account.try_borrow_mut_data()?;
-        let mut state = EscrowState::try_deserialize(&mut &escrow_data[..])?;
-        
-        state.total_escrows += 1;
-        state.active_escrows += 1;
-        state.total_volume += amount;
-        state.last_update_timestamp = clock.unix_timestamp;
-        
-        EscrowState::try_serialize(&state, &mut &mut escrow_data[..])?;
-        
-        // Notify insurance program about the new escrow
-        if ctx.accounts.insurance_program.key() == escrow_state.insurance_program_id {
-            let insurance_update_ix = Instruction {
-                program_id: INSURANCE_PROGRAM_ID,
-                accounts: vec![
-                    AccountMeta::new(ctx.accounts.client.key(), false),
-                    AccountMeta::new(ctx.accounts.freelancer.key(), false),
-                    AccountMeta::new_readonly(ctx.accounts.escrow.key(), false),
-                ],
-                data: [1, 0, 0, 0].to_vec(), // Register escrow instruction
-            };
-            
-            invoke(
-                &insurance_update_ix,
-                &[
-                    ctx.accounts.client.to_account_info(),
-                    ctx.accounts.freelancer.to_account_info(),
-                    ctx.accounts.escrow.to_account_info(),
-                ],
-            )?;
-        }
+        let escrow_state = &mut ctx.accounts.escrow_state;
+        escrow_state.total_escrows += 1;
+        escrow_state.active_escrows += 1;
+        escrow_state.total_volume += amount;
+        escrow_state.last_update_timestamp = clock.unix_timestamp;
+        
+        // Register the escrow with the insurance program when its accounts are supplied
+        register_with_insurance(&ctx.accounts, amount, deadline)?;
         
         msg!("Escrow created successfully");
         Ok(())
@@ -165,19 +208,25 @@ pub mod escrow_program {
             EscrowError::InvalidMilestoneIndex
         );
         
-        // Validate milestone is not already completed
+        // Validate milestone has been approved and not yet released
         require!(
-            !escrow.milestones[milestone_index as usize].completed,
+            escrow.milestones[milestone_index as usize].status != MilestoneStatus::Released,
             EscrowError::MilestoneAlreadyCompleted
         );
+        require!(
+            escrow.milestones[milestone_index as usize].status == MilestoneStatus::Approved,
+            EscrowError::MilestoneNotApproved
+        );
         
-        // Mark milestone as completed
-        escrow.milestones[milestone_index as usize].completed = true;
+        // Mark milestone as released
+        escrow.milestones[milestone_index as usize].status = MilestoneStatus::Released;
         escrow.milestones[milestone_index as usize].completed_at = Some(clock.unix_timestamp);
         escrow.completed_milestones += 1;
         
-        // Transfer milestone amount to freelancer
+        // Transfer milestone amount to freelancer, less the platform fee
         let milestone_amount = escrow.milestones[milestone_index as usize].amount;
+        let fee = escrow.platform_fee(ctx.accounts.escrow_state.platform_fee_bps, milestone_amount);
+        let payout = milestone_amount - fee;
         
         let seeds = &[
             b"escrow".as_ref(),
@@ -192,22 +241,48 @@ pub mod escrow_program {
             Transfer {
                 from: ctx.accounts.escrow_token_account.to_account_info(),
                 to: ctx.accounts.freelancer_token_account.to_account_info(),
-                authority: ctx.accounts.escrow.to_account_info(),
+                authority: escrow.to_account_info(),
             },
             signer,
         );
         
-        token::transfer(transfer_ctx, milestone_amount)?;
+        token::transfer(transfer_ctx, payout)?;
+        
+        collect_platform_fee(
+            &ctx.accounts.token_program,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.fee_token_account.as_ref(),
+            &mut ctx.accounts.escrow_state,
+            escrow.to_account_info(),
+            signer,
+            fee,
+        )?;
+        escrow.fees_paid += fee;
         
-        // Update transaction signatures
+        // Record transaction
         let transaction_record = TransactionRecord {
             timestamp: clock.unix_timestamp,
-            signature: ctx.accounts.transaction_signature.to_string(),
+            slot: clock.slot,
+            recipient: escrow.freelancer,
             transaction_type: TransactionType::MilestoneRelease,
-            amount: milestone_amount,
+            amount: payout,
             milestone_index: Some(milestone_index),
+            auto: false,
         };
-        escrow.transaction_signatures.push(transaction_record);
+        record_transaction(escrow, ctx.accounts.history.as_mut(), transaction_record)?;
+        
+        if fee > 0 {
+            let fee_record = TransactionRecord {
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                recipient: ctx.accounts.escrow_state.fee_recipient,
+                transaction_type: TransactionType::PlatformFee,
+                amount: fee,
+                milestone_index: Some(milestone_index),
+                auto: false,
+            };
+            record_transaction(escrow, ctx.accounts.history.as_mut(), fee_record)?;
+        }
         
         // Check if all milestones are completed
         if escrow.completed_milestones as usize == escrow.milestones.len() {
@@ -215,41 +290,263 @@ pub mod escrow_program {
             escrow.completion_date = Some(clock.unix_timestamp);
             
             // Update escrow state
-            let escrow_state_account = ctx.accounts.escrow_state.to_account_info();
-            let mut escrow_data = escrow_state_account.try_borrow_mut_data()?;
-            let mut state = EscrowState::try_deserialize(&mut &escrow_data[..])?;
-            
-            state.active_escrows -= 1;
-            state.successful_escrows += 1;
-            state.last_update_timestamp = clock.unix_timestamp;
-            
-            EscrowState::try_serialize(&state, &mut &mut escrow_data[..])?;
+            let escrow_state = &mut ctx.accounts.escrow_state;
+            escrow_state.active_escrows -= 1;
+            escrow_state.successful_escrows += 1;
+            escrow_state.last_update_timestamp = clock.unix_timestamp;
         }
         
         // Update last activity at
         escrow.last_activity_at = clock.unix_timestamp;
         
-        // Notify reputation program about the milestone completion
-        let reputation_update_ix = Instruction {
-            program_id: REPUTATION_PROGRAM_ID,
-            accounts: vec![
-                AccountMeta::new(ctx.accounts.client.key(), false),
-                AccountMeta::new(ctx.accounts.freelancer.key(), false),
-                AccountMeta::new_readonly(ctx.accounts.escrow.key(), false),
-            ],
-            data: [1, 0, 0, 0].to_vec(), // Update contract progress instruction
+        msg!("Milestone released successfully");
+        Ok(())
+    }
+
+    pub fn submit_milestone(
+        ctx: Context<SubmitMilestone>,
+        milestone_index: u8,
+        deliverable_hash: [u8; 32],
+    ) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate escrow is active
+        require!(
+            escrow.status == EscrowStatus::Active,
+            EscrowError::EscrowNotActive
+        );
+        
+        // Validate milestone index
+        require!(
+            (milestone_index as usize) < escrow.milestones.len(),
+            EscrowError::InvalidMilestoneIndex
+        );
+        
+        // Only pending work or work sent back for revision can be submitted
+        let auto_release_date = escrow.auto_release_date;
+        let milestone = &mut escrow.milestones[milestone_index as usize];
+        require!(
+            milestone.status == MilestoneStatus::Pending ||
+            milestone.status == MilestoneStatus::RevisionRequested,
+            EscrowError::InvalidMilestoneStatus
+        );
+        
+        // Start the client's review window, which runs to the escrow's auto-release
+        // date when the milestone has none of its own
+        milestone.status = MilestoneStatus::Submitted;
+        milestone.deliverable_hash = Some(deliverable_hash);
+        milestone.submitted_at = Some(clock.unix_timestamp);
+        milestone.review_deadline = Some(if milestone.review_window_days == 0 {
+            auto_release_date.max(clock.unix_timestamp)
+        } else {
+            clock.unix_timestamp + (milestone.review_window_days as i64 * 86400)
+        });
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Milestone {} submitted for approval", milestone_index);
+        Ok(())
+    }
+
+    pub fn request_revision(
+        ctx: Context<ReviewMilestone>,
+        milestone_index: u8,
+        feedback: String,
+    ) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate escrow is active
+        require!(
+            escrow.status == EscrowStatus::Active,
+            EscrowError::EscrowNotActive
+        );
+        
+        // Validate milestone index
+        require!(
+            (milestone_index as usize) < escrow.milestones.len(),
+            EscrowError::InvalidMilestoneIndex
+        );
+        
+        // Revisions can only be requested while the review window is open
+        let milestone = &mut escrow.milestones[milestone_index as usize];
+        require!(
+            milestone.status == MilestoneStatus::Submitted,
+            EscrowError::InvalidMilestoneStatus
+        );
+        require!(
+            milestone.review_deadline.map_or(false, |deadline| clock.unix_timestamp < deadline),
+            EscrowError::ReviewWindowClosed
+        );
+        
+        milestone.status = MilestoneStatus::RevisionRequested;
+        milestone.feedback = Some(feedback);
+        milestone.review_deadline = None;
+        milestone.revision_count += 1;
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Revision requested for milestone {}", milestone_index);
+        Ok(())
+    }
+
+    pub fn approve_milestone(
+        ctx: Context<ReviewMilestone>,
+        milestone_index: u8,
+    ) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate escrow is active
+        require!(
+            escrow.status == EscrowStatus::Active,
+            EscrowError::EscrowNotActive
+        );
+        
+        // Validate milestone index
+        require!(
+            (milestone_index as usize) < escrow.milestones.len(),
+            EscrowError::InvalidMilestoneIndex
+        );
+        
+        let milestone = &mut escrow.milestones[milestone_index as usize];
+        require!(
+            milestone.status == MilestoneStatus::Submitted,
+            EscrowError::InvalidMilestoneStatus
+        );
+        
+        milestone.status = MilestoneStatus::Approved;
+        milestone.review_deadline = None;
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Milestone {} approved", milestone_index);
+        Ok(())
+    }
+
+    pub fn auto_release(ctx: Context<AutoRelease>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate escrow is active and undisputed
+        require!(
+            escrow.status == EscrowStatus::Active,
+            EscrowError::EscrowNotActive
+        );
+        require!(!escrow.disputed, EscrowError::AlreadyDisputed);
+        
+        // Release approved milestones and submitted ones whose review window lapsed.
+        // Pending and revision-requested work is never paid out by the crank
+        let release_indices: Vec<usize> = (0..escrow.milestones.len())
+            .filter(|&i| {
+                let m = &escrow.milestones[i];
+                m.status == MilestoneStatus::Approved ||
+                (m.status == MilestoneStatus::Submitted &&
+                    m.review_deadline.map_or(false, |deadline| clock.unix_timestamp >= deadline))
+            })
+            .collect();
+        
+        // Validate something is due, distinguishing work still under review
+        if release_indices.is_empty() {
+            let under_review = escrow.milestones
+                .iter()
+                .any(|m| m.status == MilestoneStatus::Submitted);
+            return if under_review {
+                err!(EscrowError::AutoReleaseNotReached)
+            } else {
+                err!(EscrowError::NoMilestonesToRelease)
+            };
+        }
+        
+        let mut release_amount: u64 = 0;
+        for &index in release_indices.iter() {
+            let milestone = &mut escrow.milestones[index];
+            milestone.status = MilestoneStatus::Released;
+            milestone.review_deadline = None;
+            milestone.completed_at = Some(clock.unix_timestamp);
+            release_amount += milestone.amount;
+        }
+        escrow.completed_milestones += release_indices.len() as u8;
+        
+        let fee = escrow.platform_fee(ctx.accounts.escrow_state.platform_fee_bps, release_amount);
+        let payout = release_amount - fee;
+        let released_index = if release_indices.len() == 1 {
+            Some(release_indices[0] as u8)
+        } else {
+            None
         };
         
-        invoke(
-            &reputation_update_ix,
-            &[
-                ctx.accounts.client.to_account_info(),
-                ctx.accounts.freelancer.to_account_info(),
-                ctx.accounts.escrow.to_account_info(),
-            ],
+        // Transfer released amount to freelancer, less the platform fee
+        let seeds = &[
+            b"escrow".as_ref(),
+            escrow.client.as_ref(),
+            escrow.freelancer.as_ref(),
+            &[escrow.bump],
+        ];
+        let signer = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.freelancer_token_account.to_account_info(),
+                authority: escrow.to_account_info(),
+            },
+            signer,
+        );
+        
+        token::transfer(transfer_ctx, payout)?;
+        
+        collect_platform_fee(
+            &ctx.accounts.token_program,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.fee_token_account.as_ref(),
+            &mut ctx.accounts.escrow_state,
+            escrow.to_account_info(),
+            signer,
+            fee,
         )?;
+        escrow.fees_paid += fee;
         
-        msg!("Milestone released successfully");
+        // Record transaction
+        let transaction_record = TransactionRecord {
+            timestamp: clock.unix_timestamp,
+            slot: clock.slot,
+            recipient: escrow.freelancer,
+            transaction_type: TransactionType::MilestoneRelease,
+            amount: payout,
+            milestone_index: released_index,
+            auto: true,
+        };
+        record_transaction(escrow, ctx.accounts.history.as_mut(), transaction_record)?;
+        
+        if fee > 0 {
+            let fee_record = TransactionRecord {
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                recipient: ctx.accounts.escrow_state.fee_recipient,
+                transaction_type: TransactionType::PlatformFee,
+                amount: fee,
+                milestone_index: released_index,
+                auto: true,
+            };
+            record_transaction(escrow, ctx.accounts.history.as_mut(), fee_record)?;
+        }
+        
+        // Check if all milestones are completed
+        if escrow.completed_milestones as usize == escrow.milestones.len() {
+            escrow.status = EscrowStatus::Completed;
+            escrow.completion_date = Some(clock.unix_timestamp);
+            
+            // Update escrow state
+            let escrow_state = &mut ctx.accounts.escrow_state;
+            escrow_state.active_escrows -= 1;
+            escrow_state.successful_escrows += 1;
+            escrow_state.last_update_timestamp = clock.unix_timestamp;
+        }
+        
+        // Update last activity at
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Auto-released {} to freelancer", payout);
         Ok(())
     }
 
@@ -266,8 +563,12 @@ pub mod escrow_program {
             EscrowError::EscrowNotActive
         );
         
-        // Validate not already disputed
+        // Validate not already disputed, escrow-wide or on any milestone
         require!(!escrow.disputed, EscrowError::AlreadyDisputed);
+        require!(
+            !escrow.milestones.iter().any(|m| m.status == MilestoneStatus::Disputed),
+            EscrowError::AlreadyDisputed
+        );
         
         // Validate caller is either client or freelancer
         require!(
@@ -276,18 +577,33 @@ pub mod escrow_program {
             EscrowError::Unauthorized
         );
         
-        // Pay dispute resolution fee
+        // Pay dispute resolution fee into escrow, held for the panel
         let transfer_ctx = CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
             Transfer {
                 from: ctx.accounts.disputer_token_account.to_account_info(),
-                to: ctx.accounts.fee_account.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
                 authority: ctx.accounts.disputer.to_account_info(),
             },
         );
         
         token::transfer(transfer_ctx, escrow_state.dispute_resolution_fee)?;
         
+        // A disputed stream stops vesting; only the unvested remainder is contested
+        if let Some(stream) = escrow.stream.as_mut() {
+            stream.frozen_at = Some(Clock::get()?.unix_timestamp);
+        }
+        
+        ctx.accounts.dispute_panel.open(
+            escrow.key(),
+            ESCROW_DISPUTE_SCOPE,
+            escrow.unreleased_amount(),
+            escrow_state.dispute_resolution_fee,
+            Clock::get()?.unix_timestamp,
+            Clock::get()?.slot,
+            *ctx.bumps.get("dispute_panel").unwrap(),
+        );
+        
         // Mark escrow as disputed
         escrow.disputed = true;
         escrow.dispute_details = Some(DisputeDetails {
@@ -297,15 +613,18 @@ pub mod escrow_program {
             resolution: None,
         });
         
-        // Update transaction signatures
+        // Record transaction
+        let clock = Clock::get()?;
         let transaction_record = TransactionRecord {
-            timestamp: Clock::get()?.unix_timestamp,
-            signature: ctx.accounts.transaction_signature.to_string(),
+            timestamp: clock.unix_timestamp,
+            slot: clock.slot,
+            recipient: escrow.key(),
             transaction_type: TransactionType::DisputeFee,
             amount: escrow_state.dispute_resolution_fee,
             milestone_index: None,
+            auto: false,
         };
-        escrow.transaction_signatures.push(transaction_record);
+        record_transaction(escrow, ctx.accounts.history.as_mut(), transaction_record)?;
         
         // Update last activity at
         escrow.last_activity_at = Clock::get()?.unix_timestamp;
@@ -321,18 +640,20 @@ pub mod escrow_program {
         resolution_notes: String,
     ) -> Result<()> {
         let escrow = &mut ctx.accounts.escrow;
-        let escrow_state = &ctx.accounts.escrow_state;
         let clock = Clock::get()?;
         
         // Validate authority
         require!(
-            ctx.accounts.authority.key() == escrow_state.authority,
+            ctx.accounts.authority.key() == ctx.accounts.escrow_state.authority,
             EscrowError::Unauthorized
         );
         
         // Validate escrow is disputed
         require!(escrow.disputed, EscrowError::NotDisputed);
         
+        // Authority only steps in when the panel failed to decide
+        require!(ctx.accounts.dispute_panel.escalated, EscrowError::DisputeNotEscalated);
+        
         // Validate percentages add up to 100
         require!(
             client_percentage + freelancer_percentage == 100,
@@ -340,22 +661,19 @@ pub mod escrow_program {
         );
         
         // Calculate remaining amount in escrow
-        let mut remaining_amount = escrow.amount;
-        for milestone in escrow.milestones.iter() {
-            if milestone.completed {
-                remaining_amount = remaining_amount.saturating_sub(milestone.amount);
-            }
-        }
+        let remaining_amount = escrow.unreleased_amount();
         
         if remaining_amount > 0 {
             // Calculate amounts for each party
             let client_amount = (remaining_amount as u128 * client_percentage as u128 / 100) as u64;
             let freelancer_amount = remaining_amount - client_amount;
             
+            let client_key = escrow.client;
+            let freelancer_key = escrow.freelancer;
             let escrow_seeds = &[
                 b"escrow".as_ref(),
-                escrow.client.as_ref(),
-                escrow.freelancer.as_ref(),
+                client_key.as_ref(),
+                freelancer_key.as_ref(),
                 &[escrow.bump],
             ];
             let escrow_signer = &[&escrow_seeds[..]];
@@ -367,22 +685,24 @@ pub mod escrow_program {
                     Transfer {
                         from: ctx.accounts.escrow_token_account.to_account_info(),
                         to: ctx.accounts.client_token_account.to_account_info(),
-                        authority: ctx.accounts.escrow.to_account_info(),
+                        authority: escrow.to_account_info(),
                     },
                     escrow_signer,
                 );
                 
                 token::transfer(transfer_ctx, client_amount)?;
                 
-                // Update transaction signatures
+                // Record transaction
                 let transaction_record = TransactionRecord {
                     timestamp: clock.unix_timestamp,
-                    signature: ctx.accounts.transaction_signature.to_string(),
+                    slot: clock.slot,
+                    recipient: escrow.client,
                     transaction_type: TransactionType::DisputeResolution,
                     amount: client_amount,
                     milestone_index: None,
+                    auto: false,
                 };
-                escrow.transaction_signatures.push(transaction_record);
+                record_transaction(escrow, ctx.accounts.history.as_mut(), transaction_record)?;
             }
             
             // Transfer freelancer portion if any
@@ -392,24 +712,62 @@ pub mod escrow_program {
                     Transfer {
                         from: ctx.accounts.escrow_token_account.to_account_info(),
                         to: ctx.accounts.freelancer_token_account.to_account_info(),
-                        authority: ctx.accounts.escrow.to_account_info(),
+                        authority: escrow.to_account_info(),
                     },
                     escrow_signer,
                 );
                 
                 token::transfer(transfer_ctx, freelancer_amount)?;
                 
-                // Update transaction signatures
+                // Record transaction
                 let transaction_record = TransactionRecord {
                     timestamp: clock.unix_timestamp,
-                    signature: ctx.accounts.transaction_signature.to_string(),
+                    slot: clock.slot,
+                    recipient: escrow.freelancer,
                     transaction_type: TransactionType::DisputeResolution,
                     amount: freelancer_amount,
                     milestone_index: None,
+                    auto: false,
                 };
-                escrow.transaction_signatures.push(transaction_record);
+                record_transaction(escrow, ctx.accounts.history.as_mut(), transaction_record)?;
+            }
+        }
+        
+        // The escalated fee goes to the authority that resolved the dispute
+        let fee_amount = ctx.accounts.dispute_panel.fee_amount;
+        if fee_amount > 0 {
+            let escrow_seeds = &[
+                b"escrow".as_ref(),
+                escrow.client.as_ref(),
+                escrow.freelancer.as_ref(),
+                &[escrow.bump],
+            ];
+            let escrow_signer = &[&escrow_seeds[..]];
+            
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_token_account.to_account_info(),
+                    to: ctx.accounts.fee_account.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                escrow_signer,
+            );
+            
+            token::transfer(transfer_ctx, fee_amount)?;
+        }
+        ctx.accounts.dispute_panel.settled = true;
+        
+        // Everything left was paid out by the split
+        for milestone in escrow.milestones.iter_mut() {
+            if milestone.status != MilestoneStatus::Released {
+                milestone.status = MilestoneStatus::Released;
+                milestone.completed_at = Some(clock.unix_timestamp);
             }
         }
+        if let Some(stream) = escrow.stream.as_mut() {
+            stream.unvested_settled = true;
+        }
         
         // Update escrow
         escrow.status = EscrowStatus::Resolved;
@@ -420,18 +778,13 @@ pub mod escrow_program {
             resolver: ctx.accounts.authority.key(),
             notes: resolution_notes,
         });
-        escrow.resolved_at = Some(clock.unix_timestamp);
+        escrow.completion_date = Some(clock.unix_timestamp);
         
         // Update escrow state
-        let escrow_state_account = ctx.accounts.escrow_state.to_account_info();
-        let mut escrow_data = escrow_state_account.try_borrow_mut_data()?;
-        let mut state = EscrowState::try_deserialize(&mut &escrow_data[..])?;
-        
-        state.active_escrows -= 1;
-        state.disputed_escrows += 1;
-        state.last_update_timestamp = clock.unix_timestamp;
-        
-        EscrowState::try_serialize(&state, &mut &mut escrow_data[..])?;
+        let escrow_state = &mut ctx.accounts.escrow_state;
+        escrow_state.active_escrows -= 1;
+        escrow_state.disputed_escrows += 1;
+        escrow_state.last_update_timestamp = clock.unix_timestamp;
         
         // Update last activity at
         escrow.last_activity_at = clock.unix_timestamp;
@@ -440,631 +793,2637 @@ pub mod escrow_program {
         Ok(())
     }
 
-    pub fn update_escrow_parameters(
-        ctx: Context<UpdateEscrowParameters>,
-        dispute_resolution_fee: Option<u64>,
-        auto_release_days: Option<u16>,
-        is_paused: Option<bool>,
-    ) -> Result<()> {
-        let escrow_state = &mut ctx.accounts.escrow_state;
-        
-        // Validate authority
-        require!(
-            ctx.accounts.authority.key() == escrow_state.authority,
-            EscrowError::Unauthorized
-        );
-        
-        // Update parameters if provided
-        if let Some(fee) = dispute_resolution_fee {
-            escrow_state.dispute_resolution_fee = fee;
-        }
-        
-        if let Some(days) = auto_release_days {
-            escrow_state.auto_release_days = days;
-        }
-        
-        if let Some(paused) = is_paused {
-            escrow_state.is_paused = paused;
-        }
-        
-        escrow_state.last_update_timestamp = Clock::get()?.unix_timestamp;
-        
-        msg!("Escrow parameters updated");
-        Ok(())
-    }
-
-    pub fn complete_escrow(
-        ctx: Context<CompleteEscrow>,
-        _feedback: String,
-        rating: u8,
+    pub fn dispute_milestone(
+        ctx: Context<DisputeMilestone>,
+        milestone_index: u8,
+        reason: String,
     ) -> Result<()> {
         let escrow = &mut ctx.accounts.escrow;
+        let escrow_state = &ctx.accounts.escrow_state;
         let clock = Clock::get()?;
         
-        // Validate escrow is active
+        // Validate escrow is active and not disputed as a whole
         require!(
             escrow.status == EscrowStatus::Active,
             EscrowError::EscrowNotActive
         );
+        require!(!escrow.disputed, EscrowError::AlreadyDisputed);
         
-        // Validate all milestones are completed
-        require!(
-            escrow.completed_milestones as usize == escrow.milestones.len(),
-            EscrowError::NotAllMilestonesCompleted
-        );
-        
-        // Validate caller is client
+        // Validate caller is either client or freelancer
         require!(
-            ctx.accounts.client.key() == escrow.client,
+            ctx.accounts.disputer.key() == escrow.client || 
+            ctx.accounts.disputer.key() == escrow.freelancer,
             EscrowError::Unauthorized
         );
         
-        // Mark escrow as completed
-        escrow.status = EscrowStatus::Completed;
-        escrow.completion_date = Some(clock.unix_timestamp);
-        escrow.client_confirmed_completion = true;
+        // Validate milestone index
+        require!(
+            (milestone_index as usize) < escrow.milestones.len(),
+            EscrowError::InvalidMilestoneIndex
+        );
         
-        // Calculate completion metrics
-        let completion_time = clock.unix_timestamp - escrow.created_at;
+        let milestone = &mut escrow.milestones[milestone_index as usize];
+        require!(
+            milestone.status != MilestoneStatus::Released,
+            EscrowError::MilestoneAlreadyCompleted
+        );
+        require!(
+            milestone.status != MilestoneStatus::Disputed,
+            EscrowError::AlreadyDisputed
+        );
         
-        // Update escrow state
-        let escrow_state_account = ctx.accounts.escrow_state.to_account_info();
-        let mut escrow_data = escrow_state_account.try_borrow_mut_data()?;
-        let mut state = EscrowState::try_deserialize(&mut &escrow_data[..])?;
+        // Pay dispute resolution fee into escrow, held for the panel
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.disputer_token_account.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.disputer.to_account_info(),
+            },
+        );
         
-        state.active_escrows -= 1;
-        state.successful_escrows += 1;
+        token::transfer(transfer_ctx, escrow_state.dispute_resolution_fee)?;
         
-        // Update average completion time
-        if state.successful_escrows == 1 {
-            state.average_completion_time = completion_time;
-        } else {
-            let total_time = state.average_completion_time * (state.successful_escrows - 1) as i64;
-            state.average_completion_time = (total_time + completion_time) / state.successful_escrows as i64;
-        }
+        // Freeze only this milestone; the rest keep flowing
+        milestone.status = MilestoneStatus::Disputed;
+        milestone.review_deadline = None;
+        let milestone_amount = milestone.amount;
+        escrow.milestone_disputed = true;
         
-        state.last_update_timestamp = clock.unix_timestamp;
-        EscrowState::try_serialize(&state, &mut &mut escrow_data[..])?;
+        ctx.accounts.dispute_panel.open(
+            escrow.key(),
+            milestone_index,
+            milestone_amount,
+            escrow_state.dispute_resolution_fee,
+            clock.unix_timestamp,
+            clock.slot,
+            *ctx.bumps.get("dispute_panel").unwrap(),
+        );
         
-        // Update last activity timestamp
-        escrow.last_activity_at = clock.unix_timestamp;
+        let milestone_dispute = &mut ctx.accounts.milestone_dispute;
+        milestone_dispute.escrow = escrow.key();
+        milestone_dispute.milestone_index = milestone_index;
+        milestone_dispute.initiated_by = ctx.accounts.disputer.key();
+        milestone_dispute.initiated_at = clock.unix_timestamp;
+        milestone_dispute.reason = reason;
+        milestone_dispute.resolution = None;
+        milestone_dispute.bump = *ctx.bumps.get("milestone_dispute").unwrap();
         
-        // Notify reputation program about contract completion
-        let reputation_update_ix = Instruction {
-            program_id: REPUTATION_PROGRAM_ID,
-            accounts: vec![
-                AccountMeta::new(ctx.accounts.client.key(), false),
-                AccountMeta::new(ctx.accounts.freelancer.key(), false),
-                AccountMeta::new_readonly(ctx.accounts.escrow.key(), false),
-            ],
-            data: [2, 0, 0, 0, rating].to_vec(), // Complete contract instruction with rating
+        // Record transaction
+        let transaction_record = TransactionRecord {
+            timestamp: clock.unix_timestamp,
+            slot: clock.slot,
+            recipient: escrow.key(),
+            transaction_type: TransactionType::DisputeFee,
+            amount: escrow_state.dispute_resolution_fee,
+            milestone_index: Some(milestone_index),
+            auto: false,
         };
+        record_transaction(escrow, ctx.accounts.history.as_mut(), transaction_record)?;
         
-        invoke(
-            &reputation_update_ix,
-            &[
-                ctx.accounts.client.to_account_info(),
-                ctx.accounts.freelancer.to_account_info(),
-                ctx.accounts.escrow.to_account_info(),
-            ],
-        )?;
+        // Update last activity at
+        escrow.last_activity_at = clock.unix_timestamp;
         
-        msg!("Escrow completed successfully with rating {}", rating);
+        msg!("Milestone {} disputed", milestone_index);
         Ok(())
     }
 
-    pub fn confirm_completion(
-        ctx: Context<ConfirmCompletion>,
-        _feedback: String,
-        rating: u8,
+    pub fn resolve_milestone_dispute(
+        ctx: Context<ResolveMilestoneDispute>,
+        milestone_index: u8,
+        freelancer_amount: u64,
+        resolution_notes: String,
     ) -> Result<()> {
         let escrow = &mut ctx.accounts.escrow;
         let clock = Clock::get()?;
         
-        // Validate caller is freelancer
+        // An escrow-wide resolution settles every milestone instead
         require!(
-            ctx.accounts.freelancer.key() == escrow.freelancer,
-            EscrowError::Unauthorized
+            escrow.status == EscrowStatus::Active,
+            EscrowError::EscrowNotActive
         );
+        require!(!escrow.disputed, EscrowError::AlreadyDisputed);
         
-        // Validate escrow status is appropriate
         require!(
-            escrow.status == EscrowStatus::Active || 
-            escrow.status == EscrowStatus::Completed,
-            EscrowError::InvalidEscrowStatus
+            escrow.milestones[milestone_index as usize].status == MilestoneStatus::Disputed,
+            EscrowError::NotDisputed
         );
         
-        // Mark freelancer confirmation
-        escrow.freelancer_confirmed_completion = true;
+        // Authority only steps in when the panel failed to decide
+        require!(ctx.accounts.dispute_panel.escalated, EscrowError::DisputeNotEscalated);
         
-        // Update last activity timestamp
-        escrow.last_activity_at = clock.unix_timestamp;
+        // Resolver awards an amount to the freelancer; the client gets the rest back
+        let milestone_amount = escrow.milestones[milestone_index as usize].amount;
+        require!(
+            freelancer_amount <= milestone_amount,
+            EscrowError::InvalidAwardAmount
+        );
+        let client_amount = milestone_amount - freelancer_amount;
         
-        // If both parties have confirmed, update status if not already completed
-        if escrow.client_confirmed_completion && escrow.freelancer_confirmed_completion && escrow.status != EscrowStatus::Completed {
-            escrow.status = EscrowStatus::Completed;
-            escrow.completion_date = Some(clock.unix_timestamp);
+        let client_key = escrow.client;
+        let freelancer_key = escrow.freelancer;
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            client_key.as_ref(),
+            freelancer_key.as_ref(),
+            &[escrow.bump],
+        ];
+        let escrow_signer = &[&escrow_seeds[..]];
+        
+        // Transfer client portion if any
+        if client_amount > 0 {
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_token_account.to_account_info(),
+                    to: ctx.accounts.client_token_account.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                escrow_signer,
+            );
             
-            // Update escrow state
-            let escrow_state_account = ctx.accounts.escrow_state.to_account_info();
-            let mut escrow_data = escrow_state_account.try_borrow_mut_data()?;
-            let mut state = EscrowState::try_deserialize(&mut &escrow_data[..])?;
+            token::transfer(transfer_ctx, client_amount)?;
+            
+            // Record transaction
+            let transaction_record = TransactionRecord {
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                recipient: escrow.client,
+                transaction_type: TransactionType::DisputeResolution,
+                amount: client_amount,
+                milestone_index: Some(milestone_index),
+                auto: false,
+            };
+            record_transaction(escrow, ctx.accounts.history.as_mut(), transaction_record)?;
+        }
+        
+        // Transfer freelancer portion if any
+        if freelancer_amount > 0 {
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_token_account.to_account_info(),
+                    to: ctx.accounts.freelancer_token_account.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                escrow_signer,
+            );
             
-            state.active_escrows -= 1;
-            state.successful_escrows += 1;
-            state.last_update_timestamp = clock.unix_timestamp;
+            token::transfer(transfer_ctx, freelancer_amount)?;
             
-            EscrowState::try_serialize(&state, &mut &mut escrow_data[..])?;
+            // Record transaction
+            let transaction_record = TransactionRecord {
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                recipient: escrow.freelancer,
+                transaction_type: TransactionType::DisputeResolution,
+                amount: freelancer_amount,
+                milestone_index: Some(milestone_index),
+                auto: false,
+            };
+            record_transaction(escrow, ctx.accounts.history.as_mut(), transaction_record)?;
         }
         
-        // Notify reputation program about the feedback
-        let reputation_update_ix = Instruction {
-            program_id: REPUTATION_PROGRAM_ID,
-            accounts: vec![
-                AccountMeta::new(ctx.accounts.client.key(), false),
-                AccountMeta::new(ctx.accounts.freelancer.key(), true),
-                AccountMeta::new_readonly(ctx.accounts.escrow.key(), false),
-            ],
-            data: [3, 0, 0, 0, rating].to_vec(), // Submit feedback instruction with rating
-        };
+        // The escalated fee goes to the authority that resolved the dispute
+        let fee_amount = ctx.accounts.dispute_panel.fee_amount;
+        if fee_amount > 0 {
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_token_account.to_account_info(),
+                    to: ctx.accounts.fee_account.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                escrow_signer,
+            );
+            
+            token::transfer(transfer_ctx, fee_amount)?;
+        }
+        ctx.accounts.dispute_panel.settled = true;
         
-        invoke(
-            &reputation_update_ix,
-            &[
-                ctx.accounts.client.to_account_info(),
-                ctx.accounts.freelancer.to_account_info(),
-                ctx.accounts.escrow.to_account_info(),
-            ],
-        )?;
+        // Milestone funds are fully settled
+        let milestone = &mut escrow.milestones[milestone_index as usize];
+        milestone.status = MilestoneStatus::Released;
+        milestone.completed_at = Some(clock.unix_timestamp);
+        escrow.completed_milestones += 1;
         
-        msg!("Completion confirmation recorded with rating {}", rating);
+        ctx.accounts.milestone_dispute.resolution = Some(MilestoneDisputeResolution {
+            resolved_at: clock.unix_timestamp,
+            client_amount,
+            freelancer_amount,
+            resolver: ctx.accounts.authority.key(),
+            notes: resolution_notes,
+        });
+        
+        // Check if all milestones are completed
+        if escrow.completed_milestones as usize == escrow.milestones.len() {
+            escrow.status = EscrowStatus::Completed;
+            escrow.completion_date = Some(clock.unix_timestamp);
+            
+            // Update escrow state
+            let escrow_state = &mut ctx.accounts.escrow_state;
+            escrow_state.active_escrows -= 1;
+            escrow_state.successful_escrows += 1;
+            escrow_state.last_update_timestamp = clock.unix_timestamp;
+        }
+        
+        // Update last activity at
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Milestone {} dispute resolved: {} to freelancer, {} to client", milestone_index, freelancer_amount, client_amount);
         Ok(())
     }
 
-    pub fn get_escrow_analytics(
-        ctx: Context<GetEscrowAnalytics>,
-    ) -> Result<EscrowAnalytics> {
-        let escrow_state = &ctx.accounts.escrow_state;
+    pub fn draw_dispute_panel(
+        ctx: Context<DrawDisputePanel>,
+        _scope: u8,
+    ) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let panel = &mut ctx.accounts.dispute_panel;
+        let clock = Clock::get()?;
         
-        // Calculate success rate
-        let success_rate = if escrow_state.total_escrows > 0 {
-            (escrow_state.successful_escrows as f64 / escrow_state.total_escrows as f64) * 100.0
-        } else {
-            0.0
-        };
+        require!(panel.members.is_empty(), EscrowError::PanelAlreadyDrawn);
+        require!(
+            !panel.settled && !panel.escalated && clock.unix_timestamp < panel.commit_deadline,
+            EscrowError::CommitPeriodEnded
+        );
+        require!(clock.slot > panel.draw_slot, EscrowError::PanelDrawSlotNotReached);
         
-        // Calculate dispute rate
-        let dispute_rate = if escrow_state.total_escrows > 0 {
-            (escrow_state.disputed_escrows as f64 / escrow_state.total_escrows as f64) * 100.0
-        } else {
-            0.0
+        // The draw slot was fixed when the dispute opened, so neither party can pick
+        // its hash; a slot that has aged out of SlotHashes is replaced by a fresh one
+        let panel_key = panel.key();
+        let (seed, _) = match derive_selection_seed(&ctx.accounts.slot_hashes, panel.draw_slot, &panel_key)? {
+            Some(selection) => selection,
+            None => {
+                panel.draw_slot = clock.slot + PANEL_DRAW_DELAY_SLOTS;
+                msg!("Draw slot expired, recommitted to slot {}", panel.draw_slot);
+                return Ok(());
+            }
         };
         
-        // Format average completion time into days, hours, minutes
-        let avg_completion_days = escrow_state.average_completion_time / 86400;
-        let avg_completion_hours = (escrow_state.average_completion_time % 86400) / 3600;
-        let avg_completion_minutes = (escrow_state.average_completion_time % 3600) / 60;
+        // Candidates are core's active arbitrators who aren't party to the escrow
+        let candidates: Vec<Pubkey> = ctx.accounts.arbitrator_registry.active_arbitrators
+            .iter()
+            .filter(|arbitrator| **arbitrator != escrow.client && **arbitrator != escrow.freelancer)
+            .cloned()
+            .collect();
+        require!(
+            candidates.len() >= DISPUTE_PANEL_SIZE,
+            EscrowError::InsufficientArbitrators
+        );
         
-        let analytics = EscrowAnalytics {
-            total_escrows: escrow_state.total_escrows,
-            active_escrows: escrow_state.active_escrows,
-            total_volume: escrow_state.total_volume,
-            successful_escrows: escrow_state.successful_escrows,
-            disputed_escrows: escrow_state.disputed_escrows,
-            success_rate: (success_rate * 100.0) as u32, // Store as basis points
-            dispute_rate: (dispute_rate * 100.0) as u32, // Store as basis points
-            average_completion_time: escrow_state.average_completion_time,
-            avg_completion_days,
-            avg_completion_hours,
-            avg_completion_minutes,
-            last_update_timestamp: escrow_state.last_update_timestamp,
-        };
+        panel.members = select_panel(&candidates, DISPUTE_PANEL_SIZE, &seed)?;
+        panel.selection_seed = seed;
+        panel.drawn_at = clock.unix_timestamp;
         
-        msg!("Escrow analytics retrieved");
-        Ok(analytics)
+        msg!("Dispute panel drawn: {} members", panel.members.len());
+        Ok(())
     }
 
-    pub fn get_user_escrow_history(
-        ctx: Context<GetUserEscrowHistory>,
-        user_pubkey: Pubkey,
-        role: UserRole,
-        limit: u8,
-    ) -> Result<Vec<EscrowSummary>> {
+    pub fn commit_panel_decision(
+        ctx: Context<SubmitPanelDecision>,
+        _scope: u8,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        let panel = &mut ctx.accounts.dispute_panel;
+        let member = ctx.accounts.member.key();
         let clock = Clock::get()?;
         
-        // Since we can't filter accounts directly in the program,
-        // we'll search through accounts provided by the client
-        let escrow_accounts = &ctx.remaining_accounts;
-        let mut escrow_summaries = Vec::new();
-        let mut count = 0;
+        require!(panel.members.contains(&member), EscrowError::NotPanelMember);
+        require!(
+            clock.unix_timestamp < panel.commit_deadline,
+            EscrowError::CommitPeriodEnded
+        );
+        require!(
+            !panel.commitments.iter().any(|c| c.member == member),
+            EscrowError::AlreadyCommitted
+        );
         
-        // Process each account provided
-        for account_info in escrow_accounts.iter() {
-            if count >= limit as usize {
-                break;
-            }
-            
-            // Deserialize the escrow account
-            if let Ok(escrow) = Account::<Escrow>::try_from(account_info) {
-                // Check if the account belongs to the requested user based on role
-                let belongs_to_user = match role {
-                    UserRole::Client => escrow.client == user_pubkey,
-                    UserRole::Freelancer => escrow.freelancer == user_pubkey,
-                    UserRole::Both => escrow.client == user_pubkey || escrow.freelancer == user_pubkey,
-                };
-                
-                if belongs_to_user {
-                    // Create and add summary if it belongs to the user
-                    let summary = EscrowSummary {
-                        escrow_pubkey: account_info.key(),
-                        client: escrow.client,
-                        freelancer: escrow.freelancer,
-                        amount: escrow.amount,
-                        status: escrow.status,
-                        created_at: escrow.created_at,
-                        deadline: escrow.deadline,
-                        milestones_count: escrow.milestones.len() as u8,
-                        completed_milestones: escrow.completed_milestones,
-                        active_time_remaining: if escrow.status == EscrowStatus::Active {
-                            escrow.deadline.saturating_sub(clock.unix_timestamp)
-                        } else {
-                            0
-                        },
-                        disputed: escrow.disputed,
-                        completion_date: escrow.completion_date,
-                        last_activity_at: escrow.last_activity_at,
-                    };
-                    
-                    escrow_summaries.push(summary);
-                    count += 1;
-                }
-            }
-        }
+        panel.commitments.push(PanelCommitment {
+            member,
+            commitment,
+        });
         
-        // Sort by most recent activity first
-        escrow_summaries.sort_by(|a, b| b.last_activity_at.cmp(&a.last_activity_at));
+        msg!("Panel decision committed");
+        Ok(())
+    }
+
+    pub fn decide_panel_dispute(
+        ctx: Context<SubmitPanelDecision>,
+        _scope: u8,
+        freelancer_amount: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let panel = &mut ctx.accounts.dispute_panel;
+        let member = ctx.accounts.member.key();
+        let clock = Clock::get()?;
         
-        msg!("Found {} escrow records for user based on role {:?}", escrow_summaries.len(), role);
-        Ok(escrow_summaries)
+        // Decisions open once every member has committed or the commit period ends
+        require!(
+            panel.commitments.len() == panel.members.len() ||
+            clock.unix_timestamp >= panel.commit_deadline,
+            EscrowError::DecisionPeriodNotOpen
+        );
+        require!(
+            clock.unix_timestamp < panel.decision_deadline,
+            EscrowError::DecisionPeriodNotOpen
+        );
+        require!(
+            !panel.decisions.iter().any(|d| d.member == member),
+            EscrowError::AlreadyDecided
+        );
+        
+        // Decision must match what the member committed to
+        let committed = panel.commitments
+            .iter()
+            .find(|c| c.member == member)
+            .ok_or(EscrowError::NotPanelMember)?;
+        let expected = hashv(&[
+            &freelancer_amount.to_le_bytes(),
+            &salt,
+            member.as_ref(),
+        ]).to_bytes();
+        require!(committed.commitment == expected, EscrowError::CommitmentMismatch);
+        require!(
+            freelancer_amount <= panel.disputed_amount,
+            EscrowError::InvalidAwardAmount
+        );
+        
+        panel.decisions.push(PanelDecisionRecord {
+            member,
+            freelancer_amount,
+            decided_at: clock.unix_timestamp,
+        });
+        
+        msg!("Panel decision recorded: {} of {} to freelancer", freelancer_amount, panel.disputed_amount);
+        Ok(())
     }
 
-    pub fn calculate_escrow_risk(
-        ctx: Context<CalculateEscrowRisk>,
-        escrow_pubkey: Pubkey,
-    ) -> Result<EscrowRiskAnalysis> {
+    pub fn settle_dispute_panel<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleDisputePanel<'info>>,
+        scope: u8,
+    ) -> Result<()> {
         let clock = Clock::get()?;
+        let panel = &ctx.accounts.dispute_panel;
         
-        // Deserialize the escrow account
-        let escrow_account = ctx.accounts.escrow.to_account_info();
-        let escrow = Account::<Escrow>::try_from(&escrow_account)?;
+        let all_decided = !panel.members.is_empty() && panel.decisions.len() == panel.members.len();
+        require!(
+            all_decided || clock.unix_timestamp >= panel.decision_deadline,
+            EscrowError::PanelStillDeciding
+        );
         
-        // 1. Amount Risk - Higher amounts increase risk
-        let amount_risk = if escrow.amount > 1_000_000_000 { // 1000 SOL
-            RiskLevel::High
-        } else if escrow.amount > 100_000_000 { // 100 SOL
-            RiskLevel::Medium
+        // The disputed funds must still be held for this dispute
+        let escrow = &ctx.accounts.escrow;
+        if scope == ESCROW_DISPUTE_SCOPE {
+            require!(
+                escrow.disputed && escrow.status == EscrowStatus::Active,
+                EscrowError::NotDisputed
+            );
         } else {
-            RiskLevel::Low
-        };
+            require!(
+                escrow.milestones[scope as usize].status == MilestoneStatus::Disputed,
+                EscrowError::NotDisputed
+            );
+        }
         
-        // 2. Time Risk - Longer timeframes increase risk
-        let duration = escrow.deadline - escrow.created_at;
-        let time_risk = if duration > 60 * 86400 { // 60 days
-            RiskLevel::High
-        } else if duration > 30 * 86400 { // 30 days
-            RiskLevel::Medium
+        // No-show: nobody decided, so the dispute escalates to the program authority
+        if panel.decisions.is_empty() {
+            ctx.accounts.dispute_panel.escalated = true;
+            msg!("Dispute panel did not decide; escalated to authority");
+            return Ok(());
+        }
+        
+        // The panel award is the median decision
+        let mut awards: Vec<u64> = panel.decisions.iter().map(|d| d.freelancer_amount).collect();
+        awards.sort_unstable();
+        let disputed_amount = if scope == ESCROW_DISPUTE_SCOPE {
+            ctx.accounts.escrow.unreleased_amount()
         } else {
-            RiskLevel::Low
+            ctx.accounts.escrow.milestones[scope as usize].amount
         };
+        let freelancer_amount = awards[awards.len() / 2].min(disputed_amount);
+        let client_amount = disputed_amount - freelancer_amount;
         
-        // 3. Milestone Risk - Fewer milestones increase risk
-        let milestone_risk = if escrow.milestones.len() <= 2 {
-            RiskLevel::High
-        } else if escrow.milestones.len() <= 5 {
-            RiskLevel::Medium
-        } else {
-            RiskLevel::Low
-        };
+        let escrow = &ctx.accounts.escrow;
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            escrow.client.as_ref(),
+            escrow.freelancer.as_ref(),
+            &[escrow.bump],
+        ];
+        let escrow_signer = &[&escrow_seeds[..]];
         
-        // 4. Progress Risk - Time elapsed vs milestone completion
-        let time_elapsed = clock.unix_timestamp - escrow.created_at;
-        let time_percentage = if duration > 0 {
-            (time_elapsed as f64 / duration as f64) * 100.0
-        } else {
-            100.0
-        };
+        if client_amount > 0 {
+            transfer_from_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow_token_account,
+                ctx.accounts.client_token_account.to_account_info(),
+                escrow.to_account_info(),
+                escrow_signer,
+                client_amount,
+            )?;
+        }
         
-        let milestone_percentage = if escrow.milestones.len() > 0 {
-            (escrow.completed_milestones as f64 / escrow.milestones.len() as f64) * 100.0
-        } else {
-            0.0
-        };
+        if freelancer_amount > 0 {
+            transfer_from_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow_token_account,
+                ctx.accounts.freelancer_token_account.to_account_info(),
+                escrow.to_account_info(),
+                escrow_signer,
+                freelancer_amount,
+            )?;
+        }
         
-        let progress_risk = if time_percentage > milestone_percentage + 30.0 {
-            RiskLevel::High
-        } else if time_percentage > milestone_percentage + 15.0 {
-            RiskLevel::Medium
-        } else {
-            RiskLevel::Low
-        };
+        // Fee is split between the members who decided; dust goes to the first
+        require!(
+            ctx.remaining_accounts.len() == panel.decisions.len(),
+            EscrowError::NotPanelMember
+        );
+        let share = panel.fee_amount / panel.decisions.len() as u64;
+        let dust = panel.fee_amount - share * panel.decisions.len() as u64;
+        for (i, (decision, account_info)) in panel.decisions
+            .iter()
+            .zip(ctx.remaining_accounts.iter())
+            .enumerate()
+        {
+            let member_token_account = Account::<TokenAccount>::try_from(account_info)?;
+            require!(
+                member_token_account.owner == decision.member,
+                EscrowError::NotPanelMember
+            );
+            require!(
+                member_token_account.mint == escrow.mint,
+                EscrowError::InvalidMint
+            );
+            
+            let amount = if i == 0 { share + dust } else { share };
+            if amount > 0 {
+                transfer_from_escrow(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.escrow_token_account,
+                    account_info.clone(),
+                    escrow.to_account_info(),
+                    escrow_signer,
+                    amount,
+                )?;
+            }
+        }
         
-        // 5. Dispute Risk - Based on client and freelancer history from reputation program
-        // Note: In a real implementation, this would fetch data from the reputation program
-        let dispute_risk = if !ctx.accounts.reputation_stats.to_account_info().data_is_empty() {
-            // Here we would make a cross-program call to get reputation data
-            // For now we'll use a placeholder value based on dispute flag
-            if escrow.disputed {
-                RiskLevel::High
+        let panel_key = ctx.accounts.dispute_panel.key();
+        let escrow = &mut ctx.accounts.escrow;
+        
+        for (amount, recipient) in [
+            (client_amount, escrow.client),
+            (freelancer_amount, escrow.freelancer),
+        ] {
+            if amount > 0 {
+                record_transaction(escrow, ctx.accounts.history.as_mut(), TransactionRecord {
+                    timestamp: clock.unix_timestamp,
+                    slot: clock.slot,
+                    recipient,
+                    transaction_type: TransactionType::DisputeResolution,
+                    amount,
+                    milestone_index: if scope == ESCROW_DISPUTE_SCOPE { None } else { Some(scope) },
+                    auto: false,
+                })?;
+            }
+        }
+        
+        if scope == ESCROW_DISPUTE_SCOPE {
+            // Everything left was paid out by the award
+            for milestone in escrow.milestones.iter_mut() {
+                if milestone.status != MilestoneStatus::Released {
+                    milestone.status = MilestoneStatus::Released;
+                    milestone.completed_at = Some(clock.unix_timestamp);
+                }
+            }
+            if let Some(stream) = escrow.stream.as_mut() {
+                stream.unvested_settled = true;
+            }
+            
+            let freelancer_percentage = if disputed_amount > 0 {
+                (freelancer_amount as u128 * 100 / disputed_amount as u128) as u8
             } else {
-                RiskLevel::Low
+                0
+            };
+            escrow.status = EscrowStatus::Resolved;
+            escrow.completion_date = Some(clock.unix_timestamp);
+            if let Some(details) = escrow.dispute_details.as_mut() {
+                details.resolution = Some(DisputeResolution {
+                    resolved_at: clock.unix_timestamp,
+                    client_percentage: 100 - freelancer_percentage,
+                    freelancer_percentage,
+                    resolver: panel_key,
+                    notes: "Arbitrator panel award".to_string(),
+                });
             }
+            
+            // Update escrow state
+            let escrow_state = &mut ctx.accounts.escrow_state;
+            escrow_state.active_escrows -= 1;
+            escrow_state.disputed_escrows += 1;
+            escrow_state.last_update_timestamp = clock.unix_timestamp;
         } else {
-            RiskLevel::Medium // Default if no reputation data available
-        };
+            let milestone_dispute = ctx.accounts.milestone_dispute
+                .as_mut()
+                .ok_or(EscrowError::NotDisputed)?;
+            require!(
+                milestone_dispute.escrow == escrow.key() && milestone_dispute.milestone_index == scope,
+                EscrowError::NotDisputed
+            );
+            milestone_dispute.resolution = Some(MilestoneDisputeResolution {
+                resolved_at: clock.unix_timestamp,
+                client_amount,
+                freelancer_amount,
+                resolver: panel_key,
+                notes: "Arbitrator panel award".to_string(),
+            });
+            
+            // Milestone funds are fully settled
+            let milestone = &mut escrow.milestones[scope as usize];
+            milestone.status = MilestoneStatus::Released;
+            milestone.completed_at = Some(clock.unix_timestamp);
+            escrow.completed_milestones += 1;
+            
+            // Check if all milestones are completed
+            if escrow.completed_milestones as usize == escrow.milestones.len() {
+                escrow.status = EscrowStatus::Completed;
+                escrow.completion_date = Some(clock.unix_timestamp);
+                
+                // Update escrow state
+                let escrow_state = &mut ctx.accounts.escrow_state;
+                escrow_state.active_escrows -= 1;
+                escrow_state.successful_escrows += 1;
+                escrow_state.last_update_timestamp = clock.unix_timestamp;
+            }
+        }
         
-        // Calculate composite risk score (0-100)
-        // Weight factors based on the insurance system components from memory
-        let amount_weight = 20;
-        let time_weight = 15;
-        let milestone_weight = 15;
-        let progress_weight = 30;
-        let dispute_weight = 20;
+        escrow.last_activity_at = clock.unix_timestamp;
         
-        let risk_score = 
-            (risk_level_to_score(amount_risk) * amount_weight +
-             risk_level_to_score(time_risk) * time_weight +
-             risk_level_to_score(milestone_risk) * milestone_weight +
-             risk_level_to_score(progress_risk) * progress_weight +
-             risk_level_to_score(dispute_risk) * dispute_weight) / 
-            (amount_weight + time_weight + milestone_weight + progress_weight + dispute_weight);
+        let panel = &mut ctx.accounts.dispute_panel;
+        panel.freelancer_award = Some(freelancer_amount);
+        panel.settled = true;
         
-        // Create and return risk analysis result
-        let risk_analysis = EscrowRiskAnalysis {
-            escrow_pubkey,
-            risk_score,
-            amount_risk,
-            time_risk,
-            milestone_risk,
-            progress_risk,
-            dispute_risk,
-            analysis_timestamp: clock.unix_timestamp,
-            time_percentage: (time_percentage * 100.0) as u32, // Store as basis points
-            milestone_percentage: (milestone_percentage * 100.0) as u32, // Store as basis points
-            time_elapsed,
-            duration,
-            amount: escrow.amount,
-        };
+        msg!("Dispute panel settled: {} to freelancer, {} to client", freelancer_amount, client_amount);
+        Ok(())
+    }
+
+    pub fn propose_cancellation(
+        ctx: Context<ProposeCancellation>,
+        kill_fee: u64,
+    ) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
         
-        // Log analysis for transparency and blockchain record
-        msg!("Escrow risk analysis completed: Score {}", risk_score);
+        // Validate escrow is active and undisputed
+        require!(
+            escrow.status == EscrowStatus::Active,
+            EscrowError::EscrowNotActive
+        );
+        require!(!escrow.disputed, EscrowError::AlreadyDisputed);
         
-        Ok(risk_analysis)
+        // Streams are wound down with terminate_stream instead
+        require!(
+            escrow.mode == EscrowMode::Milestones,
+            EscrowError::NotMilestoneEscrow
+        );
+        
+        // Validate caller is either client or freelancer
+        require!(
+            ctx.accounts.party.key() == escrow.client || 
+            ctx.accounts.party.key() == escrow.freelancer,
+            EscrowError::Unauthorized
+        );
+        
+        // Kill fee pays the freelancer for work in progress out of unreleased funds
+        require!(
+            kill_fee <= escrow.unreleased_amount(),
+            EscrowError::InvalidKillFee
+        );
+        
+        // A new proposal replaces any earlier one
+        escrow.cancellation = Some(CancellationProposal {
+            proposed_by: ctx.accounts.party.key(),
+            kill_fee,
+            proposed_at: clock.unix_timestamp,
+        });
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Cancellation proposed with kill fee {}", kill_fee);
+        Ok(())
     }
-    
-    // Helper function to convert risk level to numerical score
-    fn risk_level_to_score(risk: RiskLevel) -> u8 {
-        match risk {
-            RiskLevel::Low => 25,
-            RiskLevel::Medium => 50,
-            RiskLevel::High => 100,
+
+    pub fn accept_cancellation(
+        ctx: Context<SettleCancellation>,
+        expected_kill_fee: u64,
+        expected_proposed_at: i64,
+    ) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate escrow is active and undisputed
+        require!(
+            escrow.status == EscrowStatus::Active,
+            EscrowError::EscrowNotActive
+        );
+        require!(!escrow.disputed, EscrowError::AlreadyDisputed);
+        require!(
+            !escrow.milestones.iter().any(|m| m.status == MilestoneStatus::Disputed),
+            EscrowError::AlreadyDisputed
+        );
+        
+        // Only the counterparty to the proposal can accept it
+        let proposal = escrow.cancellation
+            .clone()
+            .ok_or(EscrowError::NoCancellationProposed)?;
+        let caller = ctx.accounts.caller.key();
+        require!(
+            (caller == escrow.client || caller == escrow.freelancer) && caller != proposal.proposed_by,
+            EscrowError::Unauthorized
+        );
+        
+        // Validate the proposal was not replaced after the caller reviewed it
+        require!(
+            proposal.kill_fee == expected_kill_fee && proposal.proposed_at == expected_proposed_at,
+            EscrowError::CancellationProposalMismatch
+        );
+        
+        let unreleased = escrow.unreleased_amount();
+        let kill_fee = proposal.kill_fee.min(unreleased);
+        let refund_amount = unreleased - kill_fee;
+        
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            escrow.client.as_ref(),
+            escrow.freelancer.as_ref(),
+            &[escrow.bump],
+        ];
+        let escrow_signer = &[&escrow_seeds[..]];
+        
+        if refund_amount > 0 {
+            transfer_from_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow_token_account,
+                ctx.accounts.client_token_account.to_account_info(),
+                escrow.to_account_info(),
+                escrow_signer,
+                refund_amount,
+            )?;
+        }
+        
+        if kill_fee > 0 {
+            transfer_from_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow_token_account,
+                ctx.accounts.freelancer_token_account.to_account_info(),
+                escrow.to_account_info(),
+                escrow_signer,
+                kill_fee,
+            )?;
+        }
+        
+        let escrow = &mut ctx.accounts.escrow;
+        for (amount, transaction_type, recipient) in [
+            (refund_amount, TransactionType::Refund, escrow.client),
+            (kill_fee, TransactionType::KillFee, escrow.freelancer),
+        ] {
+            if amount > 0 {
+                record_transaction(escrow, ctx.accounts.history.as_mut(), TransactionRecord {
+                    timestamp: clock.unix_timestamp,
+                    slot: clock.slot,
+                    recipient,
+                    transaction_type,
+                    amount,
+                    milestone_index: None,
+                    auto: false,
+                })?;
+            }
         }
+        escrow.mark_cancelled(clock.unix_timestamp);
+        
+        // Update escrow state
+        let escrow_state = &mut ctx.accounts.escrow_state;
+        escrow_state.active_escrows -= 1;
+        escrow_state.last_update_timestamp = clock.unix_timestamp;
+        
+        msg!("Escrow cancelled: {} refunded to client, {} kill fee to freelancer", refund_amount, kill_fee);
+        Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct CalculateEscrowRisk<'info> {
-    pub authority: Signer<'info>,
+    pub fn refund_expired_escrow(ctx: Context<SettleCancellation>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate escrow is active and undisputed
+        require!(
+            escrow.status == EscrowStatus::Active,
+            EscrowError::EscrowNotActive
+        );
+        require!(!escrow.disputed, EscrowError::AlreadyDisputed);
+        
+        // Streams vest by time, so they have no expiry refund
+        require!(
+            escrow.mode == EscrowMode::Milestones,
+            EscrowError::NotMilestoneEscrow
+        );
+        
+        // Refund only applies once the deadline passes with no work ever submitted
+        require!(
+            clock.unix_timestamp > escrow.deadline,
+            EscrowError::DeadlineNotReached
+        );
+        require!(!escrow.any_work_submitted(), EscrowError::WorkSubmitted);
+        
+        let refund_amount = escrow.unreleased_amount();
+        
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            escrow.client.as_ref(),
+            escrow.freelancer.as_ref(),
+            &[escrow.bump],
+        ];
+        let escrow_signer = &[&escrow_seeds[..]];
+        
+        if refund_amount > 0 {
+            transfer_from_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow_token_account,
+                ctx.accounts.client_token_account.to_account_info(),
+                escrow.to_account_info(),
+                escrow_signer,
+                refund_amount,
+            )?;
+        }
+        
+        let escrow = &mut ctx.accounts.escrow;
+        if refund_amount > 0 {
+            record_transaction(escrow, ctx.accounts.history.as_mut(), TransactionRecord {
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                recipient: escrow.client,
+                transaction_type: TransactionType::Refund,
+                amount: refund_amount,
+                milestone_index: None,
+                auto: false,
+            })?;
+        }
+        escrow.mark_cancelled(clock.unix_timestamp);
+        
+        // Update escrow state
+        let escrow_state = &mut ctx.accounts.escrow_state;
+        escrow_state.active_escrows -= 1;
+        escrow_state.last_update_timestamp = clock.unix_timestamp;
+        
+        msg!("Expired escrow refunded: {} to client", refund_amount);
+        Ok(())
+    }
+
+    pub fn propose_amendment(
+        ctx: Context<ProposeAmendment>,
+        changes: Vec<MilestoneAmendment>,
+    ) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate caller is either client or freelancer
+        require!(
+            ctx.accounts.proposer.key() == escrow.client || 
+            ctx.accounts.proposer.key() == escrow.freelancer,
+            EscrowError::Unauthorized
+        );
+        require!(
+            !changes.is_empty() && changes.len() <= MAX_AMENDMENT_CHANGES,
+            EscrowError::InvalidAmendment
+        );
+        require_amendable(escrow)?;
+        
+        // Dry-run the changes so an invalid proposal fails up front
+        let mut milestones = escrow.milestones.clone();
+        apply_milestone_amendments(&mut milestones, &changes, clock.unix_timestamp)?;
+        let new_amount: u64 = milestones.iter().map(|m| m.amount).sum();
+        
+        let amendment = &mut ctx.accounts.amendment;
+        amendment.escrow = escrow.key();
+        amendment.index = escrow.amendment_count;
+        amendment.proposed_by = ctx.accounts.proposer.key();
+        amendment.changes = changes;
+        amendment.previous_amount = escrow.amount;
+        amendment.new_amount = new_amount;
+        amendment.proposed_at = clock.unix_timestamp;
+        amendment.accepted_at = None;
+        amendment.bump = *ctx.bumps.get("amendment").unwrap();
+        
+        msg!("Amendment {} proposed: total {} -> {}", amendment.index, escrow.amount, new_amount);
+        Ok(())
+    }
+
+    pub fn reject_amendment(ctx: Context<RejectAmendment>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        
+        // Either party can withdraw or reject a pending amendment
+        require!(
+            ctx.accounts.party.key() == escrow.client || 
+            ctx.accounts.party.key() == escrow.freelancer,
+            EscrowError::Unauthorized
+        );
+        
+        msg!("Amendment {} rejected", ctx.accounts.amendment.index);
+        Ok(())
+    }
+
+    pub fn accept_amendment(ctx: Context<AcceptAmendment>) -> Result<()> {
+        let clock = Clock::get()?;
+        require_amendable(&ctx.accounts.escrow)?;
+        
+        // Re-apply against current state; milestones may have moved since the proposal
+        let mut milestones = ctx.accounts.escrow.milestones.clone();
+        apply_milestone_amendments(
+            &mut milestones,
+            &ctx.accounts.amendment.changes,
+            clock.unix_timestamp,
+        )?;
+        let previous_amount = ctx.accounts.escrow.amount;
+        let new_amount: u64 = milestones.iter().map(|m| m.amount).sum();
+        
+        if new_amount > previous_amount {
+            // Client funds the increase
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.client_token_account.to_account_info(),
+                    to: ctx.accounts.escrow_token_account.to_account_info(),
+                    authority: ctx.accounts.client.to_account_info(),
+                },
+            );
+            
+            token::transfer(transfer_ctx, new_amount - previous_amount)?;
+        } else if new_amount < previous_amount {
+            // Client is refunded the reduction
+            let escrow = &ctx.accounts.escrow;
+            let escrow_seeds = &[
+                b"escrow".as_ref(),
+                escrow.client.as_ref(),
+                escrow.freelancer.as_ref(),
+                &[escrow.bump],
+            ];
+            let escrow_signer = &[&escrow_seeds[..]];
+            
+            transfer_from_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow_token_account,
+                ctx.accounts.client_token_account.to_account_info(),
+                escrow.to_account_info(),
+                escrow_signer,
+                previous_amount - new_amount,
+            )?;
+        }
+        
+        let escrow = &mut ctx.accounts.escrow;
+        escrow.milestones = milestones;
+        escrow.amount = new_amount;
+        escrow.amendment_count += 1;
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        if new_amount != previous_amount {
+            let (transaction_type, recipient) = if new_amount > previous_amount {
+                (TransactionType::Deposit, escrow.key())
+            } else {
+                (TransactionType::Refund, escrow.client)
+            };
+            record_transaction(escrow, ctx.accounts.history.as_mut(), TransactionRecord {
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                recipient,
+                transaction_type,
+                amount: new_amount.abs_diff(previous_amount),
+                milestone_index: None,
+                auto: false,
+            })?;
+        }
+        
+        let escrow_state = &mut ctx.accounts.escrow_state;
+        if new_amount > previous_amount {
+            escrow_state.total_volume += new_amount - previous_amount;
+        }
+        
+        // Removing the last outstanding milestones completes the escrow
+        if escrow.completed_milestones as usize == escrow.milestones.len() {
+            escrow.status = EscrowStatus::Completed;
+            escrow.completion_date = Some(clock.unix_timestamp);
+            escrow_state.active_escrows -= 1;
+            escrow_state.successful_escrows += 1;
+        }
+        escrow_state.last_update_timestamp = clock.unix_timestamp;
+        
+        // Accepted amendments stay on-chain as the escrow's amendment history
+        ctx.accounts.amendment.accepted_at = Some(clock.unix_timestamp);
+        
+        msg!("Amendment accepted: total {} -> {}", previous_amount, new_amount);
+        Ok(())
+    }
+
+    pub fn create_stream_escrow(
+        ctx: Context<CreateEscrow>,
+        amount: u64,
+        description: String,
+        start_at: i64,
+        end_at: i64,
+        period_seconds: i64,
+    ) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate parameters
+        require!(!ctx.accounts.escrow_state.is_paused, EscrowError::ProgramPaused);
+        require!(amount > 0, EscrowError::InvalidAmount);
+        require!(end_at > clock.unix_timestamp, EscrowError::InvalidDeadline);
+        
+        // Validate schedule: a period of zero vests continuously
+        require!(
+            start_at < end_at &&
+            period_seconds >= 0 &&
+            period_seconds <= end_at - start_at,
+            EscrowError::InvalidStreamSchedule
+        );
+        
+        // Initialize escrow
+        escrow.client = ctx.accounts.client.key();
+        escrow.freelancer = ctx.accounts.freelancer.key();
+        escrow.amount = amount;
+        escrow.mint = ctx.accounts.mint.key();
+        escrow.description = description;
+        escrow.created_at = clock.unix_timestamp;
+        escrow.deadline = end_at;
+        escrow.status = EscrowStatus::Active;
+        escrow.milestones = Vec::new();
+        escrow.completed_milestones = 0;
+        escrow.disputed = false;
+        escrow.auto_release_date = end_at;
+        escrow.last_activity_at = clock.unix_timestamp;
+        escrow.history_pages = 0;
+        escrow.last_notification_at = 0;
+        escrow.client_confirmed_completion = false;
+        escrow.freelancer_confirmed_completion = false;
+        escrow.cancellation = None;
+        escrow.amendment_count = 0;
+        escrow.partner = None;
+        escrow.fee_bps_override = None;
+        escrow.fees_paid = 0;
+        escrow.mode = EscrowMode::Stream;
+        escrow.stream = Some(StreamTerms {
+            start_at,
+            end_at,
+            period_seconds,
+            withdrawn: 0,
+            paused_at: None,
+            paused_duration: 0,
+            frozen_at: None,
+            unvested_settled: false,
+        });
+        escrow.reputation_reported = false;
+        escrow.milestone_disputed = false;
+        escrow.bump = *ctx.bumps.get("escrow").unwrap();
+        
+        // Escrows created through a partner platform carry its negotiated take-rate
+        if let Some(partner_platform) = ctx.accounts.partner_platform.as_mut() {
+            require!(partner_platform.is_active, EscrowError::PartnerInactive);
+            escrow.partner = Some(partner_platform.partner);
+            escrow.fee_bps_override = Some(partner_platform.fee_bps);
+            partner_platform.escrows_created += 1;
+        }
+        
+        // Transfer funds from client to escrow account
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.client_token_account.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.client.to_account_info(),
+            },
+        );
+        
+        token::transfer(transfer_ctx, amount)?;
+        
+        // Record transaction
+        let transaction_record = TransactionRecord {
+            timestamp: clock.unix_timestamp,
+            slot: clock.slot,
+            recipient: escrow.key(),
+            transaction_type: TransactionType::Deposit,
+            amount,
+            milestone_index: None,
+            auto: false,
+        };
+        record_transaction(escrow, None, transaction_record)?;
+        
+        // Update escrow state
+        let escrow_state = &mut ctx.accounts.escrow_state;
+        escrow_state.total_escrows += 1;
+        escrow_state.active_escrows += 1;
+        escrow_state.total_volume += amount;
+        escrow_state.last_update_timestamp = clock.unix_timestamp;
+        
+        // Register the escrow with the insurance program when its accounts are supplied
+        register_with_insurance(&ctx.accounts, amount, end_at)?;
+        
+        msg!("Stream escrow created successfully");
+        Ok(())
+    }
+
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate freelancer is signer
+        require!(
+            ctx.accounts.freelancer.key() == escrow.freelancer,
+            EscrowError::Unauthorized
+        );
+        
+        // Vested funds stay withdrawable through disputes, pauses and termination
+        let amount = escrow.amount;
+        let stream = escrow.stream.as_ref().ok_or(EscrowError::NotStreamEscrow)?;
+        let available = stream.vested_at(amount, clock.unix_timestamp) - stream.withdrawn;
+        require!(available > 0, EscrowError::NothingVested);
+        
+        let fee = escrow.platform_fee(ctx.accounts.escrow_state.platform_fee_bps, available);
+        let payout = available - fee;
+        
+        let seeds = &[
+            b"escrow".as_ref(),
+            escrow.client.as_ref(),
+            escrow.freelancer.as_ref(),
+            &[escrow.bump],
+        ];
+        let signer = &[&seeds[..]];
+        
+        transfer_from_escrow(
+            &ctx.accounts.token_program,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.freelancer_token_account.to_account_info(),
+            escrow.to_account_info(),
+            signer,
+            payout,
+        )?;
+        
+        collect_platform_fee(
+            &ctx.accounts.token_program,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.fee_token_account.as_ref(),
+            &mut ctx.accounts.escrow_state,
+            escrow.to_account_info(),
+            signer,
+            fee,
+        )?;
+        escrow.fees_paid += fee;
+        
+        let stream = escrow.stream.as_mut().unwrap();
+        stream.withdrawn += available;
+        let fully_withdrawn = stream.withdrawn == amount;
+        
+        // Record transaction
+        let transaction_record = TransactionRecord {
+            timestamp: clock.unix_timestamp,
+            slot: clock.slot,
+            recipient: escrow.freelancer,
+            transaction_type: TransactionType::StreamWithdrawal,
+            amount: payout,
+            milestone_index: None,
+            auto: false,
+        };
+        record_transaction(escrow, ctx.accounts.history.as_mut(), transaction_record)?;
+        
+        if fee > 0 {
+            let fee_record = TransactionRecord {
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                recipient: ctx.accounts.escrow_state.fee_recipient,
+                transaction_type: TransactionType::PlatformFee,
+                amount: fee,
+                milestone_index: None,
+                auto: false,
+            };
+            record_transaction(escrow, ctx.accounts.history.as_mut(), fee_record)?;
+        }
+        
+        // A stream that ran its full course and was drawn down completes the escrow
+        if fully_withdrawn && escrow.status == EscrowStatus::Active && !escrow.disputed {
+            escrow.status = EscrowStatus::Completed;
+            escrow.completion_date = Some(clock.unix_timestamp);
+            
+            // Update escrow state
+            let escrow_state = &mut ctx.accounts.escrow_state;
+            escrow_state.active_escrows -= 1;
+            escrow_state.successful_escrows += 1;
+            escrow_state.last_update_timestamp = clock.unix_timestamp;
+        }
+        
+        // Update last activity at
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Withdrew {} vested from stream", payout);
+        Ok(())
+    }
+
+    pub fn pause_stream(ctx: Context<ManageStream>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        require_stream_running(escrow)?;
+        
+        let stream = escrow.stream.as_mut().unwrap();
+        require!(stream.paused_at.is_none(), EscrowError::StreamPaused);
+        stream.paused_at = Some(clock.unix_timestamp);
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Stream paused");
+        Ok(())
+    }
+
+    pub fn resume_stream(ctx: Context<ManageStream>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        require_stream_running(escrow)?;
+        
+        // Time spent paused pushes the rest of the schedule back
+        let stream = escrow.stream.as_mut().unwrap();
+        let paused_at = stream.paused_at.ok_or(EscrowError::StreamNotPaused)?;
+        stream.paused_duration += clock.unix_timestamp - paused_at;
+        stream.paused_at = None;
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Stream resumed");
+        Ok(())
+    }
+
+    pub fn terminate_stream(ctx: Context<TerminateStream>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        require_stream_running(escrow)?;
+        
+        // Vesting stops now; the freelancer keeps what vested and the rest goes back
+        let stream = escrow.stream.as_mut().unwrap();
+        stream.frozen_at = Some(clock.unix_timestamp);
+        let refund_amount = escrow.unreleased_amount();
+        
+        let seeds = &[
+            b"escrow".as_ref(),
+            escrow.client.as_ref(),
+            escrow.freelancer.as_ref(),
+            &[escrow.bump],
+        ];
+        let signer = &[&seeds[..]];
+        
+        if refund_amount > 0 {
+            transfer_from_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow_token_account,
+                ctx.accounts.client_token_account.to_account_info(),
+                escrow.to_account_info(),
+                signer,
+                refund_amount,
+            )?;
+            
+            record_transaction(escrow, ctx.accounts.history.as_mut(), TransactionRecord {
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                recipient: escrow.client,
+                transaction_type: TransactionType::Refund,
+                amount: refund_amount,
+                milestone_index: None,
+                auto: false,
+            })?;
+        }
+        
+        escrow.stream.as_mut().unwrap().unvested_settled = true;
+        escrow.mark_cancelled(clock.unix_timestamp);
+        
+        // Update escrow state
+        let escrow_state = &mut ctx.accounts.escrow_state;
+        escrow_state.active_escrows -= 1;
+        escrow_state.last_update_timestamp = clock.unix_timestamp;
+        
+        msg!("Stream terminated: {} unvested refunded to client", refund_amount);
+        Ok(())
+    }
+
+    pub fn open_history_page(ctx: Context<OpenHistoryPage>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        
+        // Validate caller is either client or freelancer
+        require!(
+            ctx.accounts.payer.key() == escrow.client || 
+            ctx.accounts.payer.key() == escrow.freelancer,
+            EscrowError::Unauthorized
+        );
+        
+        // Pages are chained in order; a new one opens once the latest can no longer
+        // fit every record a single instruction may append
+        if escrow.history_pages > 0 {
+            let previous = ctx.accounts.previous_page
+                .as_ref()
+                .ok_or(EscrowError::InvalidHistoryPage)?;
+            require!(
+                previous.escrow == escrow.key() && previous.page == escrow.history_pages - 1,
+                EscrowError::InvalidHistoryPage
+            );
+            require!(
+                previous.records.len() + MAX_RECORDS_PER_INSTRUCTION > HISTORY_PAGE_CAPACITY,
+                EscrowError::HistoryPageNotFull
+            );
+        }
+        
+        let page = &mut ctx.accounts.page;
+        page.escrow = escrow.key();
+        page.page = escrow.history_pages;
+        page.records = Vec::new();
+        page.bump = *ctx.bumps.get("page").unwrap();
+        
+        escrow.history_pages += 1;
+        
+        msg!("Escrow history page {} opened", page.page);
+        Ok(())
+    }
+
+    pub fn update_escrow_parameters(
+        ctx: Context<UpdateEscrowParameters>,
+        dispute_resolution_fee: Option<u64>,
+        auto_release_days: Option<u16>,
+        is_paused: Option<bool>,
+        platform_fee_bps: Option<u16>,
+        fee_destination: Option<FeeDestination>,
+        fee_recipient: Option<Pubkey>,
+    ) -> Result<()> {
+        let escrow_state = &mut ctx.accounts.escrow_state;
+        
+        // Validate authority
+        require!(
+            ctx.accounts.authority.key() == escrow_state.authority,
+            EscrowError::Unauthorized
+        );
+        
+        // Update parameters if provided
+        if let Some(fee) = dispute_resolution_fee {
+            escrow_state.dispute_resolution_fee = fee;
+        }
+        
+        if let Some(days) = auto_release_days {
+            escrow_state.auto_release_days = days;
+        }
+        
+        if let Some(paused) = is_paused {
+            escrow_state.is_paused = paused;
+        }
+        
+        if let Some(fee_bps) = platform_fee_bps {
+            require!(fee_bps <= MAX_PLATFORM_FEE_BPS, EscrowError::InvalidFeeRate);
+            escrow_state.platform_fee_bps = fee_bps;
+        }
+        
+        if let Some(destination) = fee_destination {
+            escrow_state.fee_destination = destination;
+        }
+        
+        if let Some(recipient) = fee_recipient {
+            escrow_state.fee_recipient = recipient;
+        }
+        
+        // Validate fees go to the account that owns the chosen destination
+        require!(
+            escrow_state.fee_recipient == escrow_state.fee_destination.recipient(),
+            EscrowError::InvalidFeeRecipient
+        );
+        
+        escrow_state.last_update_timestamp = Clock::get()?.unix_timestamp;
+        
+        msg!("Escrow parameters updated");
+        Ok(())
+    }
+
+    pub fn register_partner_platform(
+        ctx: Context<RegisterPartnerPlatform>,
+        fee_bps: u16,
+    ) -> Result<()> {
+        // Validate negotiated rate
+        require!(fee_bps <= MAX_PLATFORM_FEE_BPS, EscrowError::InvalidFeeRate);
+        
+        let partner_platform = &mut ctx.accounts.partner_platform;
+        partner_platform.partner = ctx.accounts.partner.key();
+        partner_platform.fee_bps = fee_bps;
+        partner_platform.escrows_created = 0;
+        partner_platform.is_active = true;
+        partner_platform.bump = *ctx.bumps.get("partner_platform").unwrap();
+        
+        msg!("Partner platform registered with a {} bps take-rate", fee_bps);
+        Ok(())
+    }
+
+    pub fn update_partner_platform(
+        ctx: Context<UpdatePartnerPlatform>,
+        fee_bps: Option<u16>,
+        is_active: Option<bool>,
+    ) -> Result<()> {
+        let partner_platform = &mut ctx.accounts.partner_platform;
+        
+        // Existing escrows keep the rate they were created with
+        if let Some(fee_bps) = fee_bps {
+            require!(fee_bps <= MAX_PLATFORM_FEE_BPS, EscrowError::InvalidFeeRate);
+            partner_platform.fee_bps = fee_bps;
+        }
+        
+        if let Some(active) = is_active {
+            partner_platform.is_active = active;
+        }
+        
+        msg!("Partner platform updated");
+        Ok(())
+    }
+
+    pub fn complete_escrow(
+        ctx: Context<CompleteEscrow>,
+        _feedback: String,
+        rating: u8,
+    ) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate escrow is active
+        require!(
+            escrow.status == EscrowStatus::Active,
+            EscrowError::EscrowNotActive
+        );
+        
+        // Validate all milestones are completed
+        require!(
+            escrow.mode == EscrowMode::Milestones,
+            EscrowError::NotMilestoneEscrow
+        );
+        require!(
+            escrow.completed_milestones as usize == escrow.milestones.len(),
+            EscrowError::NotAllMilestonesCompleted
+        );
+        
+        // Validate caller is client
+        require!(
+            ctx.accounts.client.key() == escrow.client,
+            EscrowError::Unauthorized
+        );
+        
+        // Mark escrow as completed
+        escrow.status = EscrowStatus::Completed;
+        escrow.completion_date = Some(clock.unix_timestamp);
+        escrow.client_confirmed_completion = true;
+        
+        // Calculate completion metrics
+        let completion_time = clock.unix_timestamp - escrow.created_at;
+        
+        // Update escrow state
+        let escrow_state = &mut ctx.accounts.escrow_state;
+        escrow_state.active_escrows -= 1;
+        escrow_state.successful_escrows += 1;
+        
+        // Update average completion time
+        if escrow_state.successful_escrows == 1 {
+            escrow_state.average_completion_time = completion_time;
+        } else {
+            let total_time = escrow_state.average_completion_time * (escrow_state.successful_escrows - 1) as i64;
+            escrow_state.average_completion_time = (total_time + completion_time) / escrow_state.successful_escrows as i64;
+        }
+        
+        escrow_state.last_update_timestamp = clock.unix_timestamp;
+        
+        // Update last activity timestamp
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        msg!("Escrow completed successfully with rating {}", rating);
+        Ok(())
+    }
+
+    pub fn confirm_completion(
+        ctx: Context<ConfirmCompletion>,
+        _feedback: String,
+        rating: u8,
+    ) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+        
+        // Validate caller is freelancer
+        require!(
+            ctx.accounts.freelancer.key() == escrow.freelancer,
+            EscrowError::Unauthorized
+        );
+        
+        // Validate escrow status is appropriate
+        require!(
+            escrow.status == EscrowStatus::Active || 
+            escrow.status == EscrowStatus::Completed,
+            EscrowError::InvalidEscrowStatus
+        );
+        
+        // Mark freelancer confirmation
+        escrow.freelancer_confirmed_completion = true;
+        
+        // Update last activity timestamp
+        escrow.last_activity_at = clock.unix_timestamp;
+        
+        // If both parties have confirmed, update status if not already completed
+        if escrow.client_confirmed_completion && escrow.freelancer_confirmed_completion && escrow.status != EscrowStatus::Completed {
+            escrow.status = EscrowStatus::Completed;
+            escrow.completion_date = Some(clock.unix_timestamp);
+            
+            // Update escrow state
+            let escrow_state = &mut ctx.accounts.escrow_state;
+            escrow_state.active_escrows -= 1;
+            escrow_state.successful_escrows += 1;
+            escrow_state.last_update_timestamp = clock.unix_timestamp;
+        }
+        
+        msg!("Completion confirmation recorded with rating {}", rating);
+        Ok(())
+    }
+
+    /// Permissionless crank that reports a finished escrow to the reputation program.
+    /// No completion path (release, auto-release, confirmation, dispute resolution or
+    /// stream withdrawal) updates reputation itself, so a client or keeper must send
+    /// this once the escrow is Completed or Resolved for either party's score to move
+    pub fn report_contract_completion(ctx: Context<ReportContractCompletion>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        
+        // Escrows finish in many instructions, several of them permissionless cranks, so
+        // reputation is reported once afterwards rather than on each of those paths
+        require!(
+            escrow.status == EscrowStatus::Completed ||
+            escrow.status == EscrowStatus::Resolved,
+            EscrowError::InvalidEscrowStatus
+        );
+        require!(!escrow.reputation_reported, EscrowError::ReputationAlreadyReported);
+        
+        // A resolved escrow counts as a success for each party awarded at least half
+        let (client_successful, freelancer_successful) = match escrow.dispute_details
+            .as_ref()
+            .and_then(|details| details.resolution.as_ref())
+        {
+            Some(resolution) if escrow.status == EscrowStatus::Resolved => (
+                resolution.client_percentage >= 50,
+                resolution.freelancer_percentage >= 50,
+            ),
+            _ => (true, true),
+        };
+        let disputed = escrow.dispute_details.is_some() || escrow.milestone_disputed;
+        let contract_id = escrow.key().to_string();
+        
+        // The escrow state PDA signs as the caller the reputation program authorizes
+        let state_seeds = &[b"escrow_state".as_ref(), &[ctx.accounts.escrow_state.bump]];
+        let state_signer = &[&state_seeds[..]];
+        
+        for (user_profile, successful) in [
+            (&ctx.accounts.client_profile, client_successful),
+            (&ctx.accounts.freelancer_profile, freelancer_successful),
+        ] {
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.reputation_program.to_account_info(),
+                reputation_program::cpi::accounts::UpdateContractCompletion {
+                    caller: ctx.accounts.escrow_state.to_account_info(),
+                    user_profile: user_profile.to_account_info(),
+                    reputation_state: ctx.accounts.reputation_state.to_account_info(),
+                },
+                state_signer,
+            );
+            
+            reputation_program::cpi::update_contract_completion(
+                cpi_ctx,
+                successful,
+                disputed,
+                contract_id.clone(),
+                None,
+            )?;
+        }
+        
+        escrow.reputation_reported = true;
+        
+        msg!("Escrow reported to reputation program");
+        Ok(())
+    }
+
+    pub fn get_escrow_analytics(
+        ctx: Context<GetEscrowAnalytics>,
+    ) -> Result<EscrowAnalytics> {
+        let escrow_state = &ctx.accounts.escrow_state;
+        
+        // Calculate success rate
+        let success_rate = if escrow_state.total_escrows > 0 {
+            (escrow_state.successful_escrows as f64 / escrow_state.total_escrows as f64) * 100.0
+        } else {
+            0.0
+        };
+        
+        // Calculate dispute rate
+        let dispute_rate = if escrow_state.total_escrows > 0 {
+            (escrow_state.disputed_escrows as f64 / escrow_state.total_escrows as f64) * 100.0
+        } else {
+            0.0
+        };
+        
+        // Format average completion time into days, hours, minutes
+        let avg_completion_days = escrow_state.average_completion_time / 86400;
+        let avg_completion_hours = (escrow_state.average_completion_time % 86400) / 3600;
+        let avg_completion_minutes = (escrow_state.average_completion_time % 3600) / 60;
+        
+        let analytics = EscrowAnalytics {
+            total_escrows: escrow_state.total_escrows,
+            active_escrows: escrow_state.active_escrows,
+            total_volume: escrow_state.total_volume,
+            successful_escrows: escrow_state.successful_escrows,
+            disputed_escrows: escrow_state.disputed_escrows,
+            success_rate: (success_rate * 100.0) as u32, // Store as basis points
+            dispute_rate: (dispute_rate * 100.0) as u32, // Store as basis points
+            average_completion_time: escrow_state.average_completion_time,
+            platform_fee_bps: escrow_state.platform_fee_bps,
+            fee_destination: escrow_state.fee_destination,
+            total_fees_collected: escrow_state.total_fees_collected,
+            avg_completion_days,
+            avg_completion_hours,
+            avg_completion_minutes,
+            last_update_timestamp: escrow_state.last_update_timestamp,
+        };
+        
+        msg!("Escrow analytics retrieved");
+        Ok(analytics)
+    }
+
+    pub fn get_user_escrow_history(
+        ctx: Context<GetUserEscrowHistory>,
+        user_pubkey: Pubkey,
+        role: UserRole,
+        limit: u8,
+    ) -> Result<Vec<EscrowSummary>> {
+        let clock = Clock::get()?;
+        
+        // Since we can't filter accounts directly in the program,
+        // we'll search through accounts provided by the client
+        let escrow_accounts = &ctx.remaining_accounts;
+        let mut escrow_summaries = Vec::new();
+        let mut count = 0;
+        
+        // Process each account provided
+        for account_info in escrow_accounts.iter() {
+            if count >= limit as usize {
+                break;
+            }
+            
+            // Deserialize the escrow account
+            if let Ok(escrow) = Account::<Escrow>::try_from(account_info) {
+                // Check if the account belongs to the requested user based on role
+                let belongs_to_user = match role {
+                    UserRole::Client => escrow.client == user_pubkey,
+                    UserRole::Freelancer => escrow.freelancer == user_pubkey,
+                    UserRole::Both => escrow.client == user_pubkey || escrow.freelancer == user_pubkey,
+                };
+                
+                if belongs_to_user {
+                    // Create and add summary if it belongs to the user
+                    let summary = EscrowSummary {
+                        escrow_pubkey: account_info.key(),
+                        client: escrow.client,
+                        freelancer: escrow.freelancer,
+                        amount: escrow.amount,
+                        status: escrow.status,
+                        created_at: escrow.created_at,
+                        deadline: escrow.deadline,
+                        milestones_count: escrow.milestones.len() as u8,
+                        completed_milestones: escrow.completed_milestones,
+                        active_time_remaining: if escrow.status == EscrowStatus::Active {
+                            escrow.deadline.saturating_sub(clock.unix_timestamp)
+                        } else {
+                            0
+                        },
+                        disputed: escrow.disputed,
+                        completion_date: escrow.completion_date,
+                        last_activity_at: escrow.last_activity_at,
+                        partner: escrow.partner,
+                        fee_bps: escrow.fee_bps_override.unwrap_or(ctx.accounts.escrow_state.platform_fee_bps),
+                        fees_paid: escrow.fees_paid,
+                        mode: escrow.mode,
+                    };
+                    
+                    escrow_summaries.push(summary);
+                    count += 1;
+                }
+            }
+        }
+        
+        // Sort by most recent activity first
+        escrow_summaries.sort_by(|a, b| b.last_activity_at.cmp(&a.last_activity_at));
+        
+        msg!("Found {} escrow records for user based on role {:?}", escrow_summaries.len(), role);
+        Ok(escrow_summaries)
+    }
+
+    pub fn calculate_escrow_risk(
+        ctx: Context<CalculateEscrowRisk>,
+        escrow_pubkey: Pubkey,
+    ) -> Result<EscrowRiskAnalysis> {
+        let clock = Clock::get()?;
+        
+        // Deserialize the escrow account
+        let escrow_account = ctx.accounts.escrow.to_account_info();
+        let escrow = Account::<Escrow>::try_from(&escrow_account)?;
+        
+        // 1. Amount Risk - Higher amounts increase risk
+        let amount_risk = if escrow.amount > 1_000_000_000 { // 1000 SOL
+            RiskLevel::High
+        } else if escrow.amount > 100_000_000 { // 100 SOL
+            RiskLevel::Medium
+        } else {
+            RiskLevel::Low
+        };
+        
+        // 2. Time Risk - Longer timeframes increase risk
+        let duration = escrow.deadline - escrow.created_at;
+        let time_risk = if duration > 60 * 86400 { // 60 days
+            RiskLevel::High
+        } else if duration > 30 * 86400 { // 30 days
+            RiskLevel::Medium
+        } else {
+            RiskLevel::Low
+        };
+        
+        // 3. Milestone Risk - Fewer milestones increase risk
+        let milestone_risk = if escrow.milestones.len() <= 2 {
+            RiskLevel::High
+        } else if escrow.milestones.len() <= 5 {
+            RiskLevel::Medium
+        } else {
+            RiskLevel::Low
+        };
+        
+        // 4. Progress Risk - Time elapsed vs milestone completion
+        let time_elapsed = clock.unix_timestamp - escrow.created_at;
+        let time_percentage = if duration > 0 {
+            (time_elapsed as f64 / duration as f64) * 100.0
+        } else {
+            100.0
+        };
+        
+        let milestone_percentage = if escrow.milestones.len() > 0 {
+            (escrow.completed_milestones as f64 / escrow.milestones.len() as f64) * 100.0
+        } else {
+            0.0
+        };
+        
+        let progress_risk = if time_percentage > milestone_percentage + 30.0 {
+            RiskLevel::High
+        } else if time_percentage > milestone_percentage + 15.0 {
+            RiskLevel::Medium
+        } else {
+            RiskLevel::Low
+        };
+        
+        // 5. Dispute Risk - Based on client and freelancer history from reputation program
+        // Note: In a real implementation, this would fetch data from the reputation program
+        let dispute_risk = if !ctx.accounts.reputation_stats.to_account_info().data_is_empty() {
+            // Here we would make a cross-program call to get reputation data
+            // For now we'll use a placeholder value based on dispute flag
+            if escrow.disputed {
+                RiskLevel::High
+            } else {
+                RiskLevel::Low
+            }
+        } else {
+            RiskLevel::Medium // Default if no reputation data available
+        };
+        
+        // Calculate composite risk score (0-100)
+        // Weight factors based on the insurance system components from memory
+        let amount_weight = 20;
+        let time_weight = 15;
+        let milestone_weight = 15;
+        let progress_weight = 30;
+        let dispute_weight = 20;
+        
+        let risk_score = 
+            (risk_level_to_score(amount_risk) * amount_weight +
+             risk_level_to_score(time_risk) * time_weight +
+             risk_level_to_score(milestone_risk) * milestone_weight +
+             risk_level_to_score(progress_risk) * progress_weight +
+             risk_level_to_score(dispute_risk) * dispute_weight) / 
+            (amount_weight + time_weight + milestone_weight + progress_weight + dispute_weight);
+        
+        // Create and return risk analysis result
+        let risk_analysis = EscrowRiskAnalysis {
+            escrow_pubkey,
+            risk_score,
+            amount_risk,
+            time_risk,
+            milestone_risk,
+            progress_risk,
+            dispute_risk,
+            analysis_timestamp: clock.unix_timestamp,
+            time_percentage: (time_percentage * 100.0) as u32, // Store as basis points
+            milestone_percentage: (milestone_percentage * 100.0) as u32, // Store as basis points
+            time_elapsed,
+            duration,
+            amount: escrow.amount,
+        };
+        
+        // Log analysis for transparency and blockchain record
+        msg!("Escrow risk analysis completed: Score {}", risk_score);
+        
+        Ok(risk_analysis)
+    }
+    
+    // Helper function to convert risk level to numerical score
+    fn risk_level_to_score(risk: RiskLevel) -> u8 {
+        match risk {
+            RiskLevel::Low => 25,
+            RiskLevel::Medium => 50,
+            RiskLevel::High => 100,
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct CalculateEscrowRisk<'info> {
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Escrow account - deserialized in the instruction
+    pub escrow: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// CHECK: Optional reputation program account for checking user history
+    pub reputation_stats: UncheckedAccount<'info>,
+    
+    /// Insurance program account for risk assessment integration
+    pub insurance_program: Program<'info, FreelanceInsurance>,
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + EscrowState::SIZE,
+        seeds = [b"escrow_state"],
+        bump
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateEscrow<'info> {
+    #[account(mut)]
+    pub client: Signer<'info>,
+    
+    /// CHECK: This account is just used as a reference
+    pub freelancer: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Escrow::SIZE,
+        seeds = [b"escrow", client.key().as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        constraint = client_token_account.owner == client.key() @ EscrowError::Unauthorized,
+        constraint = client_token_account.mint == mint.key() @ EscrowError::InvalidMint
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = client,
+        associated_token::mint = mint,
+        associated_token::authority = escrow
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    pub mint: Account<'info, token::Mint>,
+    
+    /// Partner co-signing escrows created through its platform
+    pub partner: Option<Signer<'info>>,
+    
+    /// Partner platform the escrow is created through, if any
+    #[account(
+        mut,
+        seeds = [b"partner_platform", partner_platform.partner.as_ref()],
+        bump = partner_platform.bump,
+        constraint = partner.as_ref().map_or(false, |p| p.key() == partner_platform.partner) @ EscrowError::Unauthorized,
+    )]
+    pub partner_platform: Option<Account<'info, PartnerPlatform>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    
+    /// Insurance program to register the escrow with, if any
+    pub insurance_program: Option<Program<'info, FreelanceInsurance>>,
+    
+    /// CHECK: Payment verification initialized by the insurance program
+    #[account(mut)]
+    pub payment_verification: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseMilestone<'info> {
+    #[account(mut)]
+    pub client: Signer<'info>,
+    
+    #[account(mut)]
+    pub freelancer: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", client.key().as_ref(), freelancer.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        constraint = client_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = client,
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = freelancer_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = freelancer,
+    )]
+    pub freelancer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Fee recipient's token account, required while a take-rate applies
+    #[account(mut)]
+    pub fee_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitMilestone<'info> {
+    pub freelancer: Signer<'info>,
+    
+    /// CHECK: Only used to derive the escrow address
+    pub client: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", client.key().as_ref(), freelancer.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+#[derive(Accounts)]
+pub struct ReviewMilestone<'info> {
+    pub client: Signer<'info>,
+    
+    /// CHECK: Only used to derive the escrow address
+    pub freelancer: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", client.key().as_ref(), freelancer.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+#[derive(Accounts)]
+pub struct AutoRelease<'info> {
+    /// Anyone can trigger an auto-release once it is due
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = freelancer_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow.freelancer,
+    )]
+    pub freelancer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Fee recipient's token account, required while a take-rate applies
+    #[account(mut)]
+    pub fee_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct DisputeEscrow<'info> {
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    #[account(
+        mut,
+        constraint = disputer_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = disputer,
+    )]
+    pub disputer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = disputer,
+        space = 8 + DisputePanel::SIZE,
+        seeds = [b"dispute_panel", escrow.key().as_ref(), &[ESCROW_DISPUTE_SCOPE]],
+        bump
+    )]
+    pub dispute_panel: Account<'info, DisputePanel>,
+    
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+        constraint = authority.key() == escrow_state.authority
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.disputed,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute_panel", escrow.key().as_ref(), &[ESCROW_DISPUTE_SCOPE]],
+        bump = dispute_panel.bump,
+        constraint = !dispute_panel.settled @ EscrowError::DisputeAlreadyResolved,
+    )]
+    pub dispute_panel: Account<'info, DisputePanel>,
+    
+    #[account(
+        mut,
+        constraint = fee_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow_state.authority,
+    )]
+    pub fee_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = client_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow.client,
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = freelancer_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow.freelancer,
+    )]
+    pub freelancer_token_account: Account<'info, TokenAccount>,
+    
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct DisputeMilestone<'info> {
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    #[account(
+        init,
+        payer = disputer,
+        space = 8 + MilestoneDispute::SIZE,
+        seeds = [b"milestone_dispute", escrow.key().as_ref(), &[milestone_index]],
+        bump
+    )]
+    pub milestone_dispute: Account<'info, MilestoneDispute>,
+    
+    #[account(
+        mut,
+        constraint = disputer_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = disputer,
+    )]
+    pub disputer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = disputer,
+        space = 8 + DisputePanel::SIZE,
+        seeds = [b"dispute_panel", escrow.key().as_ref(), &[milestone_index]],
+        bump
+    )]
+    pub dispute_panel: Account<'info, DisputePanel>,
+    
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct ResolveMilestoneDispute<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+        constraint = authority.key() == escrow_state.authority
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"milestone_dispute", escrow.key().as_ref(), &[milestone_index]],
+        bump = milestone_dispute.bump,
+        constraint = milestone_dispute.resolution.is_none() @ EscrowError::DisputeAlreadyResolved,
+    )]
+    pub milestone_dispute: Account<'info, MilestoneDispute>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute_panel", escrow.key().as_ref(), &[milestone_index]],
+        bump = dispute_panel.bump,
+        constraint = !dispute_panel.settled @ EscrowError::DisputeAlreadyResolved,
+    )]
+    pub dispute_panel: Account<'info, DisputePanel>,
+    
+    #[account(
+        mut,
+        constraint = fee_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow_state.authority,
+    )]
+    pub fee_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = client_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow.client,
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = freelancer_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow.freelancer,
+    )]
+    pub freelancer_token_account: Account<'info, TokenAccount>,
+    
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(scope: u8)]
+pub struct DrawDisputePanel<'info> {
+    /// Anyone can draw the panel once its committed slot has passed
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    /// Core arbitrator registry the panel is drawn from
+    #[account(
+        seeds = [ArbitratorRegistry::SEED_PREFIX],
+        bump = arbitrator_registry.bump,
+        seeds::program = CORE_PROGRAM_ID,
+    )]
+    pub arbitrator_registry: Account<'info, ArbitratorRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute_panel", escrow.key().as_ref(), &[scope]],
+        bump = dispute_panel.bump,
+    )]
+    pub dispute_panel: Account<'info, DisputePanel>,
+    
+    /// CHECK: Address is constrained to the SlotHashes sysvar
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(scope: u8)]
+pub struct SubmitPanelDecision<'info> {
+    pub member: Signer<'info>,
+    
+    #[account(
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute_panel", escrow.key().as_ref(), &[scope]],
+        bump = dispute_panel.bump,
+        constraint = !dispute_panel.settled && !dispute_panel.escalated @ EscrowError::DisputeAlreadyResolved,
+    )]
+    pub dispute_panel: Account<'info, DisputePanel>,
+}
+
+#[derive(Accounts)]
+#[instruction(scope: u8)]
+pub struct SettleDisputePanel<'info> {
+    /// Anyone can settle once the panel has decided or timed out
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute_panel", escrow.key().as_ref(), &[scope]],
+        bump = dispute_panel.bump,
+        constraint = !dispute_panel.settled && !dispute_panel.escalated @ EscrowError::DisputeAlreadyResolved,
+    )]
+    pub dispute_panel: Account<'info, DisputePanel>,
     
-    /// CHECK: Escrow account - deserialized in the instruction
-    pub escrow: AccountInfo<'info>,
+    /// Required when the panel covers a single milestone
+    #[account(mut)]
+    pub milestone_dispute: Option<Account<'info, MilestoneDispute>>,
     
     #[account(
-        seeds = [b"escrow_state"],
-        bump = escrow_state.bump,
+        mut,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
     )]
-    pub escrow_state: Account<'info, EscrowState>,
+    pub escrow_token_account: Account<'info, TokenAccount>,
     
-    /// CHECK: Optional reputation program account for checking user history
-    pub reputation_stats: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = client_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow.client,
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = freelancer_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow.freelancer,
+    )]
+    pub freelancer_token_account: Account<'info, TokenAccount>,
     
-    /// CHECK: Insurance program account for risk assessment integration
-    #[account(address = INSURANCE_PROGRAM_ID)]
-    pub insurance_program: UncheckedAccount<'info>,
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
+    
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+pub struct ProposeCancellation<'info> {
+    pub party: Signer<'info>,
     
     #[account(
-        init,
-        payer = authority,
-        space = 8 + EscrowState::SIZE,
-        seeds = [b"escrow_state"],
-        bump
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
     )]
-    pub escrow_state: Account<'info, EscrowState>,
-    
-    pub system_program: Program<'info, System>,
+    pub escrow: Account<'info, Escrow>,
 }
 
 #[derive(Accounts)]
-pub struct CreateEscrow<'info> {
-    #[account(mut)]
-    pub client: Signer<'info>,
+pub struct SettleCancellation<'info> {
+    /// Counterparty accepting a cancellation, or anyone refunding an expired escrow
+    pub caller: Signer<'info>,
     
-    /// CHECK: This account is just used as a reference
-    pub freelancer: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
     
     #[account(
+        mut,
         seeds = [b"escrow_state"],
         bump = escrow_state.bump,
     )]
     pub escrow_state: Account<'info, EscrowState>,
     
     #[account(
-        init,
-        payer = client,
-        space = 8 + Escrow::SIZE,
-        seeds = [b"escrow", client.key().as_ref(), freelancer.key().as_ref()],
-        bump
+        mut,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
     )]
-    pub escrow: Account<'info, Escrow>,
+    pub escrow_token_account: Account<'info, TokenAccount>,
     
     #[account(
         mut,
-        constraint = client_token_account.owner == client.key()
+        constraint = client_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow.client,
     )]
     pub client_token_account: Account<'info, TokenAccount>,
     
     #[account(
-        init_if_needed,
-        payer = client,
-        associated_token::mint = mint,
-        associated_token::authority = escrow
+        mut,
+        constraint = freelancer_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = escrow.freelancer,
     )]
-    pub escrow_token_account: Account<'info, TokenAccount>,
+    pub freelancer_token_account: Account<'info, TokenAccount>,
+    
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
     
-    pub mint: Account<'info, token::Mint>,
     pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAmendment<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    /// One pending amendment at a time, keyed by the escrow's amendment count
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + EscrowAmendment::SIZE,
+        seeds = [b"escrow_amendment", escrow.key().as_ref(), &escrow.amendment_count.to_le_bytes()],
+        bump
+    )]
+    pub amendment: Account<'info, EscrowAmendment>,
+    
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-    pub insurance_program: AccountInfo<'info>,
-    /// CHECK: Used to record transaction signature
-    pub transaction_signature: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ReleaseMilestone<'info> {
+pub struct RejectAmendment<'info> {
+    pub party: Signer<'info>,
+    
+    #[account(
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_amendment", escrow.key().as_ref(), &escrow.amendment_count.to_le_bytes()],
+        bump = amendment.bump,
+        constraint = amendment.accepted_at.is_none() @ EscrowError::InvalidAmendment,
+        close = proposer,
+    )]
+    pub amendment: Account<'info, EscrowAmendment>,
+    
+    /// CHECK: Receives the rent of the closed proposal
+    #[account(mut, address = amendment.proposed_by)]
+    pub proposer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAmendment<'info> {
+    /// Both parties sign the acceptance; the client funds any increase
     #[account(mut)]
     pub client: Signer<'info>,
     
-    #[account(mut)]
-    pub freelancer: AccountInfo<'info>,
+    pub freelancer: Signer<'info>,
     
     #[account(
         mut,
         seeds = [b"escrow", client.key().as_ref(), freelancer.key().as_ref()],
         bump = escrow.bump,
+        realloc = Escrow::space_for(escrow.milestones.len() + amendment.added_milestones()),
+        realloc::payer = client,
+        realloc::zero = false,
     )]
     pub escrow: Account<'info, Escrow>,
     
     #[account(
         mut,
-        token::mint = escrow_token_account.mint,
-        token::authority = client,
+        seeds = [b"escrow_amendment", escrow.key().as_ref(), &escrow.amendment_count.to_le_bytes()],
+        bump = amendment.bump,
+        constraint = amendment.accepted_at.is_none() @ EscrowError::InvalidAmendment,
     )]
-    pub client_token_account: Account<'info, TokenAccount>,
+    pub amendment: Account<'info, EscrowAmendment>,
     
     #[account(
         mut,
-        token::mint = escrow_token_account.mint,
-        token::authority = freelancer,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
     )]
-    pub freelancer_token_account: Account<'info, TokenAccount>,
+    pub escrow_state: Account<'info, EscrowState>,
     
     #[account(
         mut,
-        seeds = [b"escrow_token", client.key().as_ref(), freelancer.key().as_ref()],
-        bump,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
     )]
     pub escrow_token_account: Account<'info, TokenAccount>,
     
     #[account(
         mut,
-        seeds = [b"escrow_state"],
-        bump = escrow_state.bump,
+        constraint = client_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = client,
     )]
-    pub escrow_state: Account<'info, EscrowState>,
-    
-    #[account(address = REPUTATION_PROGRAM_ID)]
-    pub reputation_program: AccountInfo<'info>,
+    pub client_token_account: Account<'info, TokenAccount>,
     
-    /// CHECK: Used to record transaction signature
-    pub transaction_signature: AccountInfo<'info>,
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
     
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct DisputeEscrow<'info> {
-    #[account(mut)]
-    pub disputer: Signer<'info>,
+pub struct WithdrawVested<'info> {
+    pub freelancer: Signer<'info>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), freelancer.key().as_ref()],
+        bump = escrow.bump,
+    )]
     pub escrow: Account<'info, Escrow>,
     
     #[account(
-        seeds = [b"escrow_state"],
-        bump = escrow_state.bump,
+        mut,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
     )]
-    pub escrow_state: Account<'info, EscrowState>,
+    pub escrow_token_account: Account<'info, TokenAccount>,
     
     #[account(
         mut,
-        token::mint = fee_account.mint,
-        token::authority = disputer,
+        constraint = freelancer_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = freelancer,
     )]
-    pub disputer_token_account: Account<'info, TokenAccount>,
+    pub freelancer_token_account: Account<'info, TokenAccount>,
     
     #[account(
         mut,
-        token::authority = escrow_state.authority,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
     )]
-    pub fee_account: Account<'info, TokenAccount>,
+    pub escrow_state: Account<'info, EscrowState>,
     
-    /// CHECK: Used to record transaction signature
-    pub transaction_signature: AccountInfo<'info>,
+    /// Fee recipient's token account, required while a take-rate applies
+    #[account(mut)]
+    pub fee_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
     
     pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ResolveDispute<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+pub struct ManageStream<'info> {
+    pub client: Signer<'info>,
     
     #[account(
         mut,
-        seeds = [b"escrow_state"],
-        bump = escrow_state.bump,
-        constraint = authority.key() == escrow_state.authority
+        seeds = [b"escrow", client.key().as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
     )]
-    pub escrow_state: Account<'info, EscrowState>,
+    pub escrow: Account<'info, Escrow>,
+}
+
+#[derive(Accounts)]
+pub struct TerminateStream<'info> {
+    pub client: Signer<'info>,
     
     #[account(
         mut,
-        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        seeds = [b"escrow", client.key().as_ref(), escrow.freelancer.as_ref()],
         bump = escrow.bump,
-        constraint = escrow.disputed,
     )]
     pub escrow: Account<'info, Escrow>,
     
     #[account(
         mut,
-        token::mint = escrow_token_account.mint,
-        token::authority = escrow,
+        associated_token::mint = escrow.mint,
+        associated_token::authority = escrow,
+        constraint = escrow_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
     )]
     pub escrow_token_account: Account<'info, TokenAccount>,
     
     #[account(
         mut,
-        token::mint = escrow_token_account.mint,
-        token::authority = escrow.client,
+        constraint = client_token_account.mint == escrow.mint @ EscrowError::InvalidMint,
+        token::authority = client,
     )]
     pub client_token_account: Account<'info, TokenAccount>,
     
     #[account(
         mut,
-        token::mint = escrow_token_account.mint,
-        token::authority = escrow.freelancer,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
     )]
-    pub freelancer_token_account: Account<'info, TokenAccount>,
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// Latest history page, required once the escrow's history chain is opened
+    #[account(mut)]
+    pub history: Option<Account<'info, EscrowHistory>>,
     
     pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct OpenHistoryPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    /// Latest existing page, required unless this is the first
+    pub previous_page: Option<Account<'info, EscrowHistory>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + EscrowHistory::SIZE,
+        seeds = [b"escrow_history", escrow.key().as_ref(), &escrow.history_pages.to_le_bytes()],
+        bump
+    )]
+    pub page: Account<'info, EscrowHistory>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -1084,6 +3443,52 @@ pub struct UpdateEscrowParameters<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct RegisterPartnerPlatform<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+        constraint = authority.key() == escrow_state.authority @ EscrowError::Unauthorized
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    /// CHECK: Partner platform's identity; only its key is stored
+    pub partner: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PartnerPlatform::SIZE,
+        seeds = [b"partner_platform", partner.key().as_ref()],
+        bump
+    )]
+    pub partner_platform: Account<'info, PartnerPlatform>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePartnerPlatform<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+        constraint = authority.key() == escrow_state.authority @ EscrowError::Unauthorized
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    #[account(
+        mut,
+        seeds = [b"partner_platform", partner_platform.partner.as_ref()],
+        bump = partner_platform.bump,
+    )]
+    pub partner_platform: Account<'info, PartnerPlatform>,
+}
+
 #[derive(Accounts)]
 pub struct CompleteEscrow<'info> {
     #[account(mut)]
@@ -1105,9 +3510,6 @@ pub struct CompleteEscrow<'info> {
         bump = escrow_state.bump,
     )]
     pub escrow_state: Account<'info, EscrowState>,
-    
-    #[account(address = REPUTATION_PROGRAM_ID)]
-    pub reputation_program: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
@@ -1131,9 +3533,44 @@ pub struct ConfirmCompletion<'info> {
         bump = escrow_state.bump,
     )]
     pub escrow_state: Account<'info, EscrowState>,
+}
+
+#[derive(Accounts)]
+pub struct ReportContractCompletion<'info> {
+    /// Anyone can report a finished escrow
+    pub reporter: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), escrow.freelancer.as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    /// Signs the CPI; the reputation program derives this PDA as its escrow caller
+    #[account(
+        mut,
+        seeds = [b"escrow_state"],
+        bump = escrow_state.bump,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    
+    #[account(
+        mut,
+        constraint = client_profile.user == escrow.client @ EscrowError::InvalidReputationProfile,
+    )]
+    pub client_profile: Account<'info, UserProfile>,
+    
+    #[account(
+        mut,
+        constraint = freelancer_profile.user == escrow.freelancer @ EscrowError::InvalidReputationProfile,
+    )]
+    pub freelancer_profile: Account<'info, UserProfile>,
+    
+    #[account(mut)]
+    pub reputation_state: Account<'info, ReputationState>,
     
-    #[account(address = REPUTATION_PROGRAM_ID)]
-    pub reputation_program: AccountInfo<'info>,
+    pub reputation_program: Program<'info, ReputationProgram>,
 }
 
 #[derive(Accounts)]
@@ -1169,6 +3606,10 @@ pub struct EscrowState {
     pub successful_escrows: u64,
     pub disputed_escrows: u64,
     pub average_completion_time: i64,
+    pub platform_fee_bps: u16,
+    pub fee_destination: FeeDestination,
+    pub fee_recipient: Pubkey,
+    pub total_fees_collected: u64,
     pub is_paused: bool,
     pub last_update_timestamp: i64,
     pub bump: u8,
@@ -1185,11 +3626,63 @@ impl EscrowState {
                             8 +  // successful_escrows
                             8 +  // disputed_escrows
                             8 +  // average_completion_time
+                            2 +  // platform_fee_bps
+                            1 +  // fee_destination
+                            32 + // fee_recipient
+                            8 +  // total_fees_collected
                             1 +  // is_paused
                             8 +  // last_update_timestamp
                             1;   // bump
 }
 
+/// Where platform fees are routed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FeeDestination {
+    DomainTreasury,
+    StakingRewards,
+}
+
+impl Default for FeeDestination {
+    fn default() -> Self {
+        FeeDestination::DomainTreasury
+    }
+}
+
+impl FeeDestination {
+    /// Account that owns the destination's fee token accounts: the core
+    /// program's protocol domain treasury or the staking program's state PDA
+    pub fn recipient(&self) -> Pubkey {
+        match self {
+            FeeDestination::DomainTreasury => Pubkey::find_program_address(
+                &[b"domain-treasury", PROTOCOL_DOMAIN.as_bytes()],
+                &CORE_PROGRAM_ID,
+            ).0,
+            FeeDestination::StakingRewards => Pubkey::find_program_address(
+                &[b"staking_state"],
+                &STAKING_PROGRAM_ID,
+            ).0,
+        }
+    }
+}
+
+/// Partner platform with a negotiated take-rate for escrows it originates
+#[account]
+pub struct PartnerPlatform {
+    pub partner: Pubkey,
+    pub fee_bps: u16,
+    pub escrows_created: u64,
+    pub is_active: bool,
+    pub bump: u8,
+}
+
+impl PartnerPlatform {
+    pub const SIZE: usize = 32 + // partner
+                           2 +  // fee_bps
+                           8 +  // escrows_created
+                           1 +  // is_active
+                           1;   // bump
+}
+
 #[account]
 #[derive(Default)]
 pub struct Escrow {
@@ -1207,10 +3700,20 @@ pub struct Escrow {
     pub dispute_details: Option<DisputeDetails>,
     pub completion_date: Option<i64>,
     pub last_activity_at: i64,
-    pub transaction_signatures: Vec<TransactionRecord>,
+    pub history_pages: u32,
     pub last_notification_at: i64,
     pub client_confirmed_completion: bool,
     pub freelancer_confirmed_completion: bool,
+    pub mint: Pubkey,
+    pub cancellation: Option<CancellationProposal>,
+    pub amendment_count: u32,
+    pub partner: Option<Pubkey>,
+    pub fee_bps_override: Option<u16>,
+    pub fees_paid: u64,
+    pub mode: EscrowMode,
+    pub stream: Option<StreamTerms>,
+    pub reputation_reported: bool,
+    pub milestone_disputed: bool, // Set once any milestone has been disputed
     pub bump: u8,
 }
 
@@ -1222,18 +3725,70 @@ impl Escrow {
                            8 +  // created_at
                            8 +  // deadline
                            1 +  // status
-                           (1 + 10 * Milestone::SIZE) + // milestones vector with max 10 items
+                           (4 + INITIAL_MILESTONE_CAPACITY * Milestone::SIZE) + // milestones vector, grown by amendments
                            1 +  // completed_milestones
                            1 +  // disputed
                            8 +  // auto_release_date
                            (1 + DisputeDetails::SIZE) + // dispute_details (optional)
                            (1 + 8) + // completion_date (optional)
                            8 +  // last_activity_at
-                           (1 + 5 * TransactionRecord::SIZE) + // transaction signatures with max 5 items
+                           4 +  // history_pages
                            8 +  // last_notification_at
                            1 +  // client_confirmed_completion
                            1 +  // freelancer_confirmed_completion
+                           32 + // mint
+                           (1 + CancellationProposal::SIZE) + // cancellation (optional)
+                           4 +  // amendment_count
+                           (1 + 32) + // partner (optional)
+                           (1 + 2) + // fee_bps_override (optional)
+                           8 +  // fees_paid
+                           1 +  // mode
+                           (1 + StreamTerms::SIZE) + // stream (optional)
+                           1 +  // reputation_reported
+                           1 +  // milestone_disputed
                            1;   // bump
+    
+    /// Account space needed to hold `milestone_count` milestones
+    pub fn space_for(milestone_count: usize) -> usize {
+        8 + Escrow::SIZE + milestone_count.saturating_sub(INITIAL_MILESTONE_CAPACITY) * Milestone::SIZE
+    }
+    
+    /// Whether the freelancer has ever submitted work on any milestone
+    pub fn any_work_submitted(&self) -> bool {
+        self.milestones.iter().any(|m| m.submitted_at.is_some())
+    }
+    
+    /// Close out a cancelled escrow after its funds have been paid out
+    pub fn mark_cancelled(&mut self, now: i64) {
+        self.status = EscrowStatus::Cancelled;
+        self.cancellation = None;
+        self.completion_date = Some(now);
+        self.last_activity_at = now;
+    }
+    
+    /// Platform fee on a release, at the partner rate when one was negotiated
+    pub fn platform_fee(&self, default_fee_bps: u16, amount: u64) -> u64 {
+        let fee_bps = self.fee_bps_override.unwrap_or(default_fee_bps);
+        (amount as u128 * fee_bps as u128 / 10_000) as u64
+    }
+    
+    /// Funds still held for milestones that haven't been released.
+    /// For a stream, the unvested remainder once vesting is frozen
+    pub fn unreleased_amount(&self) -> u64 {
+        if let Some(stream) = &self.stream {
+            return match stream.frozen_at {
+                _ if stream.unvested_settled => 0,
+                Some(frozen_at) => self.amount - stream.vested_at(self.amount, frozen_at),
+                None => self.amount - stream.withdrawn,
+            };
+        }
+        
+        self.milestones
+            .iter()
+            .filter(|m| m.status != MilestoneStatus::Released)
+            .map(|m| m.amount)
+            .sum()
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -1242,6 +3797,7 @@ pub enum EscrowStatus {
     Disputed,
     Completed,
     Cancelled,
+    Resolved,
 }
 
 impl Default for EscrowStatus {
@@ -1256,19 +3812,269 @@ pub struct Milestone {
     pub description: String,
     pub amount: u64,
     pub deadline: i64,
-    pub completed: bool,
+    pub status: MilestoneStatus,
     pub completed_at: Option<i64>,
     pub feedback: Option<String>,
+    pub submitted_at: Option<i64>,
+    pub deliverable_hash: Option<[u8; 32]>,
+    pub review_window_days: u16,
+    pub review_deadline: Option<i64>,
+    pub revision_count: u8,
 }
 
 impl Milestone {
-    pub const SIZE: usize = 50 + // title (max length)
-                           100 + // description (max length)
+    pub const SIZE: usize = (4 + MAX_MILESTONE_TITLE_LENGTH) + // title
+                           (4 + MAX_MILESTONE_DESCRIPTION_LENGTH) + // description
                            8 +   // amount
                            8 +   // deadline
-                           1 +   // completed
+                           1 +   // status
                            (1 + 8) + // completed_at (optional)
-                           (1 + 100); // feedback (optional)
+                           (1 + 4 + 100) + // feedback (optional, max length)
+                           (1 + 8) + // submitted_at (optional)
+                           (1 + 32) + // deliverable_hash (optional)
+                           2 +   // review_window_days
+                           (1 + 8) + // review_deadline (optional)
+                           1;    // revision_count
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MilestoneStatus {
+    Pending,
+    Submitted,
+    RevisionRequested,
+    Approved,
+    Released,
+    Disputed,
+}
+
+impl Default for MilestoneStatus {
+    fn default() -> Self {
+        MilestoneStatus::Pending
+    }
+}
+
+#[account]
+pub struct EscrowAmendment {
+    pub escrow: Pubkey,
+    pub index: u32,
+    pub proposed_by: Pubkey,
+    pub changes: Vec<MilestoneAmendment>,
+    pub previous_amount: u64,
+    pub new_amount: u64,
+    pub proposed_at: i64,
+    pub accepted_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl EscrowAmendment {
+    pub const SIZE: usize = 32 + // escrow
+                           4 +  // index
+                           32 + // proposed_by
+                           (4 + MAX_AMENDMENT_CHANGES * MilestoneAmendment::SIZE) + // changes
+                           8 +  // previous_amount
+                           8 +  // new_amount
+                           8 +  // proposed_at
+                           (1 + 8) + // accepted_at (optional)
+                           1;   // bump
+    
+    /// Number of milestones this amendment adds
+    pub fn added_milestones(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|c| matches!(c, MilestoneAmendment::Add { .. }))
+            .count()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum MilestoneAmendment {
+    Add {
+        title: String,
+        description: String,
+        amount: u64,
+        deadline: i64,
+        review_window_days: u16,
+    },
+    Remove {
+        index: u8,
+    },
+    ChangeAmount {
+        index: u8,
+        amount: u64,
+    },
+    ChangeDeadline {
+        index: u8,
+        deadline: i64,
+    },
+}
+
+impl MilestoneAmendment {
+    pub const SIZE: usize = 1 + // variant
+                           (4 + 50) + // title (max length)
+                           (4 + 100) + // description (max length)
+                           8 +  // amount
+                           8 +  // deadline
+                           2;   // review_window_days
+}
+
+/// Amendments are only allowed on a live escrow with nothing under dispute
+fn require_amendable(escrow: &Escrow) -> Result<()> {
+    require!(
+        escrow.mode == EscrowMode::Milestones,
+        EscrowError::NotMilestoneEscrow
+    );
+    require!(
+        escrow.status == EscrowStatus::Active,
+        EscrowError::EscrowNotActive
+    );
+    require!(!escrow.disputed, EscrowError::AlreadyDisputed);
+    require!(
+        !escrow.milestones.iter().any(|m| m.status == MilestoneStatus::Disputed),
+        EscrowError::AlreadyDisputed
+    );
+    Ok(())
+}
+
+/// Streams can only be paused, resumed or terminated while running undisputed
+fn require_stream_running(escrow: &Escrow) -> Result<()> {
+    require!(
+        escrow.mode == EscrowMode::Stream,
+        EscrowError::NotStreamEscrow
+    );
+    require!(
+        escrow.status == EscrowStatus::Active,
+        EscrowError::EscrowNotActive
+    );
+    require!(!escrow.disputed, EscrowError::AlreadyDisputed);
+    Ok(())
+}
+
+/// Apply amendment changes in order; only work not yet submitted can be changed
+fn apply_milestone_amendments(
+    milestones: &mut Vec<Milestone>,
+    changes: &[MilestoneAmendment],
+    now: i64,
+) -> Result<()> {
+    let amendable = |milestone: &Milestone| {
+        milestone.status == MilestoneStatus::Pending ||
+        milestone.status == MilestoneStatus::RevisionRequested
+    };
+    
+    for change in changes.iter() {
+        match change {
+            MilestoneAmendment::Add { title, description, amount, deadline, review_window_days } => {
+                require!(
+                    *amount > 0 && *deadline > now && *review_window_days > 0,
+                    EscrowError::InvalidAmendment
+                );
+                require!(
+                    title.len() <= MAX_MILESTONE_TITLE_LENGTH &&
+                    description.len() <= MAX_MILESTONE_DESCRIPTION_LENGTH,
+                    EscrowError::InvalidAmendment
+                );
+                milestones.push(Milestone {
+                    title: title.clone(),
+                    description: description.clone(),
+                    amount: *amount,
+                    deadline: *deadline,
+                    review_window_days: *review_window_days,
+                    ..Default::default()
+                });
+            }
+            MilestoneAmendment::Remove { index } => {
+                let milestone = milestones
+                    .get(*index as usize)
+                    .ok_or(EscrowError::InvalidMilestoneIndex)?;
+                require!(amendable(milestone), EscrowError::InvalidMilestoneStatus);
+                milestones.remove(*index as usize);
+            }
+            MilestoneAmendment::ChangeAmount { index, amount } => {
+                let milestone = milestones
+                    .get_mut(*index as usize)
+                    .ok_or(EscrowError::InvalidMilestoneIndex)?;
+                require!(amendable(milestone), EscrowError::InvalidMilestoneStatus);
+                require!(*amount > 0, EscrowError::InvalidAmendment);
+                milestone.amount = *amount;
+            }
+            MilestoneAmendment::ChangeDeadline { index, deadline } => {
+                let milestone = milestones
+                    .get_mut(*index as usize)
+                    .ok_or(EscrowError::InvalidMilestoneIndex)?;
+                require!(amendable(milestone), EscrowError::InvalidMilestoneStatus);
+                require!(*deadline > now, EscrowError::InvalidAmendment);
+                milestone.deadline = *deadline;
+            }
+        }
+    }
+    
+    require!(
+        !milestones.is_empty() && milestones.len() <= MAX_MILESTONES,
+        EscrowError::TooManyMilestones
+    );
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CancellationProposal {
+    pub proposed_by: Pubkey,
+    pub kill_fee: u64,
+    pub proposed_at: i64,
+}
+
+impl CancellationProposal {
+    pub const SIZE: usize = 32 + // proposed_by
+                           8 +  // kill_fee
+                           8;   // proposed_at
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EscrowMode {
+    Milestones,
+    Stream,
+}
+
+impl Default for EscrowMode {
+    fn default() -> Self {
+        EscrowMode::Milestones
+    }
+}
+
+/// Vesting schedule for a time-based escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct StreamTerms {
+    pub start_at: i64,
+    pub end_at: i64,
+    pub period_seconds: i64, // 0 vests continuously, otherwise in whole periods
+    pub withdrawn: u64,
+    pub paused_at: Option<i64>,
+    pub paused_duration: i64,
+    pub frozen_at: Option<i64>, // Set by a dispute or termination
+    pub unvested_settled: bool,
+}
+
+impl StreamTerms {
+    pub const SIZE: usize = 8 + // start_at
+                           8 +  // end_at
+                           8 +  // period_seconds
+                           8 +  // withdrawn
+                           (1 + 8) + // paused_at (optional)
+                           8 +  // paused_duration
+                           (1 + 8) + // frozen_at (optional)
+                           1;   // unvested_settled
+    
+    /// Amount of `total` vested at `now`, net of pauses and stopped at any freeze
+    pub fn vested_at(&self, total: u64, now: i64) -> u64 {
+        let cutoff = [self.paused_at, self.frozen_at]
+            .iter()
+            .flatten()
+            .fold(now, |cutoff, &t| cutoff.min(t));
+        let duration = self.end_at - self.start_at;
+        let mut elapsed = (cutoff - self.start_at - self.paused_duration).clamp(0, duration);
+        if self.period_seconds > 0 && elapsed < duration {
+            elapsed -= elapsed % self.period_seconds;
+        }
+        (total as u128 * elapsed as u128 / duration as u128) as u64
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -1303,21 +4109,402 @@ impl DisputeResolution {
                           200; // notes (max length)
 }
 
+#[account]
+pub struct MilestoneDispute {
+    pub escrow: Pubkey,
+    pub milestone_index: u8,
+    pub initiated_by: Pubkey,
+    pub initiated_at: i64,
+    pub reason: String,
+    pub resolution: Option<MilestoneDisputeResolution>,
+    pub bump: u8,
+}
+
+impl MilestoneDispute {
+    pub const SIZE: usize = 32 + // escrow
+                           1 +  // milestone_index
+                           32 + // initiated_by
+                           8 +  // initiated_at
+                           200 + // reason (max length)
+                           (1 + MilestoneDisputeResolution::SIZE) + // resolution (optional)
+                           1;   // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MilestoneDisputeResolution {
+    pub resolved_at: i64,
+    pub client_amount: u64,
+    pub freelancer_amount: u64,
+    pub resolver: Pubkey,
+    pub notes: String,
+}
+
+impl MilestoneDisputeResolution {
+    pub const SIZE: usize = 8 + // resolved_at
+                          8 + // client_amount
+                          8 + // freelancer_amount
+                          32 + // resolver
+                          200; // notes (max length)
+}
+
+#[account]
+pub struct DisputePanel {
+    pub escrow: Pubkey,
+    pub scope: u8, // Milestone index, or ESCROW_DISPUTE_SCOPE for the whole escrow
+    pub disputed_amount: u64,
+    pub fee_amount: u64,
+    pub members: Vec<Pubkey>,
+    pub commitments: Vec<PanelCommitment>,
+    pub decisions: Vec<PanelDecisionRecord>,
+    pub selection_seed: [u8; 32],
+    pub opened_at: i64,
+    pub drawn_at: i64,
+    pub draw_slot: u64, // Slot whose hash seeds the draw
+    pub commit_deadline: i64,
+    pub decision_deadline: i64,
+    pub freelancer_award: Option<u64>,
+    pub escalated: bool,
+    pub settled: bool,
+    pub bump: u8,
+}
+
+impl DisputePanel {
+    pub const SIZE: usize = 32 + // escrow
+                           1 +  // scope
+                           8 +  // disputed_amount
+                           8 +  // fee_amount
+                           (4 + DISPUTE_PANEL_SIZE * 32) + // members
+                           (4 + DISPUTE_PANEL_SIZE * PanelCommitment::SIZE) + // commitments
+                           (4 + DISPUTE_PANEL_SIZE * PanelDecisionRecord::SIZE) + // decisions
+                           32 + // selection_seed
+                           8 +  // opened_at
+                           8 +  // drawn_at
+                           8 +  // draw_slot
+                           8 +  // commit_deadline
+                           8 +  // decision_deadline
+                           (1 + 8) + // freelancer_award (optional)
+                           1 +  // escalated
+                           1 +  // settled
+                           1;   // bump
+    
+    /// Record a newly raised dispute awaiting its panel draw
+    pub fn open(
+        &mut self,
+        escrow: Pubkey,
+        scope: u8,
+        disputed_amount: u64,
+        fee_amount: u64,
+        now: i64,
+        slot: u64,
+        bump: u8,
+    ) {
+        self.escrow = escrow;
+        self.scope = scope;
+        self.disputed_amount = disputed_amount;
+        self.fee_amount = fee_amount;
+        self.members = Vec::new();
+        self.commitments = Vec::new();
+        self.decisions = Vec::new();
+        self.selection_seed = [0; 32];
+        self.opened_at = now;
+        self.drawn_at = 0;
+        self.draw_slot = slot + PANEL_DRAW_DELAY_SLOTS;
+        self.commit_deadline = now + PANEL_COMMIT_PERIOD_DAYS * 86400;
+        self.decision_deadline = self.commit_deadline + PANEL_DECISION_PERIOD_DAYS * 86400;
+        self.freelancer_award = None;
+        self.escalated = false;
+        self.settled = false;
+        self.bump = bump;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PanelCommitment {
+    pub member: Pubkey,
+    pub commitment: [u8; 32], // hash(freelancer_amount, salt, member)
+}
+
+impl PanelCommitment {
+    pub const SIZE: usize = 32 + // member
+                          32;  // commitment
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PanelDecisionRecord {
+    pub member: Pubkey,
+    pub freelancer_amount: u64,
+    pub decided_at: i64,
+}
+
+impl PanelDecisionRecord {
+    pub const SIZE: usize = 32 + // member
+                          8 +  // freelancer_amount
+                          8;   // decided_at
+}
+
+/// Transfer tokens out of an escrow's token account, signed by the escrow PDA
+fn transfer_from_escrow<'info>(
+    token_program: &Program<'info, Token>,
+    escrow_token_account: &Account<'info, TokenAccount>,
+    destination: AccountInfo<'info>,
+    escrow: AccountInfo<'info>,
+    escrow_signer: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    let transfer_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        Transfer {
+            from: escrow_token_account.to_account_info(),
+            to: destination,
+            authority: escrow,
+        },
+        escrow_signer,
+    );
+    
+    token::transfer(transfer_ctx, amount)
+}
+
+/// Open the insurance program's payment verification for a newly created escrow
+fn register_with_insurance<'info>(
+    accounts: &CreateEscrow<'info>,
+    amount: u64,
+    deadline: i64,
+) -> Result<()> {
+    let (insurance_program, payment_verification) =
+        match (&accounts.insurance_program, &accounts.payment_verification) {
+            (Some(program), Some(verification)) => (program, verification),
+            _ => return Ok(()),
+        };
+    
+    // The escrow PDA signs so the insurance program knows the registration is genuine
+    let client = accounts.client.key();
+    let freelancer = accounts.freelancer.key();
+    let seeds = &[
+        b"escrow".as_ref(),
+        client.as_ref(),
+        freelancer.as_ref(),
+        &[accounts.escrow.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        insurance_program.to_account_info(),
+        freelance_insurance::cpi::accounts::RegisterEscrow {
+            client: accounts.client.to_account_info(),
+            freelancer: accounts.freelancer.to_account_info(),
+            escrow: accounts.escrow.to_account_info(),
+            payment_verification: payment_verification.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+        },
+        signer,
+    );
+    
+    freelance_insurance::cpi::register_escrow(cpi_ctx, amount, deadline)
+}
+
+/// Route a platform fee from the escrow to the configured fee recipient
+fn collect_platform_fee<'info>(
+    token_program: &Program<'info, Token>,
+    escrow_token_account: &Account<'info, TokenAccount>,
+    fee_token_account: Option<&Account<'info, TokenAccount>>,
+    escrow_state: &mut Account<'info, EscrowState>,
+    escrow: AccountInfo<'info>,
+    escrow_signer: &[&[&[u8]]],
+    fee: u64,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+    
+    // Validate fee account belongs to the treasury or staking pool and holds the escrow mint
+    let fee_token_account = fee_token_account.ok_or(EscrowError::InvalidFeeAccount)?;
+    require!(
+        fee_token_account.owner == escrow_state.fee_recipient &&
+        fee_token_account.mint == escrow_token_account.mint,
+        EscrowError::InvalidFeeAccount
+    );
+    
+    transfer_from_escrow(
+        token_program,
+        escrow_token_account,
+        fee_token_account.to_account_info(),
+        escrow,
+        escrow_signer,
+        fee,
+    )?;
+    
+    escrow_state.total_fees_collected += fee;
+    Ok(())
+}
+
+/// Append-only page of an escrow's transaction history
+#[account]
+pub struct EscrowHistory {
+    pub escrow: Pubkey,
+    pub page: u32,
+    pub records: Vec<TransactionRecord>,
+    pub bump: u8,
+}
+
+impl EscrowHistory {
+    pub const SIZE: usize = 32 + // escrow
+                           4 +  // page
+                           (4 + HISTORY_PAGE_CAPACITY * TransactionRecord::SIZE) + // records
+                           1;   // bump
+}
+
+/// Emit the typed event for a transaction and append it to the escrow's history chain, if opened
+fn record_transaction(
+    escrow: &Account<Escrow>,
+    history: Option<&mut Account<EscrowHistory>>,
+    record: TransactionRecord,
+) -> Result<()> {
+    let escrow_key = escrow.key();
+    
+    match record.transaction_type {
+        TransactionType::Deposit => emit!(EscrowDeposited {
+            escrow: escrow_key,
+            amount: record.amount,
+            slot: record.slot,
+            timestamp: record.timestamp,
+        }),
+        TransactionType::MilestoneRelease => emit!(MilestoneReleased {
+            escrow: escrow_key,
+            recipient: record.recipient,
+            milestone_index: record.milestone_index,
+            amount: record.amount,
+            auto: record.auto,
+            slot: record.slot,
+            timestamp: record.timestamp,
+        }),
+        TransactionType::Refund | TransactionType::KillFee => emit!(EscrowRefunded {
+            escrow: escrow_key,
+            recipient: record.recipient,
+            amount: record.amount,
+            kill_fee: record.transaction_type == TransactionType::KillFee,
+            slot: record.slot,
+            timestamp: record.timestamp,
+        }),
+        TransactionType::DisputeFee | TransactionType::DisputeResolution => emit!(DisputeTransfer {
+            escrow: escrow_key,
+            recipient: record.recipient,
+            milestone_index: record.milestone_index,
+            amount: record.amount,
+            fee: record.transaction_type == TransactionType::DisputeFee,
+            slot: record.slot,
+            timestamp: record.timestamp,
+        }),
+        TransactionType::StreamWithdrawal => emit!(StreamWithdrawn {
+            escrow: escrow_key,
+            recipient: record.recipient,
+            amount: record.amount,
+            slot: record.slot,
+            timestamp: record.timestamp,
+        }),
+        TransactionType::PlatformFee => emit!(PlatformFeeCollected {
+            escrow: escrow_key,
+            recipient: record.recipient,
+            milestone_index: record.milestone_index,
+            amount: record.amount,
+            slot: record.slot,
+            timestamp: record.timestamp,
+        }),
+    }
+    
+    if escrow.history_pages > 0 {
+        let history = history.ok_or(EscrowError::InvalidHistoryPage)?;
+        require!(
+            history.escrow == escrow_key && history.page == escrow.history_pages - 1,
+            EscrowError::InvalidHistoryPage
+        );
+        require!(
+            history.records.len() < HISTORY_PAGE_CAPACITY,
+            EscrowError::HistoryPageFull
+        );
+        history.records.push(record);
+    }
+    
+    Ok(())
+}
+
+#[event]
+pub struct EscrowDeposited {
+    pub escrow: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneReleased {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub milestone_index: Option<u8>,
+    pub amount: u64,
+    pub auto: bool,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscrowRefunded {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub kill_fee: bool,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeTransfer {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub milestone_index: Option<u8>,
+    pub amount: u64,
+    pub fee: bool,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StreamWithdrawn {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformFeeCollected {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub milestone_index: Option<u8>,
+    pub amount: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct TransactionRecord {
     pub timestamp: i64,
-    pub signature: String,
+    pub slot: u64,
+    pub recipient: Pubkey,
     pub transaction_type: TransactionType,
     pub amount: u64,
     pub milestone_index: Option<u8>,
+    pub auto: bool,
 }
 
 impl TransactionRecord {
     pub const SIZE: usize = 8 + // timestamp
-                          88 + // signature (max length)
+                          8 +  // slot
+                          32 + // recipient
                           1 +  // transaction_type
                           8 +  // amount
-                          (1 + 1); // milestone_index (optional)
+                          (1 + 1) + // milestone_index (optional)
+                          1;   // auto
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -1327,6 +4514,9 @@ pub enum TransactionType {
     Refund,
     DisputeFee,
     DisputeResolution,
+    KillFee,
+    PlatformFee,
+    StreamWithdrawal,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -1339,6 +4529,9 @@ pub struct EscrowAnalytics {
     pub success_rate: u32, // Basis points (e.g., 9500 = 95.00%)
     pub dispute_rate: u32, // Basis points
     pub average_completion_time: i64, // In seconds
+    pub platform_fee_bps: u16,
+    pub fee_destination: FeeDestination,
+    pub total_fees_collected: u64,
     pub avg_completion_days: i64,
     pub avg_completion_hours: i64,
     pub avg_completion_minutes: i64,
@@ -1360,6 +4553,10 @@ pub struct EscrowSummary {
     pub disputed: bool,
     pub completion_date: Option<i64>,
     pub last_activity_at: i64,
+    pub partner: Option<Pubkey>,
+    pub fee_bps: u16, // Effective take-rate on releases
+    pub fees_paid: u64,
+    pub mode: EscrowMode,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -1439,4 +4636,189 @@ pub enum EscrowError {
     
     #[msg("Cross-program invocation failed")]
     CrossProgramInvocationFailed,
+    
+    #[msg("Token account mint does not match the escrow mint")]
+    InvalidMint,
+    
+    #[msg("Milestone is not in a valid status for this operation")]
+    InvalidMilestoneStatus,
+    
+    #[msg("Milestone has not been approved")]
+    MilestoneNotApproved,
+    
+    #[msg("Milestone review window has closed")]
+    ReviewWindowClosed,
+    
+    #[msg("Award exceeds the disputed milestone amount")]
+    InvalidAwardAmount,
+    
+    #[msg("Dispute has already been resolved")]
+    DisputeAlreadyResolved,
+    
+    #[msg("Not enough eligible arbitrators to draw a panel")]
+    InsufficientArbitrators,
+    
+    #[msg("Dispute panel has already been drawn")]
+    PanelAlreadyDrawn,
+    
+    #[msg("Panel draw slot has not been reached")]
+    PanelDrawSlotNotReached,
+    
+    #[msg("Signer is not a member of this dispute panel")]
+    NotPanelMember,
+    
+    #[msg("Panel commit period has ended")]
+    CommitPeriodEnded,
+    
+    #[msg("Panel member has already committed")]
+    AlreadyCommitted,
+    
+    #[msg("Panel decision period is not open")]
+    DecisionPeriodNotOpen,
+    
+    #[msg("Panel member has already decided")]
+    AlreadyDecided,
+    
+    #[msg("Decision does not match the committed hash")]
+    CommitmentMismatch,
+    
+    #[msg("Dispute panel is still deciding")]
+    PanelStillDeciding,
+    
+    #[msg("Dispute has not been escalated to the authority")]
+    DisputeNotEscalated,
+    
+    #[msg("Kill fee exceeds the unreleased escrow amount")]
+    InvalidKillFee,
+    
+    #[msg("No cancellation has been proposed")]
+    NoCancellationProposed,
+    
+    #[msg("Cancellation proposal does not match the accepted terms")]
+    CancellationProposalMismatch,
+    
+    #[msg("Escrow deadline has not been reached")]
+    DeadlineNotReached,
+    
+    #[msg("Freelancer has submitted work on this escrow")]
+    WorkSubmitted,
+    
+    #[msg("Invalid milestone amendment")]
+    InvalidAmendment,
+    
+    #[msg("Latest escrow history page required")]
+    InvalidHistoryPage,
+    
+    #[msg("Escrow history page is full; open the next page")]
+    HistoryPageFull,
+    
+    #[msg("Latest escrow history page still has room for another instruction")]
+    HistoryPageNotFull,
+    
+    #[msg("Platform fee rate exceeds the maximum")]
+    InvalidFeeRate,
+    
+    #[msg("Fee account must be the configured recipient's account for the escrow mint")]
+    InvalidFeeAccount,
+    
+    #[msg("Fee recipient does not own the chosen fee destination")]
+    InvalidFeeRecipient,
+    
+    #[msg("Partner platform is not active")]
+    PartnerInactive,
+    
+    #[msg("Only available on milestone escrows")]
+    NotMilestoneEscrow,
+    
+    #[msg("Only available on stream escrows")]
+    NotStreamEscrow,
+    
+    #[msg("Invalid stream vesting schedule")]
+    InvalidStreamSchedule,
+    
+    #[msg("No vested funds to withdraw")]
+    NothingVested,
+    
+    #[msg("Stream is paused")]
+    StreamPaused,
+    
+    #[msg("Stream is not paused")]
+    StreamNotPaused,
+    
+    #[msg("Escrow was already reported to the reputation program")]
+    ReputationAlreadyReported,
+    
+    #[msg("Reputation profile does not belong to this escrow's party")]
+    InvalidReputationProfile,
+    
+    #[msg("Escrow must have between one and the maximum number of milestones")]
+    TooManyMilestones,
+    
+    #[msg("No milestone review window has lapsed yet")]
+    AutoReleaseNotReached,
+    
+    #[msg("No milestones left to release")]
+    NoMilestonesToRelease,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn stream(period_seconds: i64) -> StreamTerms {
+        StreamTerms {
+            start_at: 1_000,
+            end_at: 2_000,
+            period_seconds,
+            withdrawn: 0,
+            paused_at: None,
+            paused_duration: 0,
+            frozen_at: None,
+            unvested_settled: false,
+        }
+    }
+    
+    #[test]
+    fn test_vested_at_linear() {
+        let terms = stream(0);
+        assert_eq!(terms.vested_at(10_000, 500), 0); // Before the start
+        assert_eq!(terms.vested_at(10_000, 1_250), 2_500);
+        assert_eq!(terms.vested_at(10_000, 2_000), 10_000);
+        assert_eq!(terms.vested_at(10_000, 5_000), 10_000); // Capped at the end
+    }
+    
+    #[test]
+    fn test_vested_at_pause_cutoff() {
+        let mut terms = stream(0);
+        terms.paused_at = Some(1_300);
+        assert_eq!(terms.vested_at(10_000, 1_900), 3_000); // Stops at the pause
+        
+        // Resumed: time spent paused no longer counts
+        terms.paused_at = None;
+        terms.paused_duration = 200;
+        assert_eq!(terms.vested_at(10_000, 1_900), 7_000);
+        assert_eq!(terms.vested_at(10_000, 2_200), 10_000);
+    }
+    
+    #[test]
+    fn test_vested_at_freeze_cutoff() {
+        let mut terms = stream(0);
+        terms.frozen_at = Some(1_400);
+        assert_eq!(terms.vested_at(10_000, 1_900), 4_000);
+        
+        // The earliest of a pause and a freeze wins
+        terms.paused_at = Some(1_600);
+        assert_eq!(terms.vested_at(10_000, 1_900), 4_000);
+        terms.paused_at = Some(1_200);
+        assert_eq!(terms.vested_at(10_000, 1_900), 2_000);
+    }
+    
+    #[test]
+    fn test_vested_at_period_rounding() {
+        let terms = stream(300);
+        assert_eq!(terms.vested_at(10_000, 1_299), 0); // Inside the first period
+        assert_eq!(terms.vested_at(10_000, 1_300), 3_000);
+        assert_eq!(terms.vested_at(10_000, 1_950), 9_000); // Rounded down to 900s
+        assert_eq!(terms.vested_at(10_000, 2_000), 10_000); // The final partial period vests at the end
+    }
 }
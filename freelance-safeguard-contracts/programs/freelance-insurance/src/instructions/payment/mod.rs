@@ -1,8 +1,12 @@
 pub mod verify;
 pub mod confirm;
 pub mod missed;
+pub mod parametric;
+pub mod register_escrow;
 
 pub use verify::*;
 pub use confirm::*;
 pub use missed::*;
+pub use parametric::*;
+pub use register_escrow::*;
 
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::utils::escrow::{load_escrow, EscrowMode, EscrowStatus};
+use crate::InsuranceError;
+
+#[derive(Accounts)]
+pub struct ExecuteParametricPayout<'info> {
+    /// Anyone can crank the payout; they only pay rent for the claim record
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = payment_verification.status == PaymentStatus::Pending @ InsuranceError::PaymentAlreadyClaimed,
+        constraint = payment_verification.escrow.is_some() @ InsuranceError::EscrowVerificationRequired,
+        constraint = Clock::get()?.unix_timestamp > payment_verification.deadline @ InsuranceError::DeadlineNotPassed
+    )]
+    pub payment_verification: Account<'info, PaymentVerification>,
+    
+    #[account(
+        mut,
+        constraint = policy.owner == payment_verification.freelancer @ InsuranceError::OnlyPolicyOwner,
+        constraint = policy.status == PolicyStatus::Active @ InsuranceError::PolicyNotActive,
+        constraint = policy.coverage_type == CoverageType::ParametricNonPayment @ InsuranceError::NotParametricPolicy,
+        constraint = payment_verification.created_at >= policy.start_date @ InsuranceError::VerificationOutsidePolicyTerm,
+        constraint = payment_verification.deadline <= policy.end_date @ InsuranceError::VerificationOutsidePolicyTerm
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + std::mem::size_of::<Claim>() + 
+                MAX_EVIDENCE_TYPE_LENGTH + 
+                MAX_EVIDENCE_DESC_LENGTH + 
+                (MAX_EVIDENCE_ATTACHMENTS * MAX_ATTACHMENT_LENGTH),
+        seeds = [CLAIM_SEED.as_bytes(), policy.key().as_ref(), &[policy.claims_count]],
+        bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    #[account(
+        mut,
+        seeds = [RISK_POOL_SEED.as_bytes()],
+        bump = risk_pool.bump
+    )]
+    pub risk_pool: Account<'info, RiskPool>,
+    
+    #[account(
+        mut,
+        constraint = claim_source.owner == risk_pool.key() @ InsuranceError::Unauthorized
+    )]
+    pub claim_source: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = claim_destination.owner == policy.owner @ InsuranceError::Unauthorized,
+        constraint = claim_destination.mint == claim_source.mint @ InsuranceError::Unauthorized
+    )]
+    pub claim_destination: Account<'info, TokenAccount>,
+    
+    /// CHECK: Escrow the verification was registered for; decoded in the handler
+    #[account(
+        constraint = payment_verification.escrow == Some(escrow.key()) @ InsuranceError::InvalidEscrowAccount
+    )]
+    pub escrow: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExecuteParametricPayout>) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    require!(
+        clock.unix_timestamp <= ctx.accounts.policy.end_date,
+        InsuranceError::PolicyExpired
+    );
+    
+    // The escrow must still hold the payment: completed, resolved and cancelled
+    // escrows have all paid out to one party or the other
+    let escrow_header = load_escrow(&ctx.accounts.escrow.to_account_info())?;
+    require!(
+        escrow_header.status == EscrowStatus::Active ||
+        escrow_header.status == EscrowStatus::Disputed,
+        InsuranceError::EscrowSettled
+    );
+    
+    // Streams vest over time, so there is no delivery to hold against the client
+    require!(
+        escrow_header.mode == EscrowMode::Milestones,
+        InsuranceError::StreamEscrowNotSupported
+    );
+    
+    // Only work the freelancer has delivered and the client failed to pay for triggers
+    require!(
+        escrow_header.has_unpaid_delivery(clock.unix_timestamp),
+        InsuranceError::NoUnpaidDelivery
+    );
+    
+    let payout = ctx.accounts.policy.parametric_payout;
+    require!(
+        ctx.accounts.claim_source.amount >= payout,
+        InsuranceError::InsufficientFunds
+    );
+    
+    // Pay the fixed amount straight from the pool
+    let risk_pool_bump = ctx.accounts.risk_pool.bump;
+    let seeds = &[
+        RISK_POOL_SEED.as_bytes(),
+        &[risk_pool_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.claim_source.to_account_info(),
+        to: ctx.accounts.claim_destination.to_account_info(),
+        authority: ctx.accounts.risk_pool.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    
+    token::transfer(cpi_ctx, payout)?;
+    
+    let payment_verification = &mut ctx.accounts.payment_verification;
+    let policy = &mut ctx.accounts.policy;
+    
+    // Record the claim as already decided
+    let claim = &mut ctx.accounts.claim;
+    claim.policy = policy.key();
+    claim.owner = policy.owner;
+    claim.amount = payout;
+    claim.status = ClaimStatus::Resolved;
+    claim.evidence_type = "Parametric Missed Payment".to_string();
+    claim.evidence_description = format!(
+        "Client missed payment of {} by deadline {}",
+        payment_verification.expected_amount,
+        payment_verification.deadline
+    );
+    claim.evidence_attachments = vec![format!(
+        "Payment verification: {}",
+        payment_verification.key()
+    )];
+    claim.submission_date = clock.unix_timestamp;
+    claim.verdict = Some(ClaimVerdict {
+        approved: true,
+        reason: "Parametric trigger: payment deadline passed unsettled".to_string(),
+        processed_at: clock.unix_timestamp,
+    });
+    claim.bump = *ctx.bumps.get("claim").unwrap();
+    
+    // Parametric cover pays out once
+    policy.claims_count += 1;
+    policy.status = PolicyStatus::Claimed;
+    payment_verification.status = PaymentStatus::Claimed;
+    
+    let risk_pool = &mut ctx.accounts.risk_pool;
+    risk_pool.claims_paid += payout;
+    risk_pool.total_coverage = risk_pool.total_coverage.saturating_sub(policy.coverage_amount);
+    risk_pool.active_policies = risk_pool.active_policies.saturating_sub(1);
+    
+    msg!("Parametric payout of {} sent for policy {}", payout, policy.key());
+    Ok(())
+}
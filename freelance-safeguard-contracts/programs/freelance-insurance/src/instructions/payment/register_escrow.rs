@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::utils::escrow::ESCROW_PROGRAM_ID;
+use crate::InsuranceError;
+
+#[derive(Accounts)]
+pub struct RegisterEscrow<'info> {
+    /// Client funding the escrow pays for the verification record
+    #[account(mut)]
+    pub client: Signer<'info>,
+    
+    /// CHECK: Freelancer is just a pubkey that will be stored
+    pub freelancer: UncheckedAccount<'info>,
+    
+    /// Escrow PDA signs through the escrow program's CPI, so only real escrows register
+    #[account(owner = ESCROW_PROGRAM_ID @ InsuranceError::InvalidEscrowAccount)]
+    pub escrow: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = client,
+        space = 8 + std::mem::size_of::<PaymentVerification>(),
+        seeds = [
+            PAYMENT_VERIFICATION_SEED.as_bytes(),
+            freelancer.key().as_ref(),
+            client.key().as_ref(),
+        ],
+        bump
+    )]
+    pub payment_verification: Account<'info, PaymentVerification>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterEscrow>,
+    expected_amount: u64,
+    deadline: i64,
+) -> Result<()> {
+    let payment_verification = &mut ctx.accounts.payment_verification;
+    let clock = Clock::get()?;
+    
+    // The escrow is still being initialized, so its terms come in as arguments
+    payment_verification.freelancer = ctx.accounts.freelancer.key();
+    payment_verification.client = ctx.accounts.client.key();
+    payment_verification.expected_amount = expected_amount;
+    payment_verification.deadline = deadline;
+    payment_verification.status = PaymentStatus::Pending;
+    payment_verification.escrow = Some(ctx.accounts.escrow.key());
+    payment_verification.created_at = clock.unix_timestamp;
+    payment_verification.bump = *ctx.bumps.get("payment_verification").expect("Bump not found");
+    
+    Ok(())
+}
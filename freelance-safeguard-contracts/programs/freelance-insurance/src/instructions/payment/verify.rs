@@ -40,6 +40,8 @@ pub fn handler(
     payment_verification.expected_amount = expected_amount;
     payment_verification.deadline = deadline;
     payment_verification.status = PaymentStatus::Pending;
+    // Only the escrow program links escrows, through register_escrow
+    payment_verification.escrow = None;
     payment_verification.created_at = clock.unix_timestamp;
     payment_verification.bump = *ctx.bumps.get("payment_verification").expect("Bump not found");
     
@@ -51,6 +51,7 @@ pub fn handler(
     industry_value: u8,
     reputation_score: u8,
     claims_history: u8,
+    parametric_payout: Option<u64>,
 ) -> Result<()> {
     let policy = &mut ctx.accounts.policy;
     let owner = &ctx.accounts.owner;
@@ -67,6 +68,14 @@ pub fn handler(
         InsuranceError::PeriodTooLong
     );
     
+    // Parametric cover pays a fixed amount, which can't exceed the coverage
+    if let Some(payout) = parametric_payout {
+        require!(
+            payout > 0 && payout <= coverage_amount,
+            InsuranceError::InvalidParametricPayout
+        );
+    }
+    
     // Convert job type and industry from u8 to enum
     let job_type = JobType::from_u8(job_type_value)?;
     let industry = Industry::from_u8(industry_value)?;
@@ -111,6 +120,12 @@ pub fn handler(
     policy.industry = industry;
     policy.claims_count = 0;
     policy.risk_score = risk_score;
+    policy.coverage_type = if parametric_payout.is_some() {
+        CoverageType::ParametricNonPayment
+    } else {
+        CoverageType::Indemnity
+    };
+    policy.parametric_payout = parametric_payout.unwrap_or(0);
     
     // Store premium breakdown components for transparency and auditability
     policy.premium_breakdown = PremiumBreakdown {
@@ -30,6 +30,7 @@ pub mod freelance_insurance {
         industry_value: u8,
         reputation_score: u8,
         claims_history: u8,
+        parametric_payout: Option<u64>,
     ) -> Result<()> {
         instructions::policy::create::handler(
             ctx, 
@@ -38,7 +39,8 @@ pub mod freelance_insurance {
             job_type_value, 
             industry_value, 
             reputation_score, 
-            claims_history
+            claims_history,
+            parametric_payout
         )
     }
     
@@ -154,6 +156,20 @@ pub mod freelance_insurance {
     ) -> Result<()> {
         instructions::payment::missed::handler(ctx, amount)
     }
+
+    // Open a payment verification for an escrow, called by the escrow program on creation
+    pub fn register_escrow(
+        ctx: Context<RegisterEscrow>,
+        expected_amount: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::payment::register_escrow::handler(ctx, expected_amount, deadline)
+    }
+
+    // Pay a parametric policy's fixed amount once a payment deadline passes unsettled
+    pub fn execute_parametric_payout(ctx: Context<ExecuteParametricPayout>) -> Result<()> {
+        instructions::payment::parametric::handler(ctx)
+    }
 }
 
 // Error codes
@@ -218,5 +234,29 @@ pub enum InsuranceError {
     
     #[msg("Invalid policy status")]
     InvalidPolicyStatus,
+    
+    #[msg("Policy is not parametric cover")]
+    NotParametricPolicy,
+    
+    #[msg("Parametric payout must be positive and within coverage")]
+    InvalidParametricPayout,
+    
+    #[msg("Invalid escrow account")]
+    InvalidEscrowAccount,
+    
+    #[msg("Parametric cover requires a payment verification registered by the escrow program")]
+    EscrowVerificationRequired,
+    
+    #[msg("Payment verification falls outside the policy term")]
+    VerificationOutsidePolicyTerm,
+    
+    #[msg("Escrow payment has settled")]
+    EscrowSettled,
+    
+    #[msg("Stream escrows cannot trigger parametric cover")]
+    StreamEscrowNotSupported,
+    
+    #[msg("Escrow has no delivered milestone left unpaid")]
+    NoUnpaidDelivery,
 }
 
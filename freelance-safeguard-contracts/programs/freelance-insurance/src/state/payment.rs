@@ -7,6 +7,8 @@ pub struct PaymentVerification {
     pub expected_amount: u64,
     pub deadline: i64,
     pub status: PaymentStatus,
+    /// Escrow holding the payment, checked for settlement before a parametric payout
+    pub escrow: Option<Pubkey>,
     pub created_at: i64,
     pub bump: u8,
 }
@@ -36,6 +36,9 @@ pub struct Policy {
     pub industry: Industry,
     pub claims_count: u8,
     pub risk_score: u8,
+    pub coverage_type: CoverageType,
+    /// Fixed amount paid automatically on a missed payment (parametric cover only)
+    pub parametric_payout: u64,
     
     /// Premium calculation breakdown for transparency and auditability
     pub premium_breakdown: PremiumBreakdown,
@@ -51,6 +54,14 @@ pub enum PolicyStatus {
     Claimed,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CoverageType {
+    /// Claims are assessed and paid for the loss shown
+    Indemnity,
+    /// A missed, unsettled payment deadline pays `parametric_payout` with no assessment
+    ParametricNonPayment,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum JobType {
     SoftwareDevelopment,
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::InsuranceError;
+
+/// Escrow program that owns `Escrow` accounts linked to payment verifications
+pub const ESCROW_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("HbRjXVmWEkbjCejgGLaPsWpECgwoHhXVH1gV5NUzGVSH");
+
+/// Mirror of `escrow_program::EscrowStatus`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum EscrowStatus {
+    Active,
+    Disputed,
+    Completed,
+    Cancelled,
+    Resolved,
+}
+
+/// Mirror of `escrow_program::MilestoneStatus`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum MilestoneStatus {
+    Pending,
+    Submitted,
+    RevisionRequested,
+    Approved,
+    Released,
+    Disputed,
+}
+
+/// Mirror of `escrow_program::Milestone`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Milestone {
+    pub title: String,
+    pub description: String,
+    pub amount: u64,
+    pub deadline: i64,
+    pub status: MilestoneStatus,
+    pub completed_at: Option<i64>,
+    pub feedback: Option<String>,
+    pub submitted_at: Option<i64>,
+    pub deliverable_hash: Option<[u8; 32]>,
+    pub review_window_days: u16,
+    pub review_deadline: Option<i64>,
+    pub revision_count: u8,
+}
+
+/// Mirror of `escrow_program::DisputeResolution`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DisputeResolution {
+    pub resolved_at: i64,
+    pub client_percentage: u8,
+    pub freelancer_percentage: u8,
+    pub resolver: Pubkey,
+    pub notes: String,
+}
+
+/// Mirror of `escrow_program::DisputeDetails`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DisputeDetails {
+    pub initiated_by: Pubkey,
+    pub initiated_at: i64,
+    pub reason: String,
+    pub resolution: Option<DisputeResolution>,
+}
+
+/// Mirror of `escrow_program::CancellationProposal`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CancellationProposal {
+    pub proposed_by: Pubkey,
+    pub kill_fee: u64,
+    pub proposed_at: i64,
+}
+
+/// Mirror of `escrow_program::EscrowMode`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum EscrowMode {
+    Milestones,
+    Stream,
+}
+
+/// Mirror of `escrow_program::StreamTerms`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StreamTerms {
+    pub start_at: i64,
+    pub end_at: i64,
+    pub period_seconds: i64,
+    pub withdrawn: u64,
+    pub paused_at: Option<i64>,
+    pub paused_duration: i64,
+    pub frozen_at: Option<i64>,
+    pub unvested_settled: bool,
+}
+
+/// Leading fields of `escrow_program::Escrow`, up to and including `stream`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EscrowHeader {
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub amount: u64,
+    pub description: String,
+    pub created_at: i64,
+    pub deadline: i64,
+    pub status: EscrowStatus,
+    pub milestones: Vec<Milestone>,
+    pub completed_milestones: u8,
+    pub disputed: bool,
+    pub auto_release_date: i64,
+    pub dispute_details: Option<DisputeDetails>,
+    pub completion_date: Option<i64>,
+    pub last_activity_at: i64,
+    pub history_pages: u32,
+    pub last_notification_at: i64,
+    pub client_confirmed_completion: bool,
+    pub freelancer_confirmed_completion: bool,
+    pub mint: Pubkey,
+    pub cancellation: Option<CancellationProposal>,
+    pub amendment_count: u32,
+    pub partner: Option<Pubkey>,
+    pub fee_bps_override: Option<u16>,
+    pub fees_paid: u64,
+    pub mode: EscrowMode,
+    pub stream: Option<StreamTerms>,
+}
+
+impl EscrowHeader {
+    /// Whether delivered work is still unpaid: a milestone the client approved, or
+    /// one submitted whose review window lapsed, that has not been released
+    pub fn has_unpaid_delivery(&self, now: i64) -> bool {
+        self.milestones.iter().any(|m| match m.status {
+            MilestoneStatus::Approved => true,
+            MilestoneStatus::Submitted => m.review_deadline.map_or(false, |deadline| now >= deadline),
+            _ => false,
+        })
+    }
+}
+
+/// Decodes an escrow account after checking its owner and discriminator
+pub fn load_escrow(escrow_info: &AccountInfo) -> Result<EscrowHeader> {
+    require!(
+        *escrow_info.owner == ESCROW_PROGRAM_ID,
+        InsuranceError::InvalidEscrowAccount
+    );
+    
+    let data = escrow_info.try_borrow_data()?;
+    require!(
+        data.len() > 8 && data[..8] == hash(b"account:Escrow").to_bytes()[..8],
+        InsuranceError::InvalidEscrowAccount
+    );
+    
+    EscrowHeader::deserialize(&mut &data[8..])
+        .map_err(|_| error!(InsuranceError::InvalidEscrowAccount))
+}
@@ -1,4 +1,5 @@
 // Utility functions for the insurance program
 pub mod validation;
 pub mod math;
+pub mod escrow;
 
@@ -32,6 +32,11 @@ pub mod reputation_program {
         reputation_state.authority = ctx.accounts.authority.key();
         reputation_state.insurance_program_id = insurance_program_id;
         reputation_state.escrow_program_id = escrow_program_id;
+        // The escrow program signs completion updates with its state PDA
+        reputation_state.escrow_caller = Pubkey::find_program_address(
+            &[b"escrow_state"],
+            &escrow_program_id,
+        ).0;
         reputation_state.initial_reputation_score = initial_reputation_score;
         reputation_state.total_profiles = 0;
         reputation_state.average_score = initial_reputation_score;
@@ -97,7 +102,7 @@ pub mod reputation_program {
         // Verify caller is authorized
         require!(
             ctx.accounts.caller.key() == reputation_state.authority || 
-            ctx.accounts.caller.key() == reputation_state.escrow_program_id,
+            ctx.accounts.caller.key() == reputation_state.escrow_caller,
             ReputationError::Unauthorized
         );
         
@@ -733,6 +738,7 @@ pub struct ReputationState {
     pub authority: Pubkey,
     pub insurance_program_id: Pubkey,
     pub escrow_program_id: Pubkey,
+    pub escrow_caller: Pubkey,
     pub initial_reputation_score: u8,
     pub total_profiles: u64,
     pub average_score: u8,
@@ -745,6 +751,7 @@ impl ReputationState {
                            32 + // authority
                            32 + // insurance_program_id
                            32 + // escrow_program_id
+                           32 + // escrow_caller
                            1 +  // initial_reputation_score
                            8 +  // total_profiles
                            1 +  // average_score
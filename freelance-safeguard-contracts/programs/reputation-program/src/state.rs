@@ -8,6 +8,7 @@ pub struct ReputationState {
     pub authority: Pubkey,
     pub insurance_program_id: Pubkey,
     pub escrow_program_id: Pubkey,
+    pub escrow_caller: Pubkey,
     pub initial_reputation_score: u8,
     pub total_profiles: u64,
     pub average_score: u8,
@@ -20,6 +21,7 @@ impl ReputationState {
                            32 + // authority
                            32 + // insurance_program_id
                            32 + // escrow_program_id
+                           32 + // escrow_caller
                            1 +  // initial_reputation_score
                            8 +  // total_profiles
                            1 +  // average_score